@@ -1,37 +1,60 @@
-use std::collections::BTreeSet;
+use std::{collections::BTreeSet, mem};
 
 use line_index::LineIndex;
 use oxc::{
   allocator::Allocator,
-  ast::{ast::Program, AstBuilder},
+  ast::{
+    ast::{Expression, Function, Program, TSTypeOperator},
+    AstBuilder,
+  },
   semantic::{Semantic, SymbolId},
   span::{GetSpan, Span, SPAN},
 };
-use rustc_hash::FxHashMap;
+use oxc_index::IndexVec;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   builtins::Builtins,
   config::Config,
+  program::{ModuleId, ModuleInfo},
   scope::{
     call::CallScope,
+    class::ClassScope,
     control::CfScopeKind,
-    r#type::TypeScopeTree,
+    r#type::{TypeScopeId, TypeScopeTree},
     runtime::{RuntimeScope, RuntimeScopeTree},
   },
-  ty::{accumulator::TypeAccumulator, ctx::CtxTy, Ty},
+  ty::{accumulator::TypeAccumulator, ctx::CtxTy, namespace::NamespaceType, Ty},
 };
 
+/// The states that belong to one file. Only the states of the file being analyzed are stored in
+/// the analyzer, the others are swapped out.
+pub struct FileContext<'a> {
+  pub semantic: &'a Semantic<'a>,
+  pub line_index: LineIndex,
+  pub type_scope: TypeScopeId,
+  pub diagnostics: BTreeSet<String>,
+  pub span_to_type: FxHashMap<Span, TypeAccumulator<'a>>,
+  pub pos_to_span: &'a mut [Span],
+  pub default_export: Option<Ty<'a>>,
+}
+
 pub struct Analyzer<'a> {
   pub allocator: &'a Allocator,
   pub config: &'a Config,
   pub line_index: LineIndex,
-  pub semantic: Semantic<'a>,
+  pub semantic: &'a Semantic<'a>,
   pub ast_builder: AstBuilder<'a>,
 
   pub builtins: Builtins<'a>,
 
+  pub modules: IndexVec<ModuleId, ModuleInfo<'a>>,
+  /// `None` when analyzing a single file
+  pub current_module: Option<ModuleId>,
+
   pub span_stack: Vec<Span>,
   pub call_scopes: Vec<CallScope<'a>>,
+  pub class_scopes: Vec<ClassScope<'a>>,
   pub runtime_scopes: RuntimeScopeTree<'a>,
   pub type_scopes: TypeScopeTree<'a>,
 
@@ -39,31 +62,45 @@ pub struct Analyzer<'a> {
   pub variables: FxHashMap<SymbolId, Ty<'a>>,
   /// Generic parameter with its constraint
   pub generic_constraints: FxHashMap<SymbolId, CtxTy<'a>>,
+  /// Function declarations not executed yet, with the type scope they are declared in
+  pub hoisted_functions: FxHashMap<SymbolId, (TypeScopeId, Vec<&'a Function<'a>>)>,
+  /// Types printed by name, like the instance types of classes
+  pub named_types: FxHashMap<Ty<'a>, &'a str>,
+  /// Types printed as `typeof` the value, like classes
+  pub named_values: FxHashMap<Ty<'a>, &'a str>,
   pub type_placeholder_count: usize,
+  /// Instantiations of generics with resolved arguments, so that the equal ones share the
+  /// unwrapped body
+  pub generic_instances: FxHashMap<(Ty<'a>, Vec<Ty<'a>>), Ty<'a>>,
+  /// The `(target, pattern)` pairs of object types being matched
+  pub match_assumptions: FxHashSet<(Ty<'a>, Ty<'a>)>,
+  /// The arguments executed to resolve overloads, which are reused by the selected overload
+  pub executed_arguments: FxHashMap<Span, Ty<'a>>,
+  /// The interfaces being printed, whose recursive references are printed as `any`
+  pub printing_interfaces: FxHashSet<Ty<'a>>,
+  /// The `unique symbol` types declared without a variable, like the well-known symbols
+  pub unique_symbols: FxHashMap<*const TSTypeOperator<'a>, SymbolId>,
+  /// The expressions referencing unique symbols, like `Symbol.iterator`, to print the keys
+  pub unique_symbol_names: FxHashMap<SymbolId, &'a Expression<'a>>,
 
   pub diagnostics: BTreeSet<String>,
   pub span_to_type: FxHashMap<Span, TypeAccumulator<'a>>,
   pub pos_to_span: &'a mut [Span],
+  /// The value of `export default expr`, or of an anonymous default function or class
+  pub default_export: Option<Ty<'a>>,
 }
 
 impl<'a> Analyzer<'a> {
-  pub fn new(allocator: &'a Allocator, config: Config, semantic: Semantic<'a>) -> Self {
+  pub fn new(allocator: &'a Allocator, config: Config, semantic: &'a Semantic<'a>) -> Self {
     let config = allocator.alloc(config);
 
-    let mut runtime_scopes = RuntimeScopeTree::default();
-    let root_scope = runtime_scopes.push(RuntimeScope {
-      kind: CfScopeKind::Module,
-      exited: None,
-      variables: Default::default(),
-    });
-
-    let root_call_scope =
-      CallScope::new(root_scope, true, false, /* TODO: globalThis */ Ty::Any, None);
-
     let ast_builder = AstBuilder::new(allocator);
     let pos_to_expr = allocator.alloc_slice_fill_default(semantic.source_text().len());
 
-    Analyzer {
+    let mut type_scopes = TypeScopeTree::new();
+    type_scopes.set_semantic(type_scopes.root(), semantic);
+
+    let mut analyzer = Analyzer {
       allocator,
       config,
       line_index: LineIndex::new(semantic.source_text()),
@@ -72,18 +109,84 @@ impl<'a> Analyzer<'a> {
 
       builtins: Builtins::new(),
 
+      modules: IndexVec::new(),
+      current_module: None,
+
       span_stack: Vec::new(),
-      call_scopes: Vec::from([root_call_scope]),
-      runtime_scopes,
-      type_scopes: TypeScopeTree::new(),
+      call_scopes: Vec::new(),
+      class_scopes: Vec::new(),
+      runtime_scopes: RuntimeScopeTree::default(),
+      type_scopes,
 
       variables: Default::default(),
       generic_constraints: Default::default(),
+      hoisted_functions: Default::default(),
+      named_types: Default::default(),
+      named_values: Default::default(),
       type_placeholder_count: 0,
+      generic_instances: Default::default(),
+      match_assumptions: Default::default(),
+      executed_arguments: Default::default(),
+      printing_interfaces: Default::default(),
+      unique_symbols: Default::default(),
+      unique_symbol_names: Default::default(),
 
       diagnostics: Default::default(),
       span_to_type: Default::default(),
       pos_to_span: pos_to_expr,
+      default_export: None,
+    };
+
+    analyzer.reset_runtime_states();
+    analyzer.load_builtins();
+
+    analyzer
+  }
+
+  /// Reset the states which are only valid while executing one file.
+  pub fn reset_runtime_states(&mut self) {
+    let mut runtime_scopes = RuntimeScopeTree::default();
+    let root_scope = runtime_scopes.push(RuntimeScope {
+      kind: CfScopeKind::Module,
+      exited: None,
+      variables: Default::default(),
+    });
+
+    let root_call_scope =
+      CallScope::new(root_scope, true, false, /* TODO: globalThis */ Ty::Any, None);
+
+    self.runtime_scopes = runtime_scopes;
+    self.call_scopes = Vec::from([root_call_scope]);
+    // Symbol IDs are not shared between files
+    self.variables.clear();
+    self.generic_constraints.clear();
+    self.hoisted_functions.clear();
+  }
+
+  /// Create the context for analyzing another file, with a new root type scope.
+  pub fn create_file_context(&mut self, semantic: &'a Semantic<'a>) -> FileContext<'a> {
+    let source_text = semantic.source_text();
+    FileContext {
+      semantic,
+      line_index: LineIndex::new(source_text),
+      type_scope: self.type_scopes.create_root(semantic),
+      diagnostics: Default::default(),
+      span_to_type: Default::default(),
+      pos_to_span: self.allocator.alloc_slice_fill_default(source_text.len()),
+      default_export: None,
+    }
+  }
+
+  /// Swap in the states of another file. Returns the states of the previous one.
+  pub fn swap_file_context(&mut self, context: FileContext<'a>) -> FileContext<'a> {
+    FileContext {
+      semantic: mem::replace(&mut self.semantic, context.semantic),
+      line_index: mem::replace(&mut self.line_index, context.line_index),
+      type_scope: self.type_scopes.replace_top(context.type_scope),
+      diagnostics: mem::replace(&mut self.diagnostics, context.diagnostics),
+      span_to_type: mem::replace(&mut self.span_to_type, context.span_to_type),
+      pos_to_span: mem::replace(&mut self.pos_to_span, context.pos_to_span),
+      default_export: mem::replace(&mut self.default_export, context.default_export),
     }
   }
 
@@ -119,16 +222,25 @@ impl<'a> Analyzer<'a> {
     self.span_stack.pop();
   }
 
-  pub fn resolve_module(&mut self, specifier: &'a str) -> Option<()> {
-    todo!()
+  /// Returns the exports namespace of the module imported by `specifier`. `None` if the module is
+  /// unknown, or is not analyzed yet because of circular imports. In the latter case, the current
+  /// module is analyzed again afterwards.
+  pub fn resolve_module(&mut self, specifier: &'a str) -> Option<&'a NamespaceType<'a>> {
+    let module = &self.modules[self.current_module?];
+    let dependency = *module.dependencies.get(specifier)?;
+    self.modules[dependency].exports
   }
 
   pub fn resolve_global_variable(&mut self, id: &'a str) -> Ty<'a> {
-    todo!()
+    match id {
+      "undefined" => Ty::Undefined,
+      "NaN" | "Infinity" => Ty::Number,
+      _ => self.builtins.globals.get(id).copied().unwrap_or(Ty::Error),
+    }
   }
 
   pub fn resolve_global_type(&mut self, id: &'a str) -> Ty<'a> {
-    todo!()
+    self.builtins.global_types.get(id).copied().unwrap_or(Ty::Error)
   }
 
   pub fn accumulate_type(&mut self, span: &impl GetSpan, ty: Ty<'a>) {
@@ -143,6 +255,42 @@ impl<'a> Analyzer<'a> {
     acc.add(ty, allocator);
   }
 
+  /// Like `accumulate_type`, but the positions already taken by the inner nodes are kept. This is
+  /// for the nodes with bodies, like function expressions and object literals.
+  pub fn accumulate_enclosing_type(&mut self, span: &impl GetSpan, ty: Ty<'a>) {
+    let Analyzer { allocator, span_to_type: expr_types, pos_to_span: pos_to_expr, .. } = self;
+    let span = span.span();
+    let acc = expr_types.entry(span).or_insert_with(move || {
+      for pos in span.start..span.end {
+        if pos_to_expr[pos as usize] == SPAN {
+          pos_to_expr[pos as usize] = span;
+        }
+      }
+      TypeAccumulator::default()
+    });
+    acc.add(ty, allocator);
+  }
+
+  /// Like `accumulate_type`, but the positions inside `inner` are kept. This is for call
+  /// expressions, whose arguments may contain callbacks with their own queried positions.
+  pub fn accumulate_type_outside(&mut self, span: &impl GetSpan, inner: &[Span], ty: Ty<'a>) {
+    let Analyzer { allocator, span_to_type: expr_types, pos_to_span: pos_to_expr, .. } = self;
+    let span = span.span();
+    let acc = expr_types.entry(span).or_insert_with(move || {
+      for pos in span.start..span.end {
+        if !inner.iter().any(|inner| inner.start <= pos && pos < inner.end) {
+          pos_to_expr[pos as usize] = span;
+        }
+      }
+      TypeAccumulator::default()
+    });
+    acc.add(ty, allocator);
+  }
+
+  pub fn get_type_by_span(&mut self, span: Span) -> Option<Ty<'a>> {
+    self.span_to_type.get_mut(&span)?.to_ty()
+  }
+
   pub fn get_type_by_pos(&mut self, pos: usize) -> Option<Ty<'a>> {
     let span = self.pos_to_span[pos];
     if span == SPAN {
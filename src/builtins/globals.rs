@@ -1 +1,136 @@
+use oxc::{
+  ast::ast::{Statement, TSSignature},
+  parser::Parser,
+  semantic::{SemanticBuilder, SymbolId},
+  span::{Atom, SourceType},
+};
 
+use super::libs::concat_lib_sources;
+use crate::{
+  ty::{property_key::PropertyKeyType, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  /// Analyze the bundled lib declarations, and collect the declared values and types as globals.
+  pub fn load_builtins(&mut self) {
+    let source = self.allocator.alloc_str(&concat_lib_sources(&self.config.libs()));
+    // Semantic does not bind symbols in `.d.ts` sources, so the lib is parsed as a `.ts` source.
+    let parsed =
+      self.allocator.alloc(Parser::new(self.allocator, source, SourceType::ts()).parse());
+    let semantic = self.allocator.alloc(SemanticBuilder::new().build(&parsed.program).semantic);
+
+    // The lib is a separate file, so the file-specific states are swapped out during analyzing.
+    let lib_context = self.create_file_context(semantic);
+    let lib_scope = lib_context.type_scope;
+    let old_context = self.swap_file_context(lib_context);
+
+    // Types are collected before initializing, since the lib itself references global types.
+    for statement in &parsed.program.body {
+      self.declare_statement(statement);
+    }
+    let root_scope = semantic.scopes().root_scope_id();
+    let collect_global_types = |analyzer: &mut Self| {
+      for (name, symbol) in semantic.scopes().get_bindings(root_scope) {
+        if let Some(ty) = analyzer.type_scopes.get_on_scope(lib_scope, *symbol) {
+          analyzer.builtins.global_types.insert(name, ty);
+          // Type aliases like `PropertyKey` are expanded when printed
+          if !semantic.symbols().get_flags(*symbol).is_type_alias()
+            || matches!(ty, Ty::Generic(_) | Ty::Intrinsic(_))
+          {
+            analyzer.builtins.global_type_names.insert(ty, name);
+          }
+        }
+      }
+    };
+    collect_global_types(self);
+    // The computed keys like `[Symbol.iterator]` read the global values and the interfaces merged
+    // from the later libs, and the type aliases may index the interfaces. So these are initialized
+    // first.
+    let mut statements: Vec<_> = parsed.program.body.iter().collect();
+    statements.sort_by_key(|statement| init_order(statement));
+    for statement in statements {
+      self.init_type_statements([statement]);
+      self.init_statement(statement);
+    }
+    // The type aliases may be resolved again when initialized
+    collect_global_types(self);
+
+    self.swap_file_context(old_context);
+
+    for (name, symbol) in semantic.scopes().get_bindings(root_scope) {
+      if let Some(ty) = self.variables.get(symbol) {
+        self.builtins.globals.insert(name, *ty);
+      }
+    }
+    self.reset_runtime_states();
+    self.builtins.semantic = Some(semantic);
+
+    let prototype = |name: &str| self.builtins.global_types.get(name).copied().unwrap_or(Ty::Any);
+    self.builtins.string_prototype = prototype("String");
+    self.builtins.number_prototype = prototype("Number");
+    self.builtins.bigint_prototype = prototype("BigInt");
+    self.builtins.boolean_prototype = prototype("Boolean");
+    self.builtins.object_prototype = prototype("Object");
+    self.builtins.function_prototype = prototype("Function");
+    self.builtins.symbol_prototype = prototype("Symbol");
+
+    self.builtins.symbol_iterator = self.get_well_known_symbol("iterator");
+    self.builtins.symbol_async_iterator = self.get_well_known_symbol("asyncIterator");
+  }
+
+  /// Like `Symbol.iterator`, or `None` if the lib does not declare it.
+  fn get_well_known_symbol(&mut self, name: &'static str) -> Option<SymbolId> {
+    let symbol = self.builtins.globals.get("Symbol").copied()?;
+    let key = PropertyKeyType::StringLiteral(self.allocator.alloc(Atom::from(name)));
+    match self.get_property(symbol, key) {
+      Ty::UniqueSymbol(symbol) => Some(symbol),
+      _ => None,
+    }
+  }
+
+  /// Returns `T[]` or `readonly T[]`.
+  pub fn create_array_type(&mut self, element: Ty<'a>, readonly: bool) -> Ty<'a> {
+    let generic = self.resolve_global_type(if readonly { "ReadonlyArray" } else { "Array" });
+    self.create_generic_instance(generic, vec![element])
+  }
+
+  /// Returns `Generator<T, TReturn, TNext>` or `AsyncGenerator<T, TReturn, TNext>`.
+  pub fn create_generator_type(
+    &mut self,
+    is_async: bool,
+    yielded: Ty<'a>,
+    returned: Ty<'a>,
+    next: Ty<'a>,
+  ) -> Ty<'a> {
+    let generic = self.resolve_global_type(if is_async { "AsyncGenerator" } else { "Generator" });
+    self.create_generic_instance(generic, vec![yielded, returned, next])
+  }
+
+  /// Returns `Promise<T>`.
+  pub fn create_promise_type(&mut self, value: Ty<'a>) -> Ty<'a> {
+    let generic = self.resolve_global_type("Promise");
+    self.create_generic_instance(generic, vec![value])
+  }
+}
+
+/// Variable declarations and the interfaces which neither extend nor instantiate others go first,
+/// then the type aliases, then the others.
+fn init_order(statement: &Statement) -> u8 {
+  match statement {
+    Statement::VariableDeclaration(_) => 0,
+    Statement::TSInterfaceDeclaration(node)
+      if node.type_parameters.is_none()
+        && node.extends.is_none()
+        && node.body.body.iter().all(|signature| match signature {
+          TSSignature::TSPropertySignature(node) => !node.computed,
+          TSSignature::TSMethodSignature(node) => !node.computed,
+          _ => true,
+        }) =>
+    {
+      0
+    }
+    Statement::TSTypeAliasDeclaration(_) => 1,
+    _ => 2,
+  }
+}
@@ -0,0 +1,193 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Condensed from TypeScript's `lib.dom.d.ts`. Only a small subset of the DOM is declared: the
+/// console, events, the basic node and element types, `HTMLInputElement`, `document`, `window`,
+/// `location`, `navigator`, storage, `URL`, `fetch` and timers. Other globals are not found, and
+/// other members are missing from the declared types.
+
+interface Console {
+  assert(condition?: boolean, ...data: any[]): void;
+  clear(): void;
+  debug(...data: any[]): void;
+  error(...data: any[]): void;
+  info(...data: any[]): void;
+  log(...data: any[]): void;
+  table(tabularData?: any, properties?: string[]): void;
+  time(label?: string): void;
+  timeEnd(label?: string): void;
+  trace(...data: any[]): void;
+  warn(...data: any[]): void;
+}
+
+declare var console: Console;
+
+interface Event {
+  readonly type: string;
+  readonly target: EventTarget | null;
+  readonly currentTarget: EventTarget | null;
+  preventDefault(): void;
+  stopPropagation(): void;
+}
+
+interface EventTarget {
+  addEventListener(type: string, listener: (event: Event) => void): void;
+  removeEventListener(type: string, listener: (event: Event) => void): void;
+  dispatchEvent(event: Event): boolean;
+}
+
+interface Node extends EventTarget {
+  readonly nodeName: string;
+  readonly parentNode: Node | null;
+  textContent: string | null;
+  appendChild(node: Node): Node;
+  removeChild(child: Node): Node;
+}
+
+interface Element extends Node {
+  id: string;
+  className: string;
+  innerHTML: string;
+  readonly tagName: string;
+  getAttribute(qualifiedName: string): string | null;
+  setAttribute(qualifiedName: string, value: string): void;
+  querySelector(selectors: string): Element | null;
+}
+
+interface HTMLElement extends Element {
+  hidden: boolean;
+  title: string;
+  click(): void;
+  focus(): void;
+  blur(): void;
+}
+
+interface HTMLInputElement extends HTMLElement {
+  checked: boolean;
+  disabled: boolean;
+  name: string;
+  placeholder: string;
+  type: string;
+  value: string;
+  valueAsNumber: number;
+  select(): void;
+}
+
+interface Document extends Node {
+  readonly body: HTMLElement;
+  title: string;
+  createElement(tagName: string): HTMLElement;
+  getElementById(elementId: string): HTMLElement | null;
+  querySelector(selectors: string): Element | null;
+}
+
+declare var document: Document;
+
+interface Storage {
+  readonly length: number;
+  clear(): void;
+  getItem(key: string): string | null;
+  removeItem(key: string): void;
+  setItem(key: string, value: string): void;
+}
+
+declare var localStorage: Storage;
+declare var sessionStorage: Storage;
+
+interface Location {
+  href: string;
+  hostname: string;
+  pathname: string;
+  search: string;
+  hash: string;
+  reload(): void;
+}
+
+interface Navigator {
+  readonly language: string;
+  readonly languages: readonly string[];
+  readonly onLine: boolean;
+  readonly userAgent: string;
+}
+
+interface Window extends EventTarget {
+  readonly document: Document;
+  readonly location: Location;
+  readonly navigator: Navigator;
+  readonly innerWidth: number;
+  readonly innerHeight: number;
+  alert(message?: any): void;
+}
+
+declare var window: Window;
+declare var location: Location;
+declare var navigator: Navigator;
+
+interface URLSearchParams {
+  readonly size: number;
+  append(name: string, value: string): void;
+  delete(name: string, value?: string): void;
+  get(name: string): string | null;
+  getAll(name: string): string[];
+  has(name: string, value?: string): boolean;
+  set(name: string, value: string): void;
+  toString(): string;
+}
+
+interface URLSearchParamsConstructor {
+  new (init?: string[][] | Record<string, string> | string | URLSearchParams): URLSearchParams;
+  readonly prototype: URLSearchParams;
+}
+
+declare var URLSearchParams: URLSearchParamsConstructor;
+
+interface URL {
+  hash: string;
+  host: string;
+  hostname: string;
+  href: string;
+  readonly origin: string;
+  pathname: string;
+  port: string;
+  protocol: string;
+  search: string;
+  readonly searchParams: URLSearchParams;
+  toJSON(): string;
+  toString(): string;
+}
+
+interface URLConstructor {
+  new (url: string | URL, base?: string | URL): URL;
+  readonly prototype: URL;
+  canParse(url: string | URL, base?: string | URL): boolean;
+}
+
+declare var URL: URLConstructor;
+
+interface Response {
+  readonly ok: boolean;
+  readonly status: number;
+  json(): Promise<any>;
+  text(): Promise<string>;
+}
+
+declare function alert(message?: any): void;
+declare function fetch(input: string): Promise<Response>;
+declare function setTimeout(handler: () => void, timeout?: number): number;
+declare function clearTimeout(id: number | undefined): void;
+declare function setInterval(handler: () => void, timeout?: number): number;
+declare function clearInterval(id: number | undefined): void;
+declare function requestAnimationFrame(callback: (time: number) => void): number;
+declare function queueMicrotask(callback: () => void): void;
@@ -0,0 +1,686 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Declarations from TypeScript's `lib.es2015.*.d.ts`, with the documentation comments stripped.
+
+/* lib.es2015.symbol.d.ts */
+
+interface SymbolConstructor {
+  readonly prototype: Symbol;
+  (description?: string | number): symbol;
+  for(key: string): symbol;
+  keyFor(sym: symbol): string | undefined;
+}
+
+declare var Symbol: SymbolConstructor;
+
+/* lib.es2015.symbol.wellknown.d.ts */
+
+interface SymbolConstructor {
+  readonly hasInstance: unique symbol;
+  readonly isConcatSpreadable: unique symbol;
+  readonly match: unique symbol;
+  readonly replace: unique symbol;
+  readonly search: unique symbol;
+  readonly species: unique symbol;
+  readonly split: unique symbol;
+  readonly toPrimitive: unique symbol;
+  readonly toStringTag: unique symbol;
+  readonly unscopables: unique symbol;
+}
+
+interface Symbol {
+  [Symbol.toPrimitive](hint: string): symbol;
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Array<T> {
+  readonly [Symbol.unscopables]: {
+    [K in keyof any[]]?: boolean;
+  };
+}
+
+interface ReadonlyArray<T> {
+  readonly [Symbol.unscopables]: {
+    [K in keyof readonly any[]]?: boolean;
+  };
+}
+
+interface Date {
+  [Symbol.toPrimitive](hint: "default"): string;
+  [Symbol.toPrimitive](hint: "string"): string;
+  [Symbol.toPrimitive](hint: "number"): number;
+  [Symbol.toPrimitive](hint: string): string | number;
+}
+
+interface Map<K, V> {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface WeakMap<K extends WeakKey, V> {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Set<T> {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface WeakSet<T extends WeakKey> {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface JSON {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Function {
+  [Symbol.hasInstance](value: any): boolean;
+}
+
+interface GeneratorFunction {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Math {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Promise<T> {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface PromiseConstructor {
+  readonly [Symbol.species]: PromiseConstructor;
+}
+
+interface RegExp {
+  [Symbol.match](string: string): RegExpMatchArray | null;
+  [Symbol.replace](string: string, replaceValue: string): string;
+  [Symbol.replace](string: string, replacer: (substring: string, ...args: any[]) => string): string;
+  [Symbol.search](string: string): number;
+  [Symbol.split](string: string, limit?: number): string[];
+}
+
+interface RegExpConstructor {
+  readonly [Symbol.species]: RegExpConstructor;
+}
+
+interface String {
+  match(matcher: { [Symbol.match](string: string): RegExpMatchArray | null }): RegExpMatchArray | null;
+  replace(searchValue: { [Symbol.replace](string: string, replaceValue: string): string }, replaceValue: string): string;
+  replace(searchValue: { [Symbol.replace](string: string, replacer: (substring: string, ...args: any[]) => string): string }, replacer: (substring: string, ...args: any[]) => string): string;
+  search(searcher: { [Symbol.search](string: string): number }): number;
+  split(splitter: { [Symbol.split](string: string, limit?: number): string[] }, limit?: number): string[];
+}
+
+interface ArrayBuffer {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface DataView {
+  readonly [Symbol.toStringTag]: string;
+}
+
+interface Int8Array {
+  readonly [Symbol.toStringTag]: "Int8Array";
+}
+
+interface Uint8Array {
+  readonly [Symbol.toStringTag]: "Uint8Array";
+}
+
+interface Uint8ClampedArray {
+  readonly [Symbol.toStringTag]: "Uint8ClampedArray";
+}
+
+interface Int16Array {
+  readonly [Symbol.toStringTag]: "Int16Array";
+}
+
+interface Uint16Array {
+  readonly [Symbol.toStringTag]: "Uint16Array";
+}
+
+interface Int32Array {
+  readonly [Symbol.toStringTag]: "Int32Array";
+}
+
+interface Uint32Array {
+  readonly [Symbol.toStringTag]: "Uint32Array";
+}
+
+interface Float32Array {
+  readonly [Symbol.toStringTag]: "Float32Array";
+}
+
+interface Float64Array {
+  readonly [Symbol.toStringTag]: "Float64Array";
+}
+
+interface ArrayConstructor {
+  readonly [Symbol.species]: ArrayConstructor;
+}
+
+interface MapConstructor {
+  readonly [Symbol.species]: MapConstructor;
+}
+
+interface SetConstructor {
+  readonly [Symbol.species]: SetConstructor;
+}
+
+interface ArrayBufferConstructor {
+  readonly [Symbol.species]: ArrayBufferConstructor;
+}
+
+/* lib.es2015.core.d.ts */
+
+interface Array<T> {
+  find<S extends T>(predicate: (value: T, index: number, obj: T[]) => value is S, thisArg?: any): S | undefined;
+  find(predicate: (value: T, index: number, obj: T[]) => unknown, thisArg?: any): T | undefined;
+  findIndex(predicate: (value: T, index: number, obj: T[]) => unknown, thisArg?: any): number;
+  fill(value: T, start?: number, end?: number): this;
+  copyWithin(target: number, start: number, end?: number): this;
+  toLocaleString(locales: string | string[], options?: any): string;
+}
+
+interface ArrayConstructor {
+  from<T>(arrayLike: ArrayLike<T>): T[];
+  from<T, U>(arrayLike: ArrayLike<T>, mapfn: (v: T, k: number) => U, thisArg?: any): U[];
+  of<T>(...items: T[]): T[];
+}
+
+interface DateConstructor {
+  new (value: number | string | Date): Date;
+}
+
+interface Function {
+  readonly name: string;
+}
+
+interface Math {
+  clz32(x: number): number;
+  imul(x: number, y: number): number;
+  sign(x: number): number;
+  log10(x: number): number;
+  log2(x: number): number;
+  log1p(x: number): number;
+  expm1(x: number): number;
+  cosh(x: number): number;
+  sinh(x: number): number;
+  tanh(x: number): number;
+  acosh(x: number): number;
+  asinh(x: number): number;
+  atanh(x: number): number;
+  hypot(...values: number[]): number;
+  trunc(x: number): number;
+  fround(x: number): number;
+  cbrt(x: number): number;
+}
+
+interface NumberConstructor {
+  readonly EPSILON: number;
+  isFinite(number: unknown): boolean;
+  isInteger(number: unknown): boolean;
+  isNaN(number: unknown): boolean;
+  isSafeInteger(number: unknown): boolean;
+  readonly MAX_SAFE_INTEGER: number;
+  readonly MIN_SAFE_INTEGER: number;
+  parseFloat(string: string): number;
+  parseInt(string: string, radix?: number): number;
+}
+
+interface ObjectConstructor {
+  assign<T extends {}, U>(target: T, source: U): T & U;
+  assign<T extends {}, U, V>(target: T, source1: U, source2: V): T & U & V;
+  assign<T extends {}, U, V, W>(target: T, source1: U, source2: V, source3: W): T & U & V & W;
+  assign(target: object, ...sources: any[]): any;
+  getOwnPropertySymbols(o: any): symbol[];
+  keys(o: {}): string[];
+  is(value1: any, value2: any): boolean;
+  setPrototypeOf(o: any, proto: object | null): any;
+}
+
+interface ReadonlyArray<T> {
+  find<S extends T>(predicate: (value: T, index: number, obj: readonly T[]) => value is S, thisArg?: any): S | undefined;
+  find(predicate: (value: T, index: number, obj: readonly T[]) => unknown, thisArg?: any): T | undefined;
+  findIndex(predicate: (value: T, index: number, obj: readonly T[]) => unknown, thisArg?: any): number;
+  toLocaleString(locales: string | string[], options?: any): string;
+}
+
+interface RegExp {
+  readonly flags: string;
+  readonly sticky: boolean;
+  readonly unicode: boolean;
+}
+
+interface RegExpConstructor {
+  new (pattern: RegExp | string, flags?: string): RegExp;
+  (pattern: RegExp | string, flags?: string): RegExp;
+}
+
+interface String {
+  codePointAt(pos: number): number | undefined;
+  includes(searchString: string, position?: number): boolean;
+  endsWith(searchString: string, endPosition?: number): boolean;
+  normalize(form: "NFC" | "NFD" | "NFKC" | "NFKD"): string;
+  normalize(form?: string): string;
+  repeat(count: number): string;
+  startsWith(searchString: string, position?: number): boolean;
+  anchor(name: string): string;
+  big(): string;
+  blink(): string;
+  bold(): string;
+  fixed(): string;
+  fontcolor(color: string): string;
+  fontsize(size: number): string;
+  fontsize(size: string): string;
+  italics(): string;
+  link(url: string): string;
+  small(): string;
+  strike(): string;
+  sub(): string;
+  sup(): string;
+}
+
+interface StringConstructor {
+  fromCodePoint(...codePoints: number[]): string;
+  raw(template: { raw: readonly string[] | ArrayLike<string> }, ...substitutions: any[]): string;
+}
+
+/* lib.es2015.iterable.d.ts */
+
+interface SymbolConstructor {
+  readonly iterator: unique symbol;
+}
+
+interface IteratorYieldResult<TYield> {
+  done?: false;
+  value: TYield;
+}
+
+interface IteratorReturnResult<TReturn> {
+  done: true;
+  value: TReturn;
+}
+
+type IteratorResult<T, TReturn = any> = IteratorYieldResult<T> | IteratorReturnResult<TReturn>;
+
+interface Iterator<T, TReturn = any, TNext = any> {
+  next(...[value]: [] | [TNext]): IteratorResult<T, TReturn>;
+  return?(value?: TReturn): IteratorResult<T, TReturn>;
+  throw?(e?: any): IteratorResult<T, TReturn>;
+}
+
+interface Iterable<T, TReturn = any, TNext = any> {
+  [Symbol.iterator](): Iterator<T, TReturn, TNext>;
+}
+
+interface IterableIterator<T, TReturn = any, TNext = any> extends Iterator<T, TReturn, TNext> {
+  [Symbol.iterator](): IterableIterator<T, TReturn, TNext>;
+}
+
+interface Array<T> {
+  [Symbol.iterator](): IterableIterator<T>;
+  entries(): IterableIterator<[number, T]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<T>;
+}
+
+interface ArrayConstructor {
+  from<T>(iterable: Iterable<T> | ArrayLike<T>): T[];
+  from<T, U>(iterable: Iterable<T> | ArrayLike<T>, mapfn: (v: T, k: number) => U, thisArg?: any): U[];
+}
+
+interface ReadonlyArray<T> {
+  [Symbol.iterator](): IterableIterator<T>;
+  entries(): IterableIterator<[number, T]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<T>;
+}
+
+interface IArguments {
+  [Symbol.iterator](): IterableIterator<any>;
+}
+
+interface Map<K, V> {
+  [Symbol.iterator](): IterableIterator<[K, V]>;
+  entries(): IterableIterator<[K, V]>;
+  keys(): IterableIterator<K>;
+  values(): IterableIterator<V>;
+}
+
+interface ReadonlyMap<K, V> {
+  [Symbol.iterator](): IterableIterator<[K, V]>;
+  entries(): IterableIterator<[K, V]>;
+  keys(): IterableIterator<K>;
+  values(): IterableIterator<V>;
+}
+
+interface MapConstructor {
+  new (): Map<any, any>;
+  new <K, V>(iterable?: Iterable<readonly [K, V]> | null): Map<K, V>;
+}
+
+interface WeakMap<K extends WeakKey, V> {}
+
+interface WeakMapConstructor {
+  new <K extends WeakKey, V>(iterable: Iterable<readonly [K, V]>): WeakMap<K, V>;
+}
+
+interface Set<T> {
+  [Symbol.iterator](): IterableIterator<T>;
+  entries(): IterableIterator<[T, T]>;
+  keys(): IterableIterator<T>;
+  values(): IterableIterator<T>;
+}
+
+interface ReadonlySet<T> {
+  [Symbol.iterator](): IterableIterator<T>;
+  entries(): IterableIterator<[T, T]>;
+  keys(): IterableIterator<T>;
+  values(): IterableIterator<T>;
+}
+
+interface SetConstructor {
+  new <T>(iterable?: Iterable<T> | null): Set<T>;
+}
+
+interface WeakSet<T extends WeakKey> {}
+
+interface WeakSetConstructor {
+  new <T extends WeakKey = WeakKey>(iterable: Iterable<T>): WeakSet<T>;
+}
+
+interface Promise<T> {}
+
+interface PromiseConstructor {
+  all<T>(values: Iterable<T | PromiseLike<T>>): Promise<Awaited<T>[]>;
+  race<T>(values: Iterable<T | PromiseLike<T>>): Promise<Awaited<T>>;
+}
+
+interface String {
+  [Symbol.iterator](): IterableIterator<string>;
+}
+
+interface Int8Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Int8ArrayConstructor {
+  new (elements: Iterable<number>): Int8Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Int8Array;
+}
+
+interface Uint8Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Uint8ArrayConstructor {
+  new (elements: Iterable<number>): Uint8Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Uint8Array;
+}
+
+interface Uint8ClampedArray {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Uint8ClampedArrayConstructor {
+  new (elements: Iterable<number>): Uint8ClampedArray;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Uint8ClampedArray;
+}
+
+interface Int16Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Int16ArrayConstructor {
+  new (elements: Iterable<number>): Int16Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Int16Array;
+}
+
+interface Uint16Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Uint16ArrayConstructor {
+  new (elements: Iterable<number>): Uint16Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Uint16Array;
+}
+
+interface Int32Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Int32ArrayConstructor {
+  new (elements: Iterable<number>): Int32Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Int32Array;
+}
+
+interface Uint32Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Uint32ArrayConstructor {
+  new (elements: Iterable<number>): Uint32Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Uint32Array;
+}
+
+interface Float32Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Float32ArrayConstructor {
+  new (elements: Iterable<number>): Float32Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Float32Array;
+}
+
+interface Float64Array {
+  [Symbol.iterator](): IterableIterator<number>;
+  entries(): IterableIterator<[number, number]>;
+  keys(): IterableIterator<number>;
+  values(): IterableIterator<number>;
+}
+
+interface Float64ArrayConstructor {
+  new (elements: Iterable<number>): Float64Array;
+  from(arrayLike: Iterable<number>, mapfn?: (v: number, k: number) => number, thisArg?: any): Float64Array;
+}
+
+/* lib.es2015.generator.d.ts */
+
+interface Generator<T = unknown, TReturn = any, TNext = any> extends Iterator<T, TReturn, TNext> {
+  next(...[value]: [] | [TNext]): IteratorResult<T, TReturn>;
+  return(value: TReturn): IteratorResult<T, TReturn>;
+  throw(e: any): IteratorResult<T, TReturn>;
+  [Symbol.iterator](): Generator<T, TReturn, TNext>;
+}
+
+interface GeneratorFunction {
+  new (...args: any[]): Generator;
+  (...args: any[]): Generator;
+  readonly length: number;
+  readonly name: string;
+  readonly prototype: Generator;
+}
+
+interface GeneratorFunctionConstructor {
+  new (...args: string[]): GeneratorFunction;
+  (...args: string[]): GeneratorFunction;
+  readonly length: number;
+  readonly name: string;
+  readonly prototype: GeneratorFunction;
+}
+
+/* lib.es2015.collection.d.ts */
+
+interface Map<K, V> {
+  clear(): void;
+  delete(key: K): boolean;
+  forEach(callbackfn: (value: V, key: K, map: Map<K, V>) => void, thisArg?: any): void;
+  get(key: K): V | undefined;
+  has(key: K): boolean;
+  set(key: K, value: V): this;
+  readonly size: number;
+}
+
+interface MapConstructor {
+  new (): Map<any, any>;
+  new <K, V>(entries?: readonly (readonly [K, V])[] | null): Map<K, V>;
+  readonly prototype: Map<any, any>;
+}
+
+declare var Map: MapConstructor;
+
+interface ReadonlyMap<K, V> {
+  forEach(callbackfn: (value: V, key: K, map: ReadonlyMap<K, V>) => void, thisArg?: any): void;
+  get(key: K): V | undefined;
+  has(key: K): boolean;
+  readonly size: number;
+}
+
+interface WeakMap<K extends WeakKey, V> {
+  delete(key: K): boolean;
+  get(key: K): V | undefined;
+  has(key: K): boolean;
+  set(key: K, value: V): this;
+}
+
+interface WeakMapConstructor {
+  new <K extends WeakKey = WeakKey, V = any>(entries?: readonly (readonly [K, V])[] | null): WeakMap<K, V>;
+  readonly prototype: WeakMap<WeakKey, any>;
+}
+
+declare var WeakMap: WeakMapConstructor;
+
+interface Set<T> {
+  add(value: T): this;
+  clear(): void;
+  delete(value: T): boolean;
+  forEach(callbackfn: (value: T, value2: T, set: Set<T>) => void, thisArg?: any): void;
+  has(value: T): boolean;
+  readonly size: number;
+}
+
+interface SetConstructor {
+  new <T = any>(values?: readonly T[] | null): Set<T>;
+  readonly prototype: Set<any>;
+}
+
+declare var Set: SetConstructor;
+
+interface ReadonlySet<T> {
+  forEach(callbackfn: (value: T, value2: T, set: ReadonlySet<T>) => void, thisArg?: any): void;
+  has(value: T): boolean;
+  readonly size: number;
+}
+
+interface WeakSet<T extends WeakKey> {
+  add(value: T): this;
+  delete(value: T): boolean;
+  has(value: T): boolean;
+}
+
+interface WeakSetConstructor {
+  new <T extends WeakKey = WeakKey>(values?: readonly T[] | null): WeakSet<T>;
+  readonly prototype: WeakSet<WeakKey>;
+}
+
+declare var WeakSet: WeakSetConstructor;
+
+/* lib.es2015.promise.d.ts */
+
+interface PromiseConstructor {
+  readonly prototype: Promise<any>;
+  new <T>(executor: (resolve: (value: T | PromiseLike<T>) => void, reject: (reason?: any) => void) => void): Promise<T>;
+  all<T extends readonly unknown[] | []>(values: T): Promise<{ -readonly [P in keyof T]: Awaited<T[P]> }>;
+  race<T extends readonly unknown[] | []>(values: T): Promise<Awaited<T[number]>>;
+  reject<T = never>(reason?: any): Promise<T>;
+  resolve(): Promise<void>;
+  resolve<T>(value: T): Promise<Awaited<T>>;
+  resolve<T>(value: T | PromiseLike<T>): Promise<Awaited<T>>;
+}
+
+declare var Promise: PromiseConstructor;
+
+/* lib.es2015.proxy.d.ts */
+
+interface ProxyHandler<T extends object> {
+  apply?(target: T, thisArg: any, argArray: any[]): any;
+  construct?(target: T, argArray: any[], newTarget: Function): object;
+  defineProperty?(target: T, property: string | symbol, attributes: PropertyDescriptor): boolean;
+  deleteProperty?(target: T, p: string | symbol): boolean;
+  get?(target: T, p: string | symbol, receiver: any): any;
+  getOwnPropertyDescriptor?(target: T, p: string | symbol): PropertyDescriptor | undefined;
+  getPrototypeOf?(target: T): object | null;
+  has?(target: T, p: string | symbol): boolean;
+  isExtensible?(target: T): boolean;
+  ownKeys?(target: T): ArrayLike<string | symbol>;
+  preventExtensions?(target: T): boolean;
+  set?(target: T, p: string | symbol, newValue: any, receiver: any): boolean;
+  setPrototypeOf?(target: T, v: object | null): boolean;
+}
+
+interface ProxyConstructor {
+  revocable<T extends object>(target: T, handler: ProxyHandler<T>): { proxy: T; revoke: () => void };
+  new <T extends object>(target: T, handler: ProxyHandler<T>): T;
+}
+
+declare var Proxy: ProxyConstructor;
+
+/* lib.es2015.reflect.d.ts */
+
+declare namespace Reflect {
+  function apply<T, A extends readonly any[], R>(target: (this: T, ...args: A) => R, thisArgument: T, argumentsList: Readonly<A>): R;
+  function apply(target: Function, thisArgument: any, argumentsList: ArrayLike<any>): any;
+  function construct<A extends readonly any[], R>(target: new (...args: A) => R, argumentsList: Readonly<A>, newTarget?: new (...args: any) => any): R;
+  function construct(target: Function, argumentsList: ArrayLike<any>, newTarget?: Function): any;
+  function defineProperty(target: object, propertyKey: PropertyKey, attributes: PropertyDescriptor & ThisType<any>): boolean;
+  function deleteProperty(target: object, propertyKey: PropertyKey): boolean;
+  function get<T extends object, P extends PropertyKey>(target: T, propertyKey: P, receiver?: unknown): P extends keyof T ? T[P] : any;
+  function getOwnPropertyDescriptor<T extends object, P extends PropertyKey>(target: T, propertyKey: P): TypedPropertyDescriptor<P extends keyof T ? T[P] : any> | undefined;
+  function getPrototypeOf(target: object): object | null;
+  function has(target: object, propertyKey: PropertyKey): boolean;
+  function isExtensible(target: object): boolean;
+  function ownKeys(target: object): (string | symbol)[];
+  function preventExtensions(target: object): boolean;
+  function set<T extends object, P extends PropertyKey>(target: T, propertyKey: P, value: P extends keyof T ? T[P] : any, receiver?: any): boolean;
+  function set(target: object, propertyKey: PropertyKey, value: any, receiver?: any): boolean;
+  function setPrototypeOf(target: object, proto: object | null): boolean;
+}
@@ -0,0 +1,62 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Declarations from TypeScript's `lib.es2016.*.d.ts`, with the documentation comments stripped.
+
+/* lib.es2016.array.include.d.ts */
+
+interface Array<T> {
+  includes(searchElement: T, fromIndex?: number): boolean;
+}
+
+interface ReadonlyArray<T> {
+  includes(searchElement: T, fromIndex?: number): boolean;
+}
+
+interface Int8Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Uint8Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Uint8ClampedArray {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Int16Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Uint16Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Int32Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Uint32Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Float32Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
+
+interface Float64Array {
+  includes(searchElement: number, fromIndex?: number): boolean;
+}
@@ -0,0 +1,85 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Declarations from TypeScript's `lib.es2018.*.d.ts`, with the documentation comments stripped.
+
+/* lib.es2018.asynciterable.d.ts */
+
+interface SymbolConstructor {
+  readonly asyncIterator: unique symbol;
+}
+
+interface AsyncIterator<T, TReturn = any, TNext = any> {
+  next(...[value]: [] | [TNext]): Promise<IteratorResult<T, TReturn>>;
+  return?(value?: TReturn | PromiseLike<TReturn>): Promise<IteratorResult<T, TReturn>>;
+  throw?(e?: any): Promise<IteratorResult<T, TReturn>>;
+}
+
+interface AsyncIterable<T, TReturn = any, TNext = any> {
+  [Symbol.asyncIterator](): AsyncIterator<T, TReturn, TNext>;
+}
+
+interface AsyncIterableIterator<T, TReturn = any, TNext = any> extends AsyncIterator<T, TReturn, TNext> {
+  [Symbol.asyncIterator](): AsyncIterableIterator<T, TReturn, TNext>;
+}
+
+/* lib.es2018.asyncgenerator.d.ts */
+
+interface AsyncGenerator<T = unknown, TReturn = any, TNext = any> extends AsyncIterator<T, TReturn, TNext> {
+  next(...[value]: [] | [TNext]): Promise<IteratorResult<T, TReturn>>;
+  return(value: TReturn | PromiseLike<TReturn>): Promise<IteratorResult<T, TReturn>>;
+  throw(e: any): Promise<IteratorResult<T, TReturn>>;
+  [Symbol.asyncIterator](): AsyncGenerator<T, TReturn, TNext>;
+}
+
+interface AsyncGeneratorFunction {
+  new (...args: any[]): AsyncGenerator;
+  (...args: any[]): AsyncGenerator;
+  readonly length: number;
+  readonly name: string;
+  readonly prototype: AsyncGenerator;
+}
+
+interface AsyncGeneratorFunctionConstructor {
+  new (...args: string[]): AsyncGeneratorFunction;
+  (...args: string[]): AsyncGeneratorFunction;
+  readonly length: number;
+  readonly name: string;
+  readonly prototype: AsyncGeneratorFunction;
+}
+
+/* lib.es2018.promise.d.ts */
+
+interface Promise<T> {
+  finally(onfinally?: (() => void) | undefined | null): Promise<T>;
+}
+
+/* lib.es2018.regexp.d.ts */
+
+interface RegExpMatchArray {
+  groups?: {
+    [key: string]: string;
+  };
+}
+
+interface RegExpExecArray {
+  groups?: {
+    [key: string]: string;
+  };
+}
+
+interface RegExp {
+  readonly dotAll: boolean;
+}
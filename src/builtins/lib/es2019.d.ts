@@ -0,0 +1,57 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Declarations from TypeScript's `lib.es2019.*.d.ts`, with the documentation comments stripped.
+
+/* lib.es2019.array.d.ts */
+
+type FlatArray<Arr, Depth extends number> = {
+  done: Arr;
+  recur: Arr extends ReadonlyArray<infer InnerArr>
+    ? FlatArray<InnerArr, [-1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20][Depth]>
+    : Arr;
+}[Depth extends -1 ? "done" : "recur"];
+
+interface ReadonlyArray<T> {
+  flatMap<U, This = undefined>(callback: (this: This, value: T, index: number, array: T[]) => U | ReadonlyArray<U>, thisArg?: This): U[];
+  flat<A, D extends number = 1>(this: A, depth?: D): FlatArray<A, D>[];
+}
+
+interface Array<T> {
+  flatMap<U, This = undefined>(callback: (this: This, value: T, index: number, array: T[]) => U | ReadonlyArray<U>, thisArg?: This): U[];
+  flat<A, D extends number = 1>(this: A, depth?: D): FlatArray<A, D>[];
+}
+
+/* lib.es2019.object.d.ts */
+
+interface ObjectConstructor {
+  fromEntries<T = any>(entries: Iterable<readonly [PropertyKey, T]>): { [k: string]: T };
+  fromEntries(entries: Iterable<readonly any[]>): any;
+}
+
+/* lib.es2019.string.d.ts */
+
+interface String {
+  trimEnd(): string;
+  trimStart(): string;
+  trimLeft(): string;
+  trimRight(): string;
+}
+
+/* lib.es2019.symbol.d.ts */
+
+interface Symbol {
+  readonly description: string | undefined;
+}
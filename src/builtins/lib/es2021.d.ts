@@ -0,0 +1,69 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Declarations from TypeScript's `lib.es2021.*.d.ts`, with the documentation comments stripped.
+
+/* lib.es2021.promise.d.ts */
+
+interface AggregateError extends Error {
+  errors: any[];
+}
+
+interface AggregateErrorConstructor {
+  new (errors: Iterable<any>, message?: string): AggregateError;
+  (errors: Iterable<any>, message?: string): AggregateError;
+  readonly prototype: AggregateError;
+}
+
+declare var AggregateError: AggregateErrorConstructor;
+
+interface PromiseConstructor {
+  any<T extends readonly unknown[] | []>(values: T): Promise<Awaited<T[number]>>;
+  any<T>(values: Iterable<T | PromiseLike<T>>): Promise<Awaited<T>>;
+}
+
+/* lib.es2021.string.d.ts */
+
+interface String {
+  replaceAll(searchValue: string | RegExp, replaceValue: string): string;
+  replaceAll(searchValue: string | RegExp, replacer: (substring: string, ...args: any[]) => string): string;
+}
+
+/* lib.es2021.weakref.d.ts */
+
+interface WeakRef<T extends WeakKey> {
+  readonly [Symbol.toStringTag]: "WeakRef";
+  deref(): T | undefined;
+}
+
+interface WeakRefConstructor {
+  readonly prototype: WeakRef<any>;
+  new <T extends WeakKey>(target: T): WeakRef<T>;
+}
+
+declare var WeakRef: WeakRefConstructor;
+
+interface FinalizationRegistry<T> {
+  readonly [Symbol.toStringTag]: "FinalizationRegistry";
+  register(target: WeakKey, heldValue: T, unregisterToken?: WeakKey): void;
+  unregister(unregisterToken: WeakKey): boolean;
+}
+
+interface FinalizationRegistryConstructor {
+  readonly prototype: FinalizationRegistry<any>;
+  new <T>(cleanupCallback: (heldValue: T) => void): FinalizationRegistry<T>;
+}
+
+declare var FinalizationRegistry: FinalizationRegistryConstructor;
@@ -0,0 +1,127 @@
+/*! *****************************************************************************
+Copyright (c) Microsoft Corporation. All rights reserved.
+Licensed under the Apache License, Version 2.0 (the "License"); you may not use
+this file except in compliance with the License. You may obtain a copy of the
+License at http://www.apache.org/licenses/LICENSE-2.0
+
+THIS CODE IS PROVIDED ON AN *AS IS* BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+KIND, EITHER EXPRESS OR IMPLIED, INCLUDING WITHOUT LIMITATION ANY IMPLIED
+WARRANTIES OR CONDITIONS OF TITLE, FITNESS FOR A PARTICULAR PURPOSE,
+MERCHANTABLITY OR NON-INFRINGEMENT.
+
+See the Apache Version 2.0 License for specific language governing permissions
+and limitations under the License.
+***************************************************************************** */
+
+/// Condensed from TypeScript's `lib.webworker.d.ts`. Only a small subset is declared: the console,
+/// message events, `self`, `location`, `navigator`, `URL`, `fetch` and timers. Other globals are not
+/// found, and other members are missing from the declared types.
+
+interface Console {
+  assert(condition?: boolean, ...data: any[]): void;
+  clear(): void;
+  debug(...data: any[]): void;
+  error(...data: any[]): void;
+  info(...data: any[]): void;
+  log(...data: any[]): void;
+  table(tabularData?: any, properties?: string[]): void;
+  time(label?: string): void;
+  timeEnd(label?: string): void;
+  trace(...data: any[]): void;
+  warn(...data: any[]): void;
+}
+
+declare var console: Console;
+
+interface Event {
+  readonly type: string;
+  preventDefault(): void;
+  stopPropagation(): void;
+}
+
+interface MessageEvent extends Event {
+  readonly data: any;
+}
+
+interface WorkerGlobalScope {
+  readonly location: WorkerLocation;
+  readonly navigator: WorkerNavigator;
+  importScripts(...urls: string[]): void;
+  postMessage(message: any): void;
+  close(): void;
+}
+
+interface WorkerLocation {
+  readonly href: string;
+  readonly hostname: string;
+  readonly pathname: string;
+}
+
+interface WorkerNavigator {
+  readonly language: string;
+  readonly languages: readonly string[];
+  readonly onLine: boolean;
+  readonly userAgent: string;
+}
+
+declare var self: WorkerGlobalScope;
+declare var location: WorkerLocation;
+declare var navigator: WorkerNavigator;
+declare var onmessage: ((event: MessageEvent) => void) | null;
+
+interface URLSearchParams {
+  readonly size: number;
+  append(name: string, value: string): void;
+  delete(name: string, value?: string): void;
+  get(name: string): string | null;
+  getAll(name: string): string[];
+  has(name: string, value?: string): boolean;
+  set(name: string, value: string): void;
+  toString(): string;
+}
+
+interface URLSearchParamsConstructor {
+  new (init?: string[][] | Record<string, string> | string | URLSearchParams): URLSearchParams;
+  readonly prototype: URLSearchParams;
+}
+
+declare var URLSearchParams: URLSearchParamsConstructor;
+
+interface URL {
+  hash: string;
+  host: string;
+  hostname: string;
+  href: string;
+  readonly origin: string;
+  pathname: string;
+  port: string;
+  protocol: string;
+  search: string;
+  readonly searchParams: URLSearchParams;
+  toJSON(): string;
+  toString(): string;
+}
+
+interface URLConstructor {
+  new (url: string | URL, base?: string | URL): URL;
+  readonly prototype: URL;
+  canParse(url: string | URL, base?: string | URL): boolean;
+}
+
+declare var URL: URLConstructor;
+
+interface Response {
+  readonly ok: boolean;
+  readonly status: number;
+  json(): Promise<any>;
+  text(): Promise<string>;
+}
+
+declare function importScripts(...urls: string[]): void;
+declare function postMessage(message: any): void;
+declare function fetch(input: string): Promise<Response>;
+declare function setTimeout(handler: () => void, timeout?: number): number;
+declare function clearTimeout(id: number | undefined): void;
+declare function setInterval(handler: () => void, timeout?: number): number;
+declare function clearInterval(id: number | undefined): void;
+declare function queueMicrotask(callback: () => void): void;
@@ -0,0 +1,58 @@
+use crate::config::Lib;
+
+impl Lib {
+  fn source(self) -> &'static str {
+    match self {
+      Lib::ES5 => include_str!("lib/es5.d.ts"),
+      Lib::ES2015 => include_str!("lib/es2015.d.ts"),
+      Lib::ES2016 => include_str!("lib/es2016.d.ts"),
+      Lib::ES2017 => include_str!("lib/es2017.d.ts"),
+      Lib::ES2018 => include_str!("lib/es2018.d.ts"),
+      Lib::ES2019 => include_str!("lib/es2019.d.ts"),
+      Lib::ES2020 => include_str!("lib/es2020.d.ts"),
+      Lib::ES2021 => include_str!("lib/es2021.d.ts"),
+      Lib::ES2022 => include_str!("lib/es2022.d.ts"),
+      Lib::ES2023 => include_str!("lib/es2023.d.ts"),
+      Lib::ESNext => include_str!("lib/esnext.d.ts"),
+      Lib::Dom => include_str!("lib/dom.d.ts"),
+      Lib::WebWorker => include_str!("lib/webworker.d.ts"),
+    }
+  }
+
+  /// Like `/// <reference lib="..." />` in TypeScript's lib files.
+  fn reference(self) -> Option<Lib> {
+    match self {
+      Lib::ES5 => None,
+      Lib::ES2015 => Some(Lib::ES5),
+      Lib::ES2016 => Some(Lib::ES2015),
+      Lib::ES2017 => Some(Lib::ES2016),
+      Lib::ES2018 => Some(Lib::ES2017),
+      Lib::ES2019 => Some(Lib::ES2018),
+      Lib::ES2020 => Some(Lib::ES2019),
+      Lib::ES2021 => Some(Lib::ES2020),
+      Lib::ES2022 => Some(Lib::ES2021),
+      Lib::ES2023 => Some(Lib::ES2022),
+      Lib::ESNext => Some(Lib::ES2023),
+      // DOM libs are always loaded with at least ES5
+      Lib::Dom | Lib::WebWorker => Some(Lib::ES5),
+    }
+  }
+
+  fn collect(self, libs: &mut Vec<Lib>) {
+    if !libs.contains(&self) {
+      if let Some(reference) = self.reference() {
+        reference.collect(libs);
+      }
+      libs.push(self);
+    }
+  }
+}
+
+/// Concatenate the sources of the libs and their references, in load order.
+pub fn concat_lib_sources(libs: &[Lib]) -> String {
+  let mut collected = vec![];
+  for lib in libs {
+    lib.collect(&mut collected);
+  }
+  collected.into_iter().map(Lib::source).collect()
+}
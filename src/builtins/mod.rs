@@ -1,8 +1,20 @@
 mod globals;
+mod libs;
+
+use oxc::semantic::{Semantic, SymbolId};
+use rustc_hash::FxHashMap;
 
 use crate::ty::Ty;
 
 pub struct Builtins<'a> {
+  /// The semantic of the lib, set once the lib is loaded. The symbols declared in the lib are
+  /// read as globals afterwards.
+  pub semantic: Option<&'a Semantic<'a>>,
+  pub globals: FxHashMap<&'a str, Ty<'a>>,
+  pub global_types: FxHashMap<&'a str, Ty<'a>>,
+  /// Reversed `global_types`, used to print global types by name
+  pub global_type_names: FxHashMap<Ty<'a>, &'a str>,
+
   pub string_prototype: Ty<'a>,
   pub number_prototype: Ty<'a>,
   pub bigint_prototype: Ty<'a>,
@@ -11,12 +23,21 @@ pub struct Builtins<'a> {
   pub function_prototype: Ty<'a>,
   pub array_prototype: Ty<'a>,
   pub symbol_prototype: Ty<'a>,
+
+  /// `Symbol.iterator` and `Symbol.asyncIterator`, absent before ES2015 and ES2018
+  pub symbol_iterator: Option<SymbolId>,
+  pub symbol_async_iterator: Option<SymbolId>,
 }
 
 impl<'a> Builtins<'a> {
-  // TODO: Implement this
+  /// Placeholder before the libs are loaded
   pub fn new() -> Self {
     Self {
+      semantic: None,
+      globals: Default::default(),
+      global_types: Default::default(),
+      global_type_names: Default::default(),
+
       string_prototype: Ty::Any,
       number_prototype: Ty::Any,
       bigint_prototype: Ty::Any,
@@ -25,6 +46,9 @@ impl<'a> Builtins<'a> {
       function_prototype: Ty::Any,
       array_prototype: Ty::Any,
       symbol_prototype: Ty::Any,
+
+      symbol_iterator: None,
+      symbol_async_iterator: None,
     }
   }
 }
@@ -1,8 +1,119 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Target {
+  ES5,
+  ES2015,
+  ES2016,
+  ES2017,
+  ES2018,
+  ES2019,
+  ES2020,
+  ES2021,
+  ES2022,
+  ES2023,
+  ES2024,
+  ESNext,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lib {
+  ES5,
+  ES2015,
+  ES2016,
+  ES2017,
+  ES2018,
+  ES2019,
+  ES2020,
+  ES2021,
+  ES2022,
+  ES2023,
+  ESNext,
+  Dom,
+  WebWorker,
+}
+
+impl Target {
+  /// The ECMAScript lib that matches the target.
+  pub fn lib(self) -> Lib {
+    match self {
+      Target::ES5 => Lib::ES5,
+      Target::ES2015 => Lib::ES2015,
+      Target::ES2016 => Lib::ES2016,
+      Target::ES2017 => Lib::ES2017,
+      Target::ES2018 => Lib::ES2018,
+      Target::ES2019 => Lib::ES2019,
+      Target::ES2020 => Lib::ES2020,
+      Target::ES2021 => Lib::ES2021,
+      Target::ES2022 => Lib::ES2022,
+      // There is no bundled ES2024 lib
+      Target::ES2023 | Target::ES2024 => Lib::ES2023,
+      Target::ESNext => Lib::ESNext,
+    }
+  }
+}
+
+/// The `jsx` compiler option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jsx {
+  Preserve,
+  React,
+  ReactJsx,
+  ReactJsxDev,
+  ReactNative,
+}
+
 #[derive(Debug, Clone)]
-pub struct Config {}
+pub struct Config {
+  pub target: Target,
+  /// The lib files to load. When `None`, the default libs of `target` are loaded, which are the
+  /// matching ECMAScript lib and `dom`.
+  pub lib: Option<Vec<Lib>>,
+  pub strict_null_checks: bool,
+  pub exact_optional_property_types: bool,
+  pub no_unchecked_indexed_access: bool,
+  pub jsx: Option<Jsx>,
+  pub jsx_import_source: Option<String>,
+  /// The directory of non-relative module specifiers, from `baseUrl`.
+  pub base_url: Option<PathBuf>,
+  /// The directory of the substitutions in `paths`, which is `baseUrl`, or the directory of the
+  /// config that sets `paths`.
+  pub paths_base: Option<PathBuf>,
+  /// The `paths` patterns and their substitutions, in declaration order.
+  pub paths: Vec<(String, Vec<String>)>,
+  /// The files in the program, from `files`. Absolute when loaded from a `tsconfig.json`.
+  pub files: Vec<PathBuf>,
+  /// The glob patterns of the files in the program, from `include`.
+  pub include: Vec<String>,
+  /// The glob patterns of the files excluded from `include`.
+  pub exclude: Vec<String>,
+}
+
+impl Config {
+  pub fn libs(&self) -> Vec<Lib> {
+    match &self.lib {
+      Some(lib) => lib.clone(),
+      None => vec![self.target.lib(), Lib::Dom],
+    }
+  }
+}
 
 impl Default for Config {
   fn default() -> Self {
-    Self {}
+    Self {
+      target: Target::ESNext,
+      lib: None,
+      strict_null_checks: true,
+      exact_optional_property_types: false,
+      no_unchecked_indexed_access: false,
+      jsx: None,
+      jsx_import_source: None,
+      base_url: None,
+      paths_base: None,
+      paths: vec![],
+      files: vec![],
+      include: vec![],
+      exclude: vec![],
+    }
   }
 }
@@ -0,0 +1,737 @@
+use oxc::{
+  allocator::{Box, CloneIn, Vec},
+  ast::{
+    ast::{
+      BindingIdentifier, BindingPatternKind, Class, ClassElement, Declaration,
+      ExportDefaultDeclaration, ExportDefaultDeclarationKind, Expression, FormalParameters,
+      Function, IdentifierReference, MethodDefinition, MethodDefinitionKind, ObjectPropertyKind,
+      Program, PropertyDefinitionType, PropertyKey, PropertyKind, Statement, TSAccessibility,
+      TSExportAssignment, TSModuleDeclaration, TSModuleDeclarationBody, TSModuleDeclarationName,
+      TSSignature, TSType, TSTypeAnnotation, TSTypePredicateName, VariableDeclaration,
+      VariableDeclarationKind,
+    },
+    Visit, NONE,
+  },
+  codegen::Codegen,
+  span::{GetSpan, SourceType, SPAN},
+};
+use oxc_ecmascript::BoundNames;
+use rustc_hash::FxHashSet;
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{callable::FunctionType, Ty},
+};
+
+/// The name of the variable that holds the default export, like `tsc` does.
+const DEFAULT_EXPORT_NAME: &str = "_default";
+
+impl<'a> Analyzer<'a> {
+  /// Emit the declaration file of an analyzed program. Unannotated exports are typed with the
+  /// inferred types, so `isolatedDeclarations` is not required.
+  pub fn emit_declarations(&mut self, program: &'a Program<'a>) -> String {
+    // Local declarations which are exported via `export { a }` or `export default a`
+    let mut exported_locals = FxHashSet::default();
+    for statement in &program.body {
+      match statement {
+        Statement::ExportNamedDeclaration(node) if node.source.is_none() => {
+          for specifier in &node.specifiers {
+            exported_locals.insert(specifier.local.name().as_str());
+          }
+        }
+        Statement::ExportDefaultDeclaration(node) => {
+          if let ExportDefaultDeclarationKind::Identifier(id) = &node.declaration {
+            exported_locals.insert(id.name.as_str());
+          }
+        }
+        Statement::TSExportAssignment(node) => {
+          if let Expression::Identifier(id) = &node.expression {
+            exported_locals.insert(id.name.as_str());
+          }
+        }
+        _ => {}
+      }
+    }
+
+    // Each statement is emitted into its slot, so that the order is kept
+    let mut slots: std::vec::Vec<Vec<'a, Statement<'a>>> = vec![];
+    // Local declarations which are not exported, and are emitted only if referenced
+    let mut locals = vec![];
+    let mut prev_function = None;
+    for (index, statement) in program.body.iter().enumerate() {
+      let mut emitted = self.ast_builder.vec();
+      let function = as_function_declaration(statement);
+      let is_overload_implementation = matches!(
+        (prev_function, function),
+        (Some(prev), Some(function)) if is_overload_implementation(prev, function)
+      );
+      prev_function = function;
+      if is_overload_implementation {
+        slots.push(emitted);
+        continue;
+      }
+      match statement {
+        Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSInterfaceDeclaration(_)
+        | Statement::TSTypeAliasDeclaration(_) => {
+          emitted.push(statement.clone_in(self.allocator));
+        }
+        Statement::ExportNamedDeclaration(node) => {
+          let declaration = node.declaration.as_ref().map(|node| self.emit_declaration(node, true));
+          let mut node = node.clone_in(self.allocator);
+          node.declaration = declaration;
+          emitted.push(Statement::ExportNamedDeclaration(node));
+        }
+        Statement::ExportDefaultDeclaration(node) => self.emit_default_export(node, &mut emitted),
+        Statement::TSExportAssignment(node) => self.emit_export_assignment(node, &mut emitted),
+        // `declare global {}` and `declare module "m" {}` are always emitted
+        Statement::TSModuleDeclaration(node)
+          if !matches!(node.id, TSModuleDeclarationName::Identifier(_)) =>
+        {
+          let declaration = self.emit_module_declaration(node, true);
+          emitted.push(Statement::TSModuleDeclaration(declaration));
+        }
+        Statement::VariableDeclaration(_)
+        | Statement::FunctionDeclaration(_)
+        | Statement::ClassDeclaration(_)
+        | Statement::TSEnumDeclaration(_)
+        | Statement::TSModuleDeclaration(_) => {
+          let declaration = statement.as_declaration().unwrap();
+          let mut is_exported = false;
+          declared_names(declaration, &mut |id| {
+            is_exported |= exported_locals.contains(id.name.as_str());
+          });
+          if is_exported {
+            let declaration = self.emit_declaration(declaration, true);
+            emitted.push(Statement::from(declaration));
+          } else {
+            locals.push((index, declaration));
+          }
+        }
+        _ => {}
+      }
+      slots.push(emitted);
+    }
+
+    // The local declarations referenced by the emitted ones are emitted without being exported,
+    // like `declare class Hidden {}` for `export const h = new Hidden()`
+    let mut referenced = FxHashSet::default();
+    let mut unvisited: std::vec::Vec<usize> = (0..slots.len()).collect();
+    while !unvisited.is_empty() {
+      let mut collector = ReferencedNames(&mut referenced);
+      for index in unvisited.drain(..) {
+        for statement in &slots[index] {
+          collector.visit_statement(statement);
+        }
+      }
+      locals.retain(|(index, declaration)| {
+        let mut is_referenced = false;
+        declared_names(declaration, &mut |id| {
+          is_referenced |= referenced.contains(id.name.as_str());
+        });
+        if is_referenced {
+          unvisited.push(*index);
+        }
+        !is_referenced
+      });
+      for index in &unvisited {
+        let declaration = program.body[*index].as_declaration().unwrap();
+        let declaration = self.emit_declaration(declaration, true);
+        slots[*index].push(Statement::from(declaration));
+      }
+    }
+
+    let mut body = self.ast_builder.vec();
+    for slot in slots {
+      body.extend(slot);
+    }
+
+    let program = self.ast_builder.program(
+      SPAN,
+      SourceType::d_ts(),
+      "",
+      self.ast_builder.vec(),
+      None,
+      self.ast_builder.vec(),
+      body,
+    );
+    Codegen::new().build(&program).code
+  }
+
+  fn emit_default_export(
+    &mut self,
+    node: &'a ExportDefaultDeclaration<'a>,
+    body: &mut Vec<'a, Statement<'a>>,
+  ) {
+    let declaration = match &node.declaration {
+      ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+        let ty = match &function.id {
+          Some(id) => self.get_type_by_span(id.span),
+          None => self.default_export,
+        };
+        ExportDefaultDeclarationKind::FunctionDeclaration(self.emit_function(function, ty, false))
+      }
+      ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+        ExportDefaultDeclarationKind::ClassDeclaration(self.emit_class(class, false))
+      }
+      ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+      | ExportDefaultDeclarationKind::Identifier(_) => node.declaration.clone_in(self.allocator),
+      _ => {
+        // `export default expr` becomes `declare const _default: T; export default _default;`
+        let ty = self.default_export.unwrap_or(Ty::Any);
+        self.emit_default_variable(ty, node.declaration.as_expression(), body);
+        ExportDefaultDeclarationKind::Identifier(
+          self.ast_builder.alloc_identifier_reference(SPAN, DEFAULT_EXPORT_NAME),
+        )
+      }
+    };
+    body.push(Statement::from(self.ast_builder.module_declaration_export_default_declaration(
+      node.span,
+      declaration,
+      node.exported.clone_in(self.allocator),
+    )));
+  }
+
+  /// `export = expr` becomes `declare const _default: T; export = _default;`, unless `expr` is an
+  /// identifier.
+  fn emit_export_assignment(
+    &mut self,
+    node: &'a TSExportAssignment<'a>,
+    body: &mut Vec<'a, Statement<'a>>,
+  ) {
+    let expression = if let Expression::Identifier(_) = &node.expression {
+      node.expression.clone_in(self.allocator)
+    } else {
+      let ty = self.get_type_by_span(node.expression.span()).unwrap_or(Ty::Any);
+      self.emit_default_variable(ty, Some(&node.expression), body);
+      self.ast_builder.expression_identifier_reference(SPAN, DEFAULT_EXPORT_NAME)
+    };
+    body.push(Statement::from(
+      self.ast_builder.module_declaration_ts_export_assignment(node.span, expression),
+    ));
+  }
+
+  fn emit_default_variable(
+    &mut self,
+    ty: Ty<'a>,
+    init: Option<&'a Expression<'a>>,
+    body: &mut Vec<'a, Statement<'a>>,
+  ) {
+    let mut annotation = self.serialize_type_annotation(ty);
+    if let Some(init) = init {
+      self.rename_initializer_parameters(&mut annotation.type_annotation, init);
+    }
+    let id = self.ast_builder.binding_pattern(
+      self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, DEFAULT_EXPORT_NAME),
+      Some(annotation),
+      false,
+    );
+    let declarator =
+      self.ast_builder.variable_declarator(SPAN, VariableDeclarationKind::Const, id, None, false);
+    body.push(Statement::from(self.ast_builder.declaration_variable(
+      SPAN,
+      VariableDeclarationKind::Const,
+      self.ast_builder.vec1(declarator),
+      true,
+    )));
+  }
+
+  /// `declare` is false inside a namespace, which is already ambient.
+  fn emit_declaration(&mut self, node: &'a Declaration<'a>, declare: bool) -> Declaration<'a> {
+    match node {
+      Declaration::VariableDeclaration(node) => {
+        Declaration::VariableDeclaration(self.emit_variable_declaration(node, declare))
+      }
+      Declaration::FunctionDeclaration(node) => {
+        let ty = node.id.as_ref().and_then(|id| self.get_type_by_span(id.span));
+        Declaration::FunctionDeclaration(self.emit_function(node, ty, declare))
+      }
+      Declaration::ClassDeclaration(node) => {
+        Declaration::ClassDeclaration(self.emit_class(node, declare))
+      }
+      Declaration::TSEnumDeclaration(node) => {
+        let mut node = node.clone_in(self.allocator);
+        node.declare = declare;
+        Declaration::TSEnumDeclaration(node)
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        Declaration::TSModuleDeclaration(self.emit_module_declaration(node, declare))
+      }
+      _ => node.clone_in(self.allocator),
+    }
+  }
+
+  fn emit_module_declaration(
+    &mut self,
+    node: &'a TSModuleDeclaration<'a>,
+    declare: bool,
+  ) -> Box<'a, TSModuleDeclaration<'a>> {
+    let mut module = self.ast_builder.alloc(node.clone_in(self.allocator));
+    module.declare = declare;
+    module.body = match &node.body {
+      // `namespace A.B {}`
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) => Some(
+        TSModuleDeclarationBody::TSModuleDeclaration(self.emit_module_declaration(inner, false)),
+      ),
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        let body = self.emit_module_block(&block.body);
+        Some(TSModuleDeclarationBody::TSModuleBlock(self.ast_builder.alloc_ts_module_block(
+          block.span,
+          self.ast_builder.vec(),
+          body,
+        )))
+      }
+      None => None,
+    };
+    module
+  }
+
+  /// Only the exported members of a namespace are emitted, without `declare`. The members of an
+  /// ambient namespace are exported even without `export`, so the local ones are dropped.
+  fn emit_module_block(
+    &mut self,
+    statements: &'a Vec<'a, Statement<'a>>,
+  ) -> Vec<'a, Statement<'a>> {
+    let mut body = self.ast_builder.vec();
+    let mut prev_function = None;
+    for statement in statements {
+      let function = as_function_declaration(statement);
+      let is_overload_implementation = matches!(
+        (prev_function, function),
+        (Some(prev), Some(function)) if is_overload_implementation(prev, function)
+      );
+      prev_function = function;
+      if is_overload_implementation {
+        continue;
+      }
+      match statement {
+        Statement::ImportDeclaration(_)
+        | Statement::ExportAllDeclaration(_)
+        | Statement::TSInterfaceDeclaration(_)
+        | Statement::TSTypeAliasDeclaration(_)
+        | Statement::TSImportEqualsDeclaration(_) => {
+          body.push(statement.clone_in(self.allocator));
+        }
+        Statement::ExportNamedDeclaration(node) => {
+          let declaration =
+            node.declaration.as_ref().map(|node| self.emit_declaration(node, false));
+          let mut node = node.clone_in(self.allocator);
+          node.declaration = declaration;
+          body.push(Statement::ExportNamedDeclaration(node));
+        }
+        _ => {}
+      }
+    }
+    body
+  }
+
+  fn emit_variable_declaration(
+    &mut self,
+    node: &'a VariableDeclaration<'a>,
+    declare: bool,
+  ) -> Box<'a, VariableDeclaration<'a>> {
+    let mut declarations = self.ast_builder.vec();
+    for declarator in &node.declarations {
+      if let Some(annotation) = &declarator.id.type_annotation {
+        // Keep the annotation, even if the binding is a pattern
+        let mut id = declarator.id.clone_in(self.allocator);
+        id.type_annotation = Some(annotation.clone_in(self.allocator));
+        declarations.push(self.ast_builder.variable_declarator(
+          declarator.span,
+          node.kind,
+          id,
+          None,
+          false,
+        ));
+        continue;
+      }
+      // Function expressions keep their parameter names
+      let init = declarator.init.as_ref().filter(|_| declarator.id.kind.is_binding_identifier());
+      // Each binding in a destructuring pattern becomes a declarator
+      declarator.id.bound_names(&mut |id| {
+        let ty = self.get_type_by_span(id.span).unwrap_or(Ty::Any);
+        let ty = if node.kind.is_const() { ty } else { self.get_widened_type(ty) };
+        let mut annotation = self.serialize_type_annotation(ty);
+        if let Some(init) = init {
+          self.rename_initializer_parameters(&mut annotation.type_annotation, init);
+        }
+        let pattern = self.ast_builder.binding_pattern(
+          self.ast_builder.binding_pattern_kind_binding_identifier(id.span, id.name.clone()),
+          Some(annotation),
+          false,
+        );
+        declarations.push(self.ast_builder.variable_declarator(
+          declarator.span,
+          node.kind,
+          pattern,
+          None,
+          false,
+        ));
+      });
+    }
+    self.ast_builder.alloc_variable_declaration(node.span, node.kind, declarations, declare)
+  }
+
+  /// Rename the parameters of the function types in `node`, which is the serialized type of
+  /// `init`, including the methods and function properties of object literals.
+  fn rename_initializer_parameters(&self, node: &mut TSType<'a>, init: &'a Expression<'a>) {
+    match init.without_parentheses() {
+      Expression::ArrowFunctionExpression(function) => {
+        self.rename_signature_parameters(node, &function.params);
+      }
+      Expression::FunctionExpression(function) => {
+        self.rename_signature_parameters(node, &function.params);
+      }
+      Expression::ObjectExpression(object) => {
+        let TSType::TSTypeLiteral(literal) = node else {
+          return;
+        };
+        for member in literal.members.iter_mut() {
+          let TSSignature::TSPropertySignature(signature) = member else {
+            continue;
+          };
+          let (Some(name), Some(annotation)) =
+            (signature.key.static_name(), &mut signature.type_annotation)
+          else {
+            continue;
+          };
+          // The last property with the same name wins
+          let value = object.properties.iter().rev().find_map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property)
+              if property.kind == PropertyKind::Init
+                && property.key.static_name().as_ref() == Some(&name) =>
+            {
+              Some(&property.value)
+            }
+            _ => None,
+          });
+          if let Some(value) = value {
+            self.rename_initializer_parameters(&mut annotation.type_annotation, value);
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Serialized function types have placeholder parameter names like `a0`, which are replaced by
+  /// the names of `params`.
+  fn rename_signature_parameters(&self, node: &mut TSType<'a>, params: &'a FormalParameters<'a>) {
+    let TSType::TSFunctionType(function) = node else {
+      return;
+    };
+    let mut names = vec![];
+    for (target, source) in function.params.items.iter_mut().zip(&params.items) {
+      let source = match &source.pattern.kind {
+        BindingPatternKind::AssignmentPattern(assignment) => &assignment.left,
+        _ => &source.pattern,
+      };
+      if let BindingPatternKind::BindingIdentifier(id) = &source.kind {
+        target.pattern.kind =
+          self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, id.name.clone());
+      }
+      names.push(target.pattern.get_identifier());
+    }
+    if let (Some(target), Some(source)) = (&mut function.params.rest, &params.rest) {
+      if let BindingPatternKind::BindingIdentifier(id) = &source.argument.kind {
+        target.argument.kind =
+          self.ast_builder.binding_pattern_kind_binding_identifier(SPAN, id.name.clone());
+      }
+    }
+    if let TSType::TSTypePredicate(predicate) = &mut function.return_type.type_annotation {
+      if let TSTypePredicateName::Identifier(id) = &mut predicate.parameter_name {
+        let index = id.name.strip_prefix('a').and_then(|index| index.parse::<usize>().ok());
+        if let Some(Some(name)) = index.and_then(|index| names.get(index)) {
+          id.name = name.clone();
+        }
+      }
+    }
+  }
+
+  /// `ty` is the inferred type of the function, which provides the types of unannotated parameters
+  /// and the return type.
+  fn emit_function(
+    &mut self,
+    node: &'a Function<'a>,
+    ty: Option<Ty<'a>>,
+    declare: bool,
+  ) -> Box<'a, Function<'a>> {
+    let callable = match ty {
+      Some(Ty::Function(callable)) => Some(callable),
+      _ => None,
+    };
+    let mut function = self.ast_builder.alloc(node.clone_in(self.allocator));
+    function.declare = declare;
+    function.body = None;
+    // The return type is already the promise or the generator
+    function.r#async = false;
+    function.generator = false;
+    function.params = self.emit_formal_parameters(&node.params, callable);
+    if node.return_type.is_none() {
+      let ret = match callable {
+        Some(callable) => self.serialize_ctx_ty(callable.return_type),
+        None => self.ast_builder.ts_type_any_keyword(SPAN),
+      };
+      function.return_type = Some(self.ast_builder.alloc_ts_type_annotation(SPAN, ret));
+    }
+    function
+  }
+
+  fn emit_formal_parameters(
+    &mut self,
+    node: &'a FormalParameters<'a>,
+    callable: Option<&'a FunctionType<'a>>,
+  ) -> Box<'a, FormalParameters<'a>> {
+    let mut params = self.ast_builder.alloc(node.clone_in(self.allocator));
+    for (index, param) in params.items.iter_mut().enumerate() {
+      // Parameter properties are emitted as class properties
+      param.accessibility = None;
+      param.readonly = false;
+      param.r#override = false;
+      param.decorators = self.ast_builder.vec();
+
+      let pattern = &mut param.pattern;
+      if let BindingPatternKind::AssignmentPattern(assignment) = &mut pattern.kind {
+        // Default values become optional parameters
+        let left = assignment.left.clone_in(self.allocator);
+        pattern.type_annotation = pattern.type_annotation.take().or(left.type_annotation);
+        pattern.kind = left.kind;
+        pattern.optional = true;
+      }
+      if pattern.type_annotation.is_none() {
+        let ty = match callable.and_then(|callable| callable.params.get(index)) {
+          Some((_, ty)) => self.serialize_ctx_ty(*ty),
+          None => self.ast_builder.ts_type_any_keyword(SPAN),
+        };
+        pattern.type_annotation = Some(self.ast_builder.alloc_ts_type_annotation(SPAN, ty));
+      }
+    }
+    if let Some(rest) = &mut params.rest {
+      if rest.argument.type_annotation.is_none() {
+        let ty = match callable.and_then(|callable| callable.rest_param) {
+          Some(ty) => self.serialize_ctx_ty(ty),
+          None => self.ast_builder.ts_type_any_keyword(SPAN),
+        };
+        rest.argument.type_annotation = Some(self.ast_builder.alloc_ts_type_annotation(SPAN, ty));
+      }
+    }
+    params
+  }
+
+  fn emit_class(&mut self, node: &'a Class<'a>, declare: bool) -> Box<'a, Class<'a>> {
+    let mut class = self.ast_builder.alloc(node.clone_in(self.allocator));
+    class.declare = declare;
+    class.decorators = self.ast_builder.vec();
+
+    let mut elements = self.ast_builder.vec();
+    let mut has_private_identifier = false;
+    let mut prev_method = None;
+    for element in &node.body.body {
+      let method = match element {
+        ClassElement::MethodDefinition(method) => Some(&**method),
+        _ => None,
+      };
+      let is_overload_implementation = matches!(
+        (prev_method, method),
+        (Some(prev), Some(method)) if is_method_overload_implementation(prev, method)
+      );
+      prev_method = method;
+      if is_overload_implementation {
+        continue;
+      }
+      if let Some(key) = element.property_key() {
+        if key.is_private_identifier() {
+          // Like `tsc`, all `#private` members are emitted as a single `#private;`
+          if !has_private_identifier {
+            has_private_identifier = true;
+            elements.push(self.emit_private_property(
+              self.ast_builder.property_key_private_identifier(SPAN, "private"),
+              false,
+            ));
+          }
+          continue;
+        }
+      }
+      match element {
+        ClassElement::MethodDefinition(method) => {
+          if method.accessibility == Some(TSAccessibility::Private) {
+            let key = method.key.clone_in(self.allocator);
+            elements.push(self.emit_private_property(key, method.r#static));
+            continue;
+          }
+          if method.kind == MethodDefinitionKind::Constructor {
+            // Parameter properties
+            for param in &method.value.params.items {
+              if param.accessibility.is_none() && !param.readonly {
+                continue;
+              }
+              let pattern = match &param.pattern.kind {
+                BindingPatternKind::AssignmentPattern(assignment) => &assignment.left,
+                _ => &param.pattern,
+              };
+              let BindingPatternKind::BindingIdentifier(id) = &pattern.kind else {
+                continue;
+              };
+              let key = self.ast_builder.property_key_identifier_name(id.span, id.name.clone());
+              if param.accessibility == Some(TSAccessibility::Private) {
+                elements.push(self.emit_private_property(key, false));
+                continue;
+              }
+              let annotation = match &pattern.type_annotation {
+                Some(annotation) => annotation.clone_in(self.allocator),
+                None => {
+                  let ty = self.get_type_by_span(id.span).unwrap_or(Ty::Any);
+                  self.serialize_type_annotation(ty)
+                }
+              };
+              elements.push(self.ast_builder.class_element_property_definition(
+                param.span,
+                PropertyDefinitionType::PropertyDefinition,
+                self.ast_builder.vec(),
+                key,
+                None,
+                false,
+                false,
+                false,
+                param.r#override,
+                false,
+                false,
+                param.readonly,
+                Some(annotation),
+                param.accessibility,
+              ));
+            }
+          }
+
+          let ty = self.get_type_by_span(method.key.span());
+          let function = if method.kind == MethodDefinitionKind::Method {
+            self.emit_function(&method.value, ty, false)
+          } else {
+            // Constructors and setters have no return type, and getters are typed by the function
+            let mut function = self.emit_function(&method.value, ty, false);
+            if method.kind != MethodDefinitionKind::Get {
+              function.return_type = None;
+            }
+            function
+          };
+          let mut method = method.clone_in(self.allocator);
+          method.decorators = self.ast_builder.vec();
+          method.value = function;
+          elements.push(ClassElement::MethodDefinition(method));
+        }
+        ClassElement::PropertyDefinition(property) => {
+          if property.accessibility == Some(TSAccessibility::Private) {
+            let key = property.key.clone_in(self.allocator);
+            elements.push(self.emit_private_property(key, property.r#static));
+            continue;
+          }
+          let mut property_clone = property.clone_in(self.allocator);
+          property_clone.decorators = self.ast_builder.vec();
+          property_clone.value = None;
+          property_clone.definite = false;
+          if property.type_annotation.is_none() {
+            let ty = self.get_type_by_span(property.key.span()).unwrap_or(Ty::Any);
+            let ty = if property.readonly { ty } else { self.get_widened_type(ty) };
+            property_clone.type_annotation = Some(self.serialize_type_annotation(ty));
+          }
+          elements.push(ClassElement::PropertyDefinition(property_clone));
+        }
+        ClassElement::AccessorProperty(property) => {
+          let mut property_clone = property.clone_in(self.allocator);
+          property_clone.decorators = self.ast_builder.vec();
+          property_clone.value = None;
+          if property.type_annotation.is_none() {
+            let ty = self.get_type_by_span(property.key.span()).unwrap_or(Ty::Any);
+            let ty = self.get_widened_type(ty);
+            property_clone.type_annotation = Some(self.serialize_type_annotation(ty));
+          }
+          elements.push(ClassElement::AccessorProperty(property_clone));
+        }
+        ClassElement::TSIndexSignature(_) => elements.push(element.clone_in(self.allocator)),
+        ClassElement::StaticBlock(_) => {}
+      }
+    }
+    class.body.body = elements;
+    class
+  }
+
+  /// Private members are emitted without types.
+  fn emit_private_property(&mut self, key: PropertyKey<'a>, r#static: bool) -> ClassElement<'a> {
+    let accessibility = (!key.is_private_identifier()).then_some(TSAccessibility::Private);
+    self.ast_builder.class_element_property_definition(
+      SPAN,
+      PropertyDefinitionType::PropertyDefinition,
+      self.ast_builder.vec(),
+      key,
+      None,
+      false,
+      r#static,
+      false,
+      false,
+      false,
+      false,
+      false,
+      NONE,
+      accessibility,
+    )
+  }
+
+  fn serialize_type_annotation(&mut self, ty: Ty<'a>) -> Box<'a, TSTypeAnnotation<'a>> {
+    let ty = self.serialize_type(ty);
+    self.ast_builder.alloc_ts_type_annotation(SPAN, ty)
+  }
+}
+
+/// Collects the names referenced by the emitted declarations.
+struct ReferencedNames<'s, 'a>(&'s mut FxHashSet<&'a str>);
+
+impl<'a> Visit<'a> for ReferencedNames<'_, 'a> {
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+    self.0.insert(it.name.as_str());
+  }
+}
+
+/// Unlike `BoundNames`, enums and namespaces are also included.
+fn declared_names<'a>(declaration: &Declaration<'a>, f: &mut impl FnMut(&BindingIdentifier<'a>)) {
+  match declaration {
+    Declaration::TSEnumDeclaration(node) => f(&node.id),
+    Declaration::TSModuleDeclaration(node) => {
+      if let TSModuleDeclarationName::Identifier(id) = &node.id {
+        f(id);
+      }
+    }
+    _ => declaration.bound_names(f),
+  }
+}
+
+fn as_function_declaration<'b, 'a>(statement: &'b Statement<'a>) -> Option<&'b Function<'a>> {
+  match statement {
+    Statement::FunctionDeclaration(node) => Some(node),
+    Statement::ExportNamedDeclaration(node) => match &node.declaration {
+      Some(Declaration::FunctionDeclaration(node)) => Some(node),
+      _ => None,
+    },
+    Statement::ExportDefaultDeclaration(node) => match &node.declaration {
+      ExportDefaultDeclarationKind::FunctionDeclaration(node) => Some(node),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn is_method_overload_implementation(prev: &MethodDefinition, node: &MethodDefinition) -> bool {
+  prev.kind == node.kind
+    && prev.r#static == node.r#static
+    && prev.value.body.is_none()
+    && node.value.body.is_some()
+    && prev.key.static_name().is_some()
+    && prev.key.static_name() == node.key.static_name()
+}
+
+/// Only the overload signatures of an overloaded function are emitted, not the implementation.
+fn is_overload_implementation(prev: &Function, node: &Function) -> bool {
+  prev.body.is_none()
+    && node.body.is_some()
+    && prev.id.as_ref().map(|id| id.name.as_str()) == node.id.as_ref().map(|id| id.name.as_str())
+}
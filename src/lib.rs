@@ -1,19 +1,26 @@
 mod analyzer;
 mod builtins;
 mod config;
+mod dts;
 mod nodes;
+mod program;
+mod resolver;
 mod scope;
+mod tsconfig;
 pub mod ty;
 mod utils;
 
 pub use analyzer::Analyzer;
-pub use config::Config;
+pub use config::{Config, Jsx, Lib, Target};
+pub use program::{analyze_program, ModuleId, ModuleResolver};
+pub use resolver::NodeModuleResolver;
+
 use oxc::{allocator::Allocator, parser::Parser, semantic::SemanticBuilder, span::SourceType};
 
 pub fn analyze<'a>(allocator: &'a Allocator, code: &'a str, config: Config) -> Analyzer<'a> {
-  let parsed = allocator.alloc(Parser::new(&allocator, code, SourceType::tsx()).parse());
-  let semantic = SemanticBuilder::new().build(&parsed.program);
-  let mut analyzer = Analyzer::new(&allocator, config, semantic.semantic);
+  let parsed = allocator.alloc(Parser::new(allocator, code, SourceType::tsx()).parse());
+  let semantic = allocator.alloc(SemanticBuilder::new().build(&parsed.program).semantic);
+  let mut analyzer = Analyzer::new(allocator, config, semantic);
   analyzer.exec_program(&parsed.program);
   analyzer
 }
@@ -25,22 +25,32 @@ impl<'a> Analyzer<'a> {
         }
         ArrayExpressionElement::Elision(_node) => (false, Ty::Undefined),
         _ => {
-          let sat = sat
-            .map(|sat| self.get_property(sat, PropertyKeyType::NumericLiteral((i as f64).into())));
+          let sat = sat.map(|sat| self.get_element_sat(sat, i));
           (false, self.exec_expression_with_as_const(element.to_expression(), sat, as_const))
         }
       };
       values.push(value);
     }
 
-    if as_const {
+    // A tuple in the contextual type, like `T extends readonly unknown[] | []`, types the literal
+    // as a tuple
+    let is_tuple_context = |sat| match sat {
+      Some(Ty::Tuple(_)) => true,
+      Some(Ty::Union(union)) => {
+        let mut found = false;
+        union.for_each(|ty| found |= matches!(ty, Ty::Tuple(_)));
+        found
+      }
+      _ => false,
+    };
+    if as_const || is_tuple_context(sat) {
       Ty::Tuple(
         self.allocator.alloc(TupleType {
           elements: values
             .into_iter()
             .map(|(spread, ty)| TupleElement { name: None, spread, ty, optional: false })
             .collect(),
-          readonly: true,
+          readonly: as_const,
         }),
       )
     } else {
@@ -49,7 +59,27 @@ impl<'a> Analyzer<'a> {
         .map(|(spread, ty)| if spread { self.iterate_result_union(ty) } else { ty })
         .collect::<Vec<_>>();
       let el_type = self.into_union(types).unwrap_or(Ty::Never);
-      todo!("Array<{:?}>", el_type);
+      self.create_array_type(el_type, false)
+    }
+  }
+
+  /// The contextual type of the element at `index`, from a tuple or an iterable.
+  fn get_element_sat(&mut self, sat: Ty<'a>, index: usize) -> Ty<'a> {
+    match sat {
+      Ty::Tuple(_) => {
+        self.get_property(sat, PropertyKeyType::NumericLiteral((index as f64).into()))
+      }
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types = members
+          .into_iter()
+          .filter(|ty| !matches!(ty, Ty::Null | Ty::Undefined))
+          .map(|ty| self.get_element_sat(ty, index))
+          .collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ => self.iterate_result_union(sat),
     }
   }
 }
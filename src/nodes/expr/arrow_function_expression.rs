@@ -1,13 +1,63 @@
 use oxc::ast::ast::ArrowFunctionExpression;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{callable::CallableType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_arrow_function_expression(
     &mut self,
     node: &'a ArrowFunctionExpression<'a>,
-    _sat: Option<Ty<'a>>,
+    sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    todo!()
+    let type_params = node
+      .type_parameters
+      .as_ref()
+      .map(|type_parameters| self.resolve_type_parameter_declaration(type_parameters))
+      .unwrap_or_default();
+
+    let contextual =
+      sat.and_then(|sat| self.get_contextual_signature(sat, node.params.items.len()));
+    let (this_param, params, rest_param) = self.exec_formal_parameters(
+      &node.params,
+      contextual.as_ref().map(|contextual| contextual.params.as_slice()),
+    );
+    let contextual_ret = contextual.and_then(|contextual| contextual.return_type);
+
+    let annotated_ret = node.return_type.as_ref().map(|n| &n.type_annotation);
+    let resolved_annotated = annotated_ret.map(|t| self.resolve_type(t));
+    let inferred_ret = if node.expression {
+      self.exec_function_expression_body(
+        &node.body,
+        node.r#async,
+        resolved_annotated,
+        contextual_ret,
+      )
+    } else {
+      // Arrows capture `this` of the enclosing function
+      let this = self.call_scopes.last().map(|call_scope| call_scope.this);
+      self.exec_function_body(
+        &node.body,
+        node.r#async,
+        false,
+        this,
+        resolved_annotated,
+        contextual_ret,
+      )
+    };
+    let return_type = self.ctx_ty_from_annotation(&node.return_type, Some(inferred_ret));
+    let predicate = self.get_type_predicate(annotated_ret, &node.params);
+
+    Ty::Function(self.allocator.alloc(CallableType {
+      is_method: false,
+      scope: self.type_scopes.top(),
+      type_params,
+      this_param,
+      params,
+      rest_param,
+      return_type,
+      predicate,
+    }))
   }
 }
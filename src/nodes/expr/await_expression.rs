@@ -8,8 +8,8 @@ impl<'a> Analyzer<'a> {
     node: &'a AwaitExpression<'a>,
     sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    let value =
-      self.exec_expression(&node.argument, sat.map(|sat| todo!("sat | PromiseLike<sat>")));
+    let sat = sat.map(|sat| self.get_awaitable_type(sat));
+    let value = self.exec_expression(&node.argument, sat);
     self.get_to_awaited(value)
   }
 }
@@ -1,4 +1,4 @@
-use oxc::ast::ast::CallExpression;
+use oxc::ast::ast::{CallExpression, Expression};
 
 use crate::{analyzer::Analyzer, ty::Ty};
 
@@ -12,6 +12,8 @@ impl<'a> Analyzer<'a> {
 
     if indeterminate {
       self.pop_scope();
+    } else {
+      self.narrow_assertion_call(node);
     }
 
     value
@@ -22,6 +24,14 @@ impl<'a> Analyzer<'a> {
     node: &'a CallExpression<'a>,
     sat: Option<Ty<'a>>,
   ) -> (bool, Ty<'a>) {
+    if let Expression::Super(_) = &node.callee {
+      // `super()` calls the constructor of the base class
+      let super_class = self.class_scopes.last().and_then(|scope| scope.super_class);
+      let callable = self.extract_callable_constructor(super_class.unwrap_or(Ty::Error));
+      self.exec_call(callable, &node.type_parameters, Ty::Error, &node.arguments, None);
+      return (false, Ty::Void);
+    }
+
     let (mut indeterminate, callee, this_arg) = self.exec_callee(&node.callee);
 
     if !indeterminate && node.optional {
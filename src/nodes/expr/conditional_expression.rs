@@ -11,10 +11,12 @@ impl<'a> Analyzer<'a> {
     self.exec_expression(&node.test, None);
 
     self.push_exit_blocker_scope();
+    self.narrow_condition(&node.test, true);
     let consequent = self.exec_expression(&node.consequent, sat);
     let scope_1 = self.runtime_scopes.pop();
 
     self.push_exit_blocker_scope();
+    self.narrow_condition(&node.test, false);
     let alternate = self.exec_expression(&node.alternate, sat);
     let scope_2 = self.runtime_scopes.pop();
 
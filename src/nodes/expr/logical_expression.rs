@@ -1,4 +1,4 @@
-use oxc::ast::ast::LogicalExpression;
+use oxc::ast::ast::{LogicalExpression, LogicalOperator};
 
 use crate::{analyzer::Analyzer, ty::Ty};
 
@@ -11,9 +11,31 @@ impl<'a> Analyzer<'a> {
     let left = self.exec_expression(&node.left, sat);
 
     self.push_indeterminate_scope();
+    // The right side is only executed when the left side does not short-circuit
+    match node.operator {
+      LogicalOperator::And => self.narrow_condition(&node.left, true),
+      LogicalOperator::Or => self.narrow_condition(&node.left, false),
+      LogicalOperator::Coalesce => self.narrow_condition_nullish(&node.left, true),
+    }
     let right = self.exec_expression(&node.right, sat);
     self.pop_scope();
 
-    self.into_union([left, right]).unwrap()
+    // The left side is the result when it short-circuits
+    let (short_circuit, left) = match node.operator {
+      LogicalOperator::And => {
+        (self.test_truthy(left) == Some(false), self.narrow_by_truthy(left, false))
+      }
+      LogicalOperator::Or => {
+        (self.test_truthy(left) == Some(true), self.narrow_by_truthy(left, true))
+      }
+      LogicalOperator::Coalesce => {
+        (self.test_nullish(left) == Some(false), self.non_nullable(left))
+      }
+    };
+    if short_circuit {
+      left
+    } else {
+      self.into_union([left, right]).unwrap()
+    }
   }
 }
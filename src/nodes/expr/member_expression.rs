@@ -1,4 +1,4 @@
-use oxc::ast::ast::MemberExpression;
+use oxc::ast::ast::{Expression, MemberExpression};
 
 use crate::{
   analyzer::Analyzer,
@@ -36,7 +36,10 @@ impl<'a> Analyzer<'a> {
 
     let key = self.exec_key(node);
 
-    let value = self.get_property(object, key);
+    let mut value = self.get_property(object, key);
+    if self.config.no_unchecked_indexed_access && self.is_index_signature_access(object, key) {
+      value = self.into_union([value, Ty::Undefined]).unwrap();
+    }
 
     ((indeterminate, value), (object, key))
   }
@@ -54,13 +57,18 @@ impl<'a> Analyzer<'a> {
     });
 
     self.set_property(object, key, value);
+    self.reset_narrowed_property(node);
   }
 
   fn exec_key(&mut self, node: &'a MemberExpression<'a>) -> PropertyKeyType<'a> {
     let value = match node {
-      MemberExpression::ComputedMemberExpression(node) => {
-        self.exec_expression(&node.expression, None)
-      }
+      MemberExpression::ComputedMemberExpression(node) => match &node.expression {
+        // Literal keys are not widened
+        Expression::StringLiteral(_) | Expression::NumericLiteral(_) => {
+          self.exec_expression_with_as_const(&node.expression, None, true)
+        }
+        _ => self.exec_expression(&node.expression, None),
+      },
       MemberExpression::StaticMemberExpression(node) => self.exec_identifier_name(&node.property),
       MemberExpression::PrivateFieldExpression(node) => self.exec_private_identifier(&node.field),
     };
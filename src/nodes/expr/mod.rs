@@ -36,7 +36,22 @@ impl<'a> Analyzer<'a> {
     let span = node.span();
     self.push_span(&span);
     let value = self.exec_expression_impl(node, sat);
-    self.accumulate_type(&span, value);
+    if matches!(
+      node,
+      Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ObjectExpression(_)
+    ) {
+      self.accumulate_enclosing_type(&span, value);
+    } else if let Expression::CallExpression(node) = node {
+      let arguments = node.arguments.iter().map(GetSpan::span).collect::<Vec<_>>();
+      self.accumulate_type_outside(&span, &arguments, value);
+    } else if let Expression::NewExpression(node) = node {
+      let arguments = node.arguments.iter().map(GetSpan::span).collect::<Vec<_>>();
+      self.accumulate_type_outside(&span, &arguments, value);
+    } else {
+      self.accumulate_type(&span, value);
+    }
     self.pop_span();
     value
   }
@@ -55,9 +70,9 @@ impl<'a> Analyzer<'a> {
     self.push_span(&span);
 
     let value = match node {
+      // Enum members are already literals
       match_member_expression!(Expression) => {
-        // TODO: Enum member
-        todo!()
+        self.exec_member_expression_read(node.to_member_expression(), sat).0
       }
       Expression::StringLiteral(node) => Ty::StringLiteral(&node.value),
       Expression::NumericLiteral(node) => Ty::NumericLiteral(node.value.into()),
@@ -1,6 +1,9 @@
 use oxc::ast::ast::NewExpression;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{unresolved::UnresolvedType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_new_expression(
@@ -9,11 +12,16 @@ impl<'a> Analyzer<'a> {
     sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
     let callee = self.exec_expression(&node.callee, None);
+    if let Ty::Unresolved(UnresolvedType::UnInitVariable(symbol_id)) = callee {
+      if let Some(instance) = self.get_uninitialized_class_instance(symbol_id) {
+        return instance;
+      }
+    }
 
-    let callable = self.extract_callable_function(callee);
+    let callable = self.extract_callable_constructor(callee);
 
-    self.exec_call(callable, &node.type_parameters, Ty::Error, &node.arguments, sat);
+    let ret_val = self.exec_call(callable, &node.type_parameters, Ty::Error, &node.arguments, sat);
 
-    todo!()
+    ret_val.unwrap_or(Ty::Error)
   }
 }
@@ -1,10 +1,22 @@
-use oxc::ast::ast::{ObjectExpression, ObjectPropertyKind, PropertyKind};
+use oxc::{
+  ast::ast::{Expression, ObjectExpression, ObjectPropertyKind, PropertyKind},
+  span::GetSpan,
+};
 
 use crate::{
   analyzer::Analyzer,
-  ty::{record::RecordTypeBuilder, Ty},
+  ty::{interface::InterfaceType, property_key::PropertyKeyType, record::RecordTypeBuilder, Ty},
 };
 
+enum PropertySlot<'a> {
+  Property(PropertyKeyType<'a>, Ty<'a>),
+  Spread(Ty<'a>),
+  /// A method or function property, whose `this` depends on the other properties.
+  Method(PropertyKeyType<'a>),
+  /// The property type of a getter or a setter.
+  Accessor(PropertyKeyType<'a>, Ty<'a>, PropertyKind),
+}
+
 impl<'a> Analyzer<'a> {
   pub fn exec_object_expression(
     &mut self,
@@ -12,34 +24,117 @@ impl<'a> Analyzer<'a> {
     sat: Option<Ty<'a>>,
     as_const: bool,
   ) -> Ty<'a> {
-    let mut object = RecordTypeBuilder::default();
-
+    let mut slots = vec![];
     for property in &node.properties {
-      match property {
+      slots.push(match property {
         ObjectPropertyKind::ObjectProperty(node) => {
           let key = self.exec_property_key(&node.key);
-          let sat = sat.map(|sat| self.get_property(sat, key));
-          let value = self.exec_expression_with_as_const(&node.value, sat, as_const);
-          let value = value;
-
-          // tsc doesn't care. So we don't care either.
-          // if matches!(&node.key, PropertyKey::StaticIdentifier(node) if node.name == "__proto__") {
-          //   object.init_proto(value);
-          // } else {
-
-          let value = match node.kind {
-            PropertyKind::Init => value,
-            PropertyKind::Get | PropertyKind::Set => todo!(),
-          };
-          object.init_property(self, key, value, false, as_const);
+          if matches!(node.value, Expression::FunctionExpression(_)) {
+            PropertySlot::Method(key)
+          } else {
+            let sat = sat.map(|sat| self.get_property(sat, key));
+            let value = self.exec_expression_with_as_const(&node.value, sat, as_const);
+
+            // tsc doesn't care. So we don't care either.
+            // if matches!(&node.key, PropertyKey::StaticIdentifier(node) if node.name == "__proto__") {
+            //   object.init_proto(value);
+            // } else {
+
+            PropertySlot::Property(key, value)
+          }
         }
         ObjectPropertyKind::SpreadProperty(node) => {
-          let argument = self.exec_expression_with_as_const(&node.argument, sat, as_const);
-          object.init_spread(self, argument);
+          PropertySlot::Spread(self.exec_expression_with_as_const(&node.argument, sat, as_const))
         }
+      });
+    }
+
+    // `this` in methods and accessors is the contextual type, or the completed object. While the
+    // methods are executed, the object has only the other properties.
+    let (this, this_interface) = match sat {
+      Some(sat) => (sat, None),
+      None => {
+        let mut object = RecordTypeBuilder::default();
+        self.init_object_slots(&mut object, &slots, as_const);
+        let interface = &*self.allocator.alloc(InterfaceType::default());
+        interface.0.borrow_mut().record = object.build();
+        (Ty::Interface(interface), Some(interface))
+      }
+    };
+    for (property, slot) in node.properties.iter().zip(slots.iter_mut()) {
+      if let (ObjectPropertyKind::ObjectProperty(node), PropertySlot::Method(key)) =
+        (property, &slot)
+      {
+        let Expression::FunctionExpression(function) = &node.value else { unreachable!() };
+        let key = *key;
+        // The contextual type of an accessor is the property type, not a signature
+        let sat = match node.kind {
+          PropertyKind::Init => sat.map(|sat| self.get_property(sat, key)),
+          PropertyKind::Get | PropertyKind::Set => None,
+        };
+        let span = node.value.span();
+        self.push_span(&span);
+        let value = self.exec_object_method(function, sat, this, node.method);
+        self.accumulate_enclosing_type(&span, value);
+        self.pop_span();
+        *slot = match node.kind {
+          PropertyKind::Init => PropertySlot::Property(key, value),
+          PropertyKind::Get | PropertyKind::Set => {
+            let Ty::Function(callable) = value else { unreachable!() };
+            let value = if node.kind == PropertyKind::Get {
+              self.resolve_ctx_ty(self.type_scopes.empty_scope, callable.return_type)
+            } else {
+              match callable.params.first() {
+                Some((_, param)) => self.resolve_ctx_ty(self.type_scopes.empty_scope, *param),
+                None => Ty::Any,
+              }
+            };
+            PropertySlot::Accessor(key, value, node.kind)
+          }
+        };
       }
     }
 
-    Ty::Record(self.allocator.alloc(object.build()))
+    let mut object = RecordTypeBuilder::default();
+    self.init_object_slots(&mut object, &slots, as_const);
+    let object = object.build();
+    if let Some(interface) = this_interface {
+      interface.0.borrow_mut().record = object.clone();
+    }
+    Ty::Record(self.allocator.alloc(object))
+  }
+
+  fn init_object_slots(
+    &mut self,
+    object: &mut RecordTypeBuilder<'a>,
+    slots: &[PropertySlot<'a>],
+    as_const: bool,
+  ) {
+    for slot in slots {
+      match *slot {
+        PropertySlot::Property(key, value) => {
+          object.init_property(self, key, value, false, as_const)
+        }
+        PropertySlot::Spread(argument) => object.init_spread(self, argument),
+        PropertySlot::Method(_) => {}
+        PropertySlot::Accessor(key, value, kind) => {
+          // A getter and a setter of the same key make a writable property typed by the getter
+          let paired = match key {
+            PropertyKeyType::StringLiteral(name) => slots.iter().any(|other| match other {
+              PropertySlot::Accessor(PropertyKeyType::StringLiteral(other_name), _, other_kind) => {
+                *other_name == name && *other_kind != kind
+              }
+              _ => false,
+            }),
+            _ => false,
+          };
+          match kind {
+            PropertyKind::Get => object.init_property(self, key, value, false, as_const || !paired),
+            PropertyKind::Set if !paired => object.init_property(self, key, value, false, as_const),
+            _ => {}
+          }
+        }
+      }
+    }
   }
 }
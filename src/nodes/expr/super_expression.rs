@@ -3,7 +3,12 @@ use oxc::ast::ast::Super;
 use crate::{analyzer::Analyzer, ty::Ty};
 
 impl<'a> Analyzer<'a> {
+  /// `super.x` refers to the base class in static members, and to its instance type otherwise.
   pub fn exec_super(&mut self, _node: &'a Super, _sat: Option<Ty<'a>>) -> Ty<'a> {
-    todo!()
+    let Some(scope) = self.class_scopes.last() else {
+      return Ty::Error;
+    };
+    let ty = if scope.is_static { scope.super_class } else { scope.super_instance };
+    ty.unwrap_or(Ty::Error)
   }
 }
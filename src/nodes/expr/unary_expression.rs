@@ -53,9 +53,9 @@ impl<'a> Analyzer<'a> {
         let facts = self.get_facts(argument);
         let values = TYPEOF_VALUES
           .iter()
-          .filter_map(|(fact, value)| facts.contains(*fact).then_some(Ty::StringLiteral(value)))
+          .filter_map(|(fact, value)| (!facts.contains(*fact)).then_some(Ty::StringLiteral(value)))
           .collect::<Vec<_>>();
-        self.into_union(values).unwrap()
+        self.into_union(values).unwrap_or(Ty::Never)
       }
       UnaryOperator::Void => Ty::Undefined,
       UnaryOperator::Delete => unreachable!(),
@@ -1,13 +1,44 @@
 use oxc::ast::ast::YieldExpression;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{analyzer::Analyzer, scope::call::CallScopeReturnType, ty::Ty};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_yield_expression(
     &mut self,
     node: &'a YieldExpression<'a>,
-    sat: Option<Ty<'a>>,
+    _sat: Option<Ty<'a>>,
   ) -> Ty<'a> {
-    todo!()
+    let call_scope = self.call_scopes.last().unwrap();
+    let is_async = call_scope.is_async;
+    let annotated = match &call_scope.ret {
+      CallScopeReturnType::Annotated(ty) => Some(*ty),
+      CallScopeReturnType::Inferred(_) => None,
+    };
+    let annotated = annotated.map(|ty| self.get_iterator_type_args(ty).unwrap_or([Ty::Any; 3]));
+
+    let (yielded, result) = if node.delegate {
+      // `yield*` yields the values of the iterable, and results in its return value
+      let argument = node.argument.as_ref().unwrap();
+      let value = self.exec_expression(argument, None);
+      let yielded = self.iterate_result_union(value);
+      let returned =
+        self.get_iterator_type_args(value).map_or(Ty::Any, |[_, returned, _]| returned);
+      (yielded, returned)
+    } else {
+      // `yield` results in the value passed to `next()`
+      let sat = annotated.map(|[yielded, _, _]| yielded);
+      let yielded = node
+        .argument
+        .as_ref()
+        .map_or(Ty::Undefined, |argument| self.exec_expression(argument, sat));
+      (yielded, annotated.map_or(Ty::Any, |[_, _, next]| next))
+    };
+
+    if annotated.is_none() {
+      let yielded = if is_async { self.get_to_awaited(yielded) } else { yielded };
+      let call_scope = self.call_scopes.last_mut().unwrap();
+      call_scope.yielded.add(yielded, self.allocator);
+    }
+    result
   }
 }
@@ -1,4 +1,4 @@
-use oxc::{allocator, ast::ast::Argument};
+use oxc::{allocator, ast::ast::Argument, span::GetSpan};
 
 use crate::{analyzer::Analyzer, ty::Ty};
 
@@ -10,26 +10,41 @@ impl<'a> Analyzer<'a> {
   ) {
     for (i, arg) in node.iter().enumerate() {
       match arg {
-        Argument::SpreadElement(node) => {
-          self.exec_expression(&node.argument, None);
+        Argument::SpreadElement(_) => {
+          self.exec_argument(arg, None, false);
           sat = None;
         }
         node => {
-          self.exec_expression(
-            node.to_expression(),
-            if let Some(s) = sat.as_ref() {
-              if let Some((false, ty)) = s.get(i) {
-                Some(*ty)
-              } else {
-                sat = None;
-                None
-              }
+          let arg_sat = if let Some(s) = sat.as_ref() {
+            if let Some((false, ty)) = s.get(i) {
+              Some(*ty)
             } else {
+              sat = None;
               None
-            },
-          );
+            }
+          } else {
+            None
+          };
+          self.exec_argument(node, arg_sat, false);
         }
       }
     }
   }
+
+  /// Execute an argument, unless it is already executed to resolve overloads. The type of a spread
+  /// argument is the type of the spread value.
+  pub fn exec_argument(
+    &mut self,
+    node: &'a Argument<'a>,
+    sat: Option<Ty<'a>>,
+    as_const: bool,
+  ) -> Ty<'a> {
+    if let Some(ty) = self.executed_arguments.remove(&node.span()) {
+      return ty;
+    }
+    match node {
+      Argument::SpreadElement(node) => self.exec_expression(&node.argument, None),
+      node => self.exec_expression_with_as_const(node.to_expression(), sat, as_const),
+    }
+  }
 }
@@ -77,6 +77,11 @@ impl<'a> Analyzer<'a> {
         let binding_val = self.exec_with_default(&node.right, init);
 
         self.init_binding_pattern(&node.left, Some(binding_val));
+
+        if init.is_none() {
+          // Parameters like `a = 1` are typed by the widened default value
+          init = Some(self.get_widened_type(binding_val));
+        }
       }
     }
 
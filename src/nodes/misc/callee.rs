@@ -27,14 +27,17 @@ fn unwrap_to_member_expression<'a>(
 impl<'a> Analyzer<'a> {
   pub fn exec_callee(&mut self, node: &'a Expression<'a>) -> (bool, Ty<'a>, Ty<'a>) {
     if let Some((member_expr, same_chain)) = unwrap_to_member_expression(node) {
-      if same_chain {
+      let (indeterminate, callee, object) = if same_chain {
         let ((indeterminate, callee), (object, _)) =
           self.exec_member_expression_read_in_chain(member_expr, None);
         (indeterminate, callee, object)
       } else {
         let (callee, (object, _)) = self.exec_member_expression_read(member_expr, None);
         (false, callee, object)
-      }
+      };
+      // Looked up again when narrowing by a type predicate
+      self.accumulate_type(node, callee);
+      (indeterminate, callee, object)
     } else {
       let (indeterminate, callee) = self.exec_expression_in_chain(node, None);
       (indeterminate, callee, Ty::Undefined)
@@ -1,17 +1,345 @@
-use oxc::ast::ast::Class;
+use oxc::{
+  ast::ast::{
+    BindingPatternKind, Class, ClassElement, MethodDefinition, MethodDefinitionKind,
+    TSTypeParameterInstantiation,
+  },
+  semantic::SymbolId,
+};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  scope::{call::CallScope, class::ClassScope, control::CfScopeKind},
+  ty::{
+    callable::{ConstructorType, ExtractedCallable},
+    ctx::CtxTy,
+    generic::{GenericBody, GenericType},
+    interface::InterfaceType,
+    property_key::PropertyKeyType,
+    record::RecordTypeBuilder,
+    unresolved::UnresolvedType,
+    Ty,
+  },
+};
 
 impl<'a> Analyzer<'a> {
+  /// Returns the class itself, which is a constructor of the instance type. With static members,
+  /// it is an interface of the statics and the constructor.
   pub fn exec_class(&mut self, node: &'a Class<'a>, _sat: Option<Ty<'a>>) -> Ty<'a> {
-    todo!()
+    let (instance, generic) = self.get_class_instance_type(node);
+
+    let type_params = match generic {
+      Some(Ty::Generic(generic)) => generic.params.clone(),
+      _ => node
+        .type_parameters
+        .as_ref()
+        .map(|type_parameters| self.resolve_type_parameter_declaration(type_parameters))
+        .unwrap_or_default(),
+    };
+    // `this` of a generic class is an instance of itself with its type parameters, which is also
+    // constructed with the inferred type arguments
+    let instance_ty = match generic {
+      Some(generic) => {
+        let args = type_params
+          .iter()
+          .map(|param| Ty::Unresolved(UnresolvedType::UnInitType(param.symbol_id)))
+          .collect();
+        self.create_generic_instance(generic, args)
+      }
+      None => Ty::Interface(instance),
+    };
+    let return_type = match generic {
+      Some(_) => self.ctx_ty_from_annotation(&None, Some(instance_ty)),
+      None => CtxTy::Static(instance_ty),
+    };
+
+    let (super_class, super_instance) = if let Some(super_class) = &node.super_class {
+      let super_class = self.exec_expression(super_class, None);
+      let super_instance =
+        match self.get_constructed_type(super_class, node.super_type_parameters.as_deref()) {
+          Ty::Instance(super_instance) => self.unwrap_generic_instance(super_instance),
+          super_instance => super_instance,
+        };
+      instance.0.borrow_mut().extend(super_instance);
+      (Some(super_class), Some(super_instance))
+    } else {
+      (None, None)
+    };
+    // `implements` clauses only check the class, and contribute no members
+
+    let super_statics = match super_class {
+      Some(Ty::Interface(super_class)) => Some(super_class.0.borrow().record.clone()),
+      _ => None,
+    };
+    let has_statics = super_statics.as_ref().is_some_and(|statics| !statics.is_empty())
+      || node.body.body.iter().any(is_static_element);
+    let statics = has_statics.then(|| {
+      let statics: &'a InterfaceType<'a> = self.allocator.alloc(InterfaceType::default());
+      if let Some(super_statics) = super_statics {
+        statics.0.borrow_mut().record.extend(super_statics);
+      }
+      statics
+    });
+    // Without static members, the class is typed as its constructor, which is created later
+    let class_ty = statics.map_or(Ty::Any, Ty::Interface);
+
+    self.class_scopes.push(ClassScope { super_class, super_instance, is_static: false });
+
+    // Properties are initialized before the constructor and the methods are executed, so that they
+    // are typed in `this`
+    for element in &node.body.body {
+      let is_static = is_static_element(element);
+      self.class_scopes.last_mut().unwrap().is_static = is_static;
+      let (target, this) = match (is_static, statics) {
+        (true, Some(statics)) => (statics, class_ty),
+        _ => (instance, instance_ty),
+      };
+      match element {
+        ClassElement::PropertyDefinition(node) => {
+          self.exec_property_definition(node, target, this);
+        }
+        ClassElement::AccessorProperty(node) => {
+          self.exec_accessor_property(node, target, this);
+        }
+        ClassElement::TSIndexSignature(node) => {
+          let key = self.resolve_type_annotation(&node.parameters[0].type_annotation);
+          let key = self.to_property_key(key);
+          let value = self.resolve_type_annotation(&node.type_annotation);
+          self.init_class_member(target, key, value, false, node.readonly);
+        }
+        ClassElement::StaticBlock(node) => {
+          self.exec_with_this(this, |analyzer| analyzer.exec_static_block(node));
+        }
+        ClassElement::MethodDefinition(node) => {
+          if node.kind == MethodDefinitionKind::Constructor {
+            self.init_parameter_properties(node, instance);
+          }
+        }
+      }
+    }
+
+    self.class_scopes.last_mut().unwrap().is_static = false;
+    let constructor = node.body.body.iter().find_map(|element| match element {
+      ClassElement::MethodDefinition(node) if node.kind == MethodDefinitionKind::Constructor => {
+        Some(node)
+      }
+      _ => None,
+    });
+    let (params, rest_param) = if let Some(constructor) = constructor {
+      let function = self.exec_method(&constructor.value, None, instance_ty);
+      self.accumulate_type(&constructor.key, function);
+      let Ty::Function(function) = function else { unreachable!() };
+      (function.params.clone(), function.rest_param)
+    } else if let Some(super_class) = super_class {
+      // The constructor of the base class is inherited
+      match self.extract_callable_constructor(super_class) {
+        Some(ExtractedCallable::Single(base)) => (base.params.clone(), base.rest_param),
+        _ => (vec![], Some(CtxTy::Static(Ty::Any))),
+      }
+    } else {
+      (vec![], None)
+    };
+    let constructor = Ty::Constructor(self.allocator.alloc(ConstructorType {
+      is_method: false,
+      scope: self.type_scopes.top(),
+      type_params,
+      this_param: None,
+      params,
+      rest_param,
+      return_type,
+      predicate: None,
+    }));
+    let class_ty = match statics {
+      Some(statics) => {
+        statics.0.borrow_mut().callables.push(constructor);
+        class_ty
+      }
+      None => constructor,
+    };
+
+    // The class is bound before the methods are executed, so that they can reference it
+    if let Some(id) = &node.id {
+      self.named_values.insert(class_ty, id.name.as_str());
+      if node.is_expression() {
+        self.declare_binding_identifier(id, true);
+      }
+      self.init_binding_identifier(id, Some(class_ty));
+    }
+
+    // Setters are executed after getters, so that their parameters can take the getter types
+    let methods = node.body.body.iter().filter_map(|element| match element {
+      ClassElement::MethodDefinition(node) => Some(node),
+      _ => None,
+    });
+    let (setters, methods): (Vec<_>, Vec<_>) =
+      methods.partition(|node| node.kind == MethodDefinitionKind::Set);
+    for node in methods.into_iter().chain(setters) {
+      if node.kind == MethodDefinitionKind::Constructor {
+        continue;
+      }
+      self.class_scopes.last_mut().unwrap().is_static = node.r#static;
+      match (node.r#static, statics) {
+        (true, Some(statics)) => self.exec_method_definition(node, statics, class_ty),
+        _ => self.exec_method_definition(node, instance, instance_ty),
+      }
+    }
+
+    self.class_scopes.pop();
+    class_ty
   }
 
   pub fn declare_class(&mut self, node: &'a Class<'a>) {
-    self.declare_binding_identifier(node.id.as_ref().unwrap(), true);
+    let id = node.id.as_ref().unwrap();
+    self.declare_binding_identifier(id, true);
+    self.get_class_instance_type(node);
   }
 
+  /// The binding is initialized by `exec_class`.
   pub fn init_class(&mut self, node: &'a Class<'a>) -> Ty<'a> {
-    todo!()
+    self.exec_class(node, None)
+  }
+
+  /// The instance type is created before the class is executed, so that the class can be
+  /// referenced as a type in its body and before its declaration. Merged with an interface of the
+  /// same name. A class with type parameters is also typed as a generic of the instance type, which
+  /// is returned as the second element.
+  fn get_class_instance_type(
+    &mut self,
+    node: &'a Class<'a>,
+  ) -> (&'a InterfaceType<'a>, Option<Ty<'a>>) {
+    let Some(id) = &node.id else {
+      return (self.allocator.alloc(InterfaceType::default()), None);
+    };
+    let symbol_id = id.symbol_id();
+    let (instance, generic) = match self.type_scopes.get_on_top(symbol_id) {
+      Some(Ty::Interface(existing)) => (existing, None),
+      Some(
+        generic @ Ty::Generic(GenericType { body: GenericBody::Class { instance, .. }, .. }),
+      ) => (*instance, Some(generic)),
+      _ => {
+        let instance: &'a InterfaceType<'a> = self.allocator.alloc(InterfaceType::default());
+        let ty = match &node.type_parameters {
+          Some(type_parameters) => {
+            let params = self.resolve_type_parameter_declaration(type_parameters);
+            let body = GenericBody::Class { instance, symbol_id, scope: self.type_scopes.top() };
+            Ty::Generic(self.allocator.alloc(GenericType { name: &id.name, params, body }))
+          }
+          None => Ty::Interface(instance),
+        };
+        self.type_scopes.insert_on_top(symbol_id, ty);
+        (instance, matches!(ty, Ty::Generic(_)).then_some(ty))
+      }
+    };
+    self.named_types.insert(Ty::Interface(instance), id.name.as_str());
+    (instance, generic)
+  }
+
+  /// A hoisted function read before a class is initialized may construct it. The instance type is
+  /// known since the class is declared.
+  pub fn get_uninitialized_class_instance(&mut self, symbol_id: SymbolId) -> Option<Ty<'a>> {
+    if !self.semantic.symbols().get_flags(symbol_id).is_class() {
+      return None;
+    }
+    match self.type_scopes.search(symbol_id) {
+      ty @ Ty::Interface(_) => Some(ty),
+      generic @ Ty::Generic(GenericType { params, .. }) => {
+        let args = params.iter().map(|_| Ty::Any).collect();
+        Some(self.create_generic_instance(generic, args))
+      }
+      _ => None,
+    }
+  }
+
+  /// The instance type constructed by `class`, for `extends` and `instanceof`.
+  pub fn get_constructed_type(
+    &mut self,
+    class: Ty<'a>,
+    type_args: Option<&'a TSTypeParameterInstantiation<'a>>,
+  ) -> Ty<'a> {
+    let constructor = match self.extract_callable_constructor(class) {
+      Some(ExtractedCallable::Single(constructor)) => constructor,
+      Some(ExtractedCallable::Overloaded(overloads)) => match overloads.first() {
+        Some(ExtractedCallable::Single(constructor)) => constructor,
+        _ => return Ty::Any,
+      },
+      Some(_) => return Ty::Any,
+      None => {
+        self.add_diagnostic("Type is not a constructor function type");
+        return Ty::Error;
+      }
+    };
+    match type_args {
+      Some(type_args) if !constructor.type_params.is_empty() => {
+        let type_args = self.resolve_type_parameter_instantiation(type_args);
+        let scope = self.instantiate_generic_params(&constructor.type_params, &type_args);
+        self.resolve_ctx_ty(scope, constructor.return_type)
+      }
+      _ => self.resolve_ctx_ty(self.type_scopes.empty_scope, constructor.return_type),
+    }
+  }
+
+  /// Constructor parameters with an accessibility modifier or `readonly` are also properties.
+  fn init_parameter_properties(
+    &mut self,
+    node: &'a MethodDefinition<'a>,
+    instance: &'a InterfaceType<'a>,
+  ) {
+    for param in &node.value.params.items {
+      if param.accessibility.is_none() && !param.readonly {
+        continue;
+      }
+      // The annotation of a parameter with a default value is on the left side
+      let (pattern, default) = match &param.pattern.kind {
+        BindingPatternKind::AssignmentPattern(node) => (&node.left, Some(&node.right)),
+        _ => (&param.pattern, None),
+      };
+      let BindingPatternKind::BindingIdentifier(id) = &pattern.kind else {
+        continue;
+      };
+      let value = match (&pattern.type_annotation, default) {
+        (Some(type_annotation), _) => self.resolve_type_annotation(type_annotation),
+        (None, Some(default)) => {
+          let default = self.exec_with_default(default, None);
+          self.get_widened_type(default)
+        }
+        (None, None) => Ty::Any,
+      };
+      let key = PropertyKeyType::StringLiteral(&id.name);
+      self.init_class_member(instance, key, value, pattern.optional, param.readonly);
+    }
+  }
+
+  /// Add a member to the instance type or the static side of a class. Overrides the inherited one.
+  pub fn init_class_member(
+    &mut self,
+    target: &'a InterfaceType<'a>,
+    key: PropertyKeyType<'a>,
+    value: Ty<'a>,
+    optional: bool,
+    readonly: bool,
+  ) {
+    let mut member = RecordTypeBuilder::default();
+    member.init_property(self, key, value, optional, readonly);
+    target.0.borrow_mut().record.extend(member.build());
+  }
+
+  /// Execute a property initializer or a static block, where `this` is the instance or the class.
+  pub fn exec_with_this<T>(&mut self, this: Ty<'a>, f: impl FnOnce(&mut Self) -> T) -> T {
+    let body_scope = self.push_scope(CfScopeKind::Function);
+    self.call_scopes.push(CallScope::new(body_scope, false, false, this, None));
+    let result = f(self);
+    self.call_scopes.pop();
+    self.pop_scope();
+    result
+  }
+}
+
+/// Unlike `ClassElement::r#static`, static blocks and static index signatures are static.
+fn is_static_element(element: &ClassElement) -> bool {
+  match element {
+    ClassElement::StaticBlock(_) => true,
+    ClassElement::MethodDefinition(node) => node.r#static,
+    ClassElement::PropertyDefinition(node) => node.r#static,
+    ClassElement::AccessorProperty(node) => node.r#static,
+    ClassElement::TSIndexSignature(node) => node.r#static,
   }
 }
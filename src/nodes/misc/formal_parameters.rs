@@ -1,11 +1,17 @@
 use oxc::ast::ast::FormalParameters;
 
-use crate::{analyzer::Analyzer, ty::ctx::CtxTy};
+use crate::{
+  analyzer::Analyzer,
+  ty::{ctx::CtxTy, Ty},
+};
 
 impl<'a> Analyzer<'a> {
+  /// Unannotated parameters are typed by the `contextual` parameter types if provided, which are
+  /// `(spread, type)` like the result of `get_callable_parameter_types`.
   pub fn exec_formal_parameters(
     &mut self,
     node: &'a FormalParameters<'a>,
+    contextual: Option<&[(bool, Ty<'a>)]>,
   ) -> (Option<CtxTy<'a>>, Vec<(bool, CtxTy<'a>)>, Option<CtxTy<'a>>) {
     for param in &node.items {
       self.declare_binding_pattern(&param.pattern, param.pattern.type_annotation.is_some());
@@ -15,8 +21,9 @@ impl<'a> Analyzer<'a> {
     }
 
     let mut params = vec![];
-    for param in &node.items {
-      let inferred = self.init_binding_pattern(&param.pattern, None);
+    for (index, param) in node.items.iter().enumerate() {
+      let contextual = contextual.and_then(|c| self.get_contextual_parameter_type(c, index));
+      let inferred = self.init_binding_pattern(&param.pattern, contextual);
       params.push((
         param.pattern.optional || param.pattern.kind.is_assignment_pattern(),
         self.ctx_ty_from_annotation(&param.pattern.type_annotation, inferred),
@@ -24,7 +31,11 @@ impl<'a> Analyzer<'a> {
     }
 
     let rest = if let Some(rest) = &node.rest {
-      let inferred = self.init_binding_rest_element(rest, None);
+      // The rest parameter takes the contextual rest parameter as is
+      let contextual = contextual.and_then(|c| c.get(node.items.len())).and_then(|(spread, ty)| {
+        (*spread && !self.is_type_parameter_dependent(*ty)).then_some(*ty)
+      });
+      let inferred = self.init_binding_rest_element(rest, contextual);
       Some(self.ctx_ty_from_annotation(&rest.argument.type_annotation, inferred))
     } else {
       None
@@ -55,4 +66,19 @@ impl<'a> Analyzer<'a> {
     // TODO: this type
     (None, params, rest)
   }
+
+  fn get_contextual_parameter_type(
+    &mut self,
+    contextual: &[(bool, Ty<'a>)],
+    index: usize,
+  ) -> Option<Ty<'a>> {
+    let ty = match contextual.get(index).or_else(|| contextual.last().filter(|(spread, _)| *spread))
+    {
+      Some((false, ty)) => *ty,
+      Some((true, ty)) => self.iterate_result_union(*ty),
+      None => return None,
+    };
+    // Uninferred type parameters are not usable
+    (!self.is_type_parameter_dependent(ty)).then_some(ty)
+  }
 }
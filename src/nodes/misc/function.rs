@@ -1,4 +1,4 @@
-use oxc::ast::ast::Function;
+use oxc::{ast::ast::Function, semantic::SymbolId};
 
 use crate::{
   analyzer::Analyzer,
@@ -6,14 +6,52 @@ use crate::{
 };
 
 impl<'a> Analyzer<'a> {
-  pub fn exec_function(&mut self, node: &'a Function<'a>, _sat: Option<Ty<'a>>) -> Ty<'a> {
+  pub fn exec_function(&mut self, node: &'a Function<'a>, sat: Option<Ty<'a>>) -> Ty<'a> {
+    self.exec_function_with_this(node, sat, None, false)
+  }
+
+  /// Execute a class method, whose `this` is the class instance or the class itself.
+  pub fn exec_method(
+    &mut self,
+    node: &'a Function<'a>,
+    sat: Option<Ty<'a>>,
+    this: Ty<'a>,
+  ) -> Ty<'a> {
+    self.exec_function_with_this(node, sat, Some(this), true)
+  }
+
+  /// Execute a method or a function property of an object literal, whose `this` is the object
+  /// unless the contextual signature declares it.
+  pub fn exec_object_method(
+    &mut self,
+    node: &'a Function<'a>,
+    sat: Option<Ty<'a>>,
+    this: Ty<'a>,
+    is_method: bool,
+  ) -> Ty<'a> {
+    self.exec_function_with_this(node, sat, Some(this), is_method)
+  }
+
+  fn exec_function_with_this(
+    &mut self,
+    node: &'a Function<'a>,
+    sat: Option<Ty<'a>>,
+    this: Option<Ty<'a>>,
+    is_method: bool,
+  ) -> Ty<'a> {
     let type_params = node
       .type_parameters
       .as_ref()
       .map(|type_parameters| self.resolve_type_parameter_declaration(&type_parameters))
       .unwrap_or_default();
 
-    let (this_param, params, rest_param) = self.exec_formal_parameters(&node.params);
+    let contextual =
+      sat.and_then(|sat| self.get_contextual_signature(sat, node.params.items.len()));
+    let (this_param, params, rest_param) = self.exec_formal_parameters(
+      &node.params,
+      contextual.as_ref().map(|contextual| contextual.params.as_slice()),
+    );
+    let this = contextual.as_ref().and_then(|contextual| contextual.this).or(this);
 
     let annotated_ret = node.return_type.as_ref().map(|n| &n.type_annotation);
     let inferred_ret = if let Some(body) = &node.body {
@@ -22,30 +60,85 @@ impl<'a> Analyzer<'a> {
         body,
         node.r#async,
         node.generator,
-        /*TODO:*/ None,
+        this,
         resolved_annotated,
+        contextual.and_then(|contextual| contextual.return_type),
       )
     } else {
       Ty::Error
     };
     let return_type = self.ctx_ty_from_annotation(&node.return_type, Some(inferred_ret));
+    let predicate = self.get_type_predicate(annotated_ret, &node.params);
 
     Ty::Function(self.allocator.alloc(CallableType {
-      is_method: false,
+      is_method,
       scope: self.type_scopes.top(),
       type_params,
       this_param,
       params,
       rest_param,
       return_type,
+      predicate,
     }))
   }
 
+  /// The body is executed when the function is first read, or at the end of the enclosing block,
+  /// so that it sees the types and values declared after it.
   pub fn declare_function(&mut self, node: &'a Function<'a>) {
-    let symbol = node.id.as_ref().unwrap().symbol_id.get().unwrap();
-    let value = self.exec_function(node, None);
+    let id = node.id.as_ref().unwrap();
 
-    self.declare_variable(symbol, true);
+    // Overload signatures are not bound by semantic either, but share the symbol of the
+    // implementation
+    let symbol = id.symbol_id.get().or_else(|| {
+      let scopes = self.semantic.scopes();
+      let parent = scopes.get_parent_id(node.scope_id())?;
+      scopes.get_binding(parent, id.name.as_str())
+    });
+    let Some(symbol) = symbol else {
+      // Ambient function declarations are not bound by semantic, and are treated as globals.
+      // Overloads are merged as an intersection.
+      let value = self.exec_function(node, None);
+      let value = match self.builtins.globals.get(id.name.as_str()) {
+        Some(existing) => self.into_intersection([*existing, value]),
+        None => value,
+      };
+      self.builtins.globals.insert(id.name.as_str(), value);
+      return;
+    };
+
+    if let Some((_, declarations)) = self.hoisted_functions.get_mut(&symbol) {
+      declarations.push(node);
+    } else {
+      self.hoisted_functions.insert(symbol, (self.type_scopes.top(), vec![node]));
+      self.declare_variable(symbol, true);
+    }
+  }
+
+  /// Execute the declarations of a hoisted function, unless they are already executed.
+  pub fn init_hoisted_function(&mut self, symbol: SymbolId) {
+    // Removed first, so that a recursive read does not execute it again
+    let Some((scope, declarations)) = self.hoisted_functions.remove(&symbol) else {
+      return;
+    };
+    let old_top = self.type_scopes.replace_top(scope);
+    let mut overloads = vec![];
+    let mut implementation = None;
+    for node in declarations {
+      let value = self.exec_function(node, None);
+      self.accumulate_type(node.id.as_ref().unwrap(), value);
+      if node.body.is_some() {
+        implementation = Some(value);
+      } else {
+        overloads.push(value);
+      }
+    }
+    // The implementation signature is not callable when there are overloads
+    let value = match (overloads.len(), implementation) {
+      (0, Some(implementation)) => implementation,
+      (1, _) => overloads[0],
+      _ => self.into_intersection(overloads),
+    };
     self.init_variable(symbol, value);
+    self.type_scopes.replace_top(old_top);
   }
 }
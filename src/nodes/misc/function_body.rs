@@ -17,22 +17,42 @@ impl<'a> Analyzer<'a> {
     is_generator: bool,
     this: Option<Ty<'a>>,
     annotated_ret: Option<Ty<'a>>,
+    contextual_ret: Option<Ty<'a>>,
   ) -> Ty<'a> {
     let body_scope = self.push_scope(CfScopeKind::Function);
-    self.call_scopes.push(CallScope::new(
-      body_scope,
-      is_async,
-      is_generator,
-      this.unwrap_or(Ty::Any),
-      annotated_ret,
-    ));
+    let mut call_scope =
+      CallScope::new(body_scope, is_async, is_generator, this.unwrap_or(Ty::Any), annotated_ret);
+    if !is_generator {
+      call_scope.ret_sat = contextual_ret.map(|ty| self.get_return_sat(is_async, ty));
+    }
+    self.call_scopes.push(call_scope);
 
     self.exec_statement_vec(&node.statements);
 
+    // Falling off the end returns `undefined`, unless the end is unreachable
+    let reachable_end = self.runtime_scopes.get(body_scope).exited != Some(true);
+    let call_scope = self.call_scopes.last_mut().unwrap();
+    if let CallScopeReturnType::Inferred(acc) = &mut call_scope.ret {
+      if reachable_end && !acc.is_empty() {
+        acc.add(Ty::Undefined, self.allocator);
+      }
+    }
+
     self.pop_scope();
-    match self.call_scopes.pop().unwrap().ret {
+    let mut call_scope = self.call_scopes.pop().unwrap();
+    match call_scope.ret {
       CallScopeReturnType::Annotated(ty) => ty,
-      CallScopeReturnType::Inferred(mut acc) => acc.to_ty().unwrap_or(Ty::Void),
+      CallScopeReturnType::Inferred(mut acc) => {
+        let ty = acc.to_ty().unwrap_or(Ty::Void);
+        if is_generator {
+          let yielded = call_scope.yielded.to_ty().unwrap_or(Ty::Never);
+          self.create_generator_type(is_async, yielded, ty, Ty::Unknown)
+        } else if is_async {
+          self.create_promise_type(ty)
+        } else {
+          ty
+        }
+      }
     }
   }
 
@@ -41,13 +61,31 @@ impl<'a> Analyzer<'a> {
     node: &'a FunctionBody<'a>,
     is_async: bool,
     annotated_ret: Option<Ty<'a>>,
+    contextual_ret: Option<Ty<'a>>,
   ) -> Ty<'a> {
     let [Statement::ExpressionStatement(expr)] = node.statements.as_slice() else {
       unreachable!();
     };
+    let sat = annotated_ret.or(contextual_ret).map(|ty| self.get_return_sat(is_async, ty));
     self.push_scope(CfScopeKind::Function);
-    let value = self.exec_expression(&expr.expression, annotated_ret);
+    let value = self.exec_expression(&expr.expression, sat);
     self.pop_scope();
-    value
+    if is_async {
+      let value = self.get_to_awaited(value);
+      self.create_promise_type(value)
+    } else {
+      value
+    }
+  }
+
+  /// The contextual type of the returned values in a function returning `ty`. Async functions
+  /// return `Promise<T>`, and may return `T | PromiseLike<T>`.
+  pub fn get_return_sat(&mut self, is_async: bool, ty: Ty<'a>) -> Ty<'a> {
+    if is_async {
+      let value = self.get_to_awaited(ty);
+      self.get_awaitable_type(value)
+    } else {
+      ty
+    }
   }
 }
@@ -1,6 +1,15 @@
-use oxc::ast::ast::IdentifierReference;
+use oxc::{
+  ast::{
+    ast::{BindingPatternKind, IdentifierReference},
+    AstKind,
+  },
+  semantic::SymbolId,
+};
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{unresolved::UnresolvedType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_identifier_reference_read(
@@ -12,10 +21,23 @@ impl<'a> Analyzer<'a> {
     let symbol = reference.symbol_id();
 
     if let Some(symbol) = symbol {
-      self.read_variable(symbol)
+      if self.hoisted_functions.contains_key(&symbol) {
+        self.init_hoisted_function(symbol);
+      }
+      match self.read_variable(symbol) {
+        // A typed variable read before it is initialized, like in a hoisted function
+        ty @ Ty::Unresolved(UnresolvedType::UnInitVariable(_)) => {
+          self.get_annotated_variable_type(symbol).unwrap_or(ty)
+        }
+        ty => ty,
+      }
     } else {
-      // TODO: globals and `arguments`
-      Ty::Unknown
+      // TODO: `arguments`
+      let ty = self.resolve_global_variable(&node.name);
+      if ty == Ty::Error {
+        self.add_diagnostic(format!("Cannot find name '{}'", node.name));
+      }
+      ty
     }
   }
 
@@ -34,4 +56,17 @@ impl<'a> Analyzer<'a> {
       // TODO: globals and `arguments`
     }
   }
+
+  /// The type annotation of a variable declared like `const a: T`.
+  fn get_annotated_variable_type(&mut self, symbol: SymbolId) -> Option<Ty<'a>> {
+    let declaration = self.semantic.symbols().get_declaration(symbol);
+    let AstKind::VariableDeclarator(declarator) = self.semantic.nodes().kind(declaration) else {
+      return None;
+    };
+    let BindingPatternKind::BindingIdentifier(_) = &declarator.id.kind else {
+      return None;
+    };
+    let annotation = declarator.id.type_annotation.as_ref()?;
+    Some(self.resolve_type_annotation(annotation))
+  }
 }
@@ -1 +1,95 @@
+use oxc::ast::ast::{MethodDefinition, MethodDefinitionKind};
 
+use crate::{
+  analyzer::Analyzer,
+  ty::{
+    callable::FunctionType, ctx::CtxTy, interface::InterfaceType, property_key::PropertyKeyType, Ty,
+  },
+};
+
+impl<'a> Analyzer<'a> {
+  /// Add the method or accessor to `target`, which is the instance type or the static side of the
+  /// class. Constructors are handled by the class.
+  pub fn exec_method_definition(
+    &mut self,
+    node: &'a MethodDefinition<'a>,
+    target: &'a InterfaceType<'a>,
+    this: Ty<'a>,
+  ) {
+    let key = self.exec_property_key(&node.key);
+    let sat = match node.kind {
+      MethodDefinitionKind::Set => self.get_setter_sat(target, key),
+      _ => None,
+    };
+    let function = self.exec_method(&node.value, sat, this);
+    self.accumulate_type(&node.key, function);
+    let Ty::Function(callable) = function else { unreachable!() };
+
+    match node.kind {
+      MethodDefinitionKind::Constructor => unreachable!(),
+      MethodDefinitionKind::Method => {
+        self.init_class_member(target, key, function, node.optional, false);
+      }
+      MethodDefinitionKind::Get => {
+        let value = self.resolve_ctx_ty(self.type_scopes.empty_scope, callable.return_type);
+        if !self.merge_accessor(target, key, Some(value)) {
+          self.init_class_member(target, key, value, node.optional, true);
+        }
+      }
+      MethodDefinitionKind::Set => {
+        let value = match callable.params.first() {
+          Some((_, param)) => self.resolve_ctx_ty(self.type_scopes.empty_scope, *param),
+          None => Ty::Any,
+        };
+        if !self.merge_accessor(target, key, None) {
+          self.init_class_member(target, key, value, node.optional, false);
+        }
+      }
+    }
+  }
+
+  /// The contextual signature of a setter, whose parameter takes the type of the getter defined
+  /// before it.
+  fn get_setter_sat(
+    &mut self,
+    target: &'a InterfaceType<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Option<Ty<'a>> {
+    let PropertyKeyType::StringLiteral(name) = key else {
+      return None;
+    };
+    let getter = target.0.borrow().record.string_keyed.0.get(name.as_str())?.value;
+    Some(Ty::Function(self.allocator.alloc(FunctionType {
+      is_method: true,
+      scope: self.type_scopes.empty_scope,
+      type_params: vec![],
+      this_param: None,
+      params: vec![(false, CtxTy::Static(getter))],
+      rest_param: None,
+      return_type: CtxTy::Static(Ty::Void),
+      predicate: None,
+    })))
+  }
+
+  /// A getter and a setter of the same key make a writable property typed by the getter. Returns
+  /// `false` if the other accessor is not defined yet.
+  fn merge_accessor(
+    &mut self,
+    target: &'a InterfaceType<'a>,
+    key: PropertyKeyType<'a>,
+    getter: Option<Ty<'a>>,
+  ) -> bool {
+    let PropertyKeyType::StringLiteral(name) = key else {
+      return false;
+    };
+    let mut inner = target.0.borrow_mut();
+    let Some(property) = inner.record.string_keyed.0.get_mut(name.as_str()) else {
+      return false;
+    };
+    if let Some(getter) = getter {
+      property.value = getter;
+    }
+    property.readonly = false;
+    true
+  }
+}
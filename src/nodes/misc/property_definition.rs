@@ -1 +1,62 @@
+use oxc::{
+  allocator,
+  ast::ast::{AccessorProperty, Expression, PropertyDefinition, TSTypeAnnotation},
+};
 
+use crate::{
+  analyzer::Analyzer,
+  ty::{interface::InterfaceType, Ty},
+};
+
+impl<'a> Analyzer<'a> {
+  /// Add the property to `target`, which is the instance type or the static side of the class.
+  pub fn exec_property_definition(
+    &mut self,
+    node: &'a PropertyDefinition<'a>,
+    target: &'a InterfaceType<'a>,
+    this: Ty<'a>,
+  ) {
+    let key = self.exec_property_key(&node.key);
+    let value =
+      self.exec_class_property_value(&node.type_annotation, &node.value, this, node.readonly);
+    self.accumulate_type(&node.key, value);
+    self.init_class_member(target, key, value, node.optional, node.readonly);
+  }
+
+  /// `accessor x` is a pair of getter and setter, which is typed like a property.
+  pub fn exec_accessor_property(
+    &mut self,
+    node: &'a AccessorProperty<'a>,
+    target: &'a InterfaceType<'a>,
+    this: Ty<'a>,
+  ) {
+    let key = self.exec_property_key(&node.key);
+    let value = self.exec_class_property_value(&node.type_annotation, &node.value, this, false);
+    self.accumulate_type(&node.key, value);
+    self.init_class_member(target, key, value, false, false);
+  }
+
+  fn exec_class_property_value(
+    &mut self,
+    type_annotation: &'a Option<allocator::Box<'a, TSTypeAnnotation<'a>>>,
+    value: &'a Option<Expression<'a>>,
+    this: Ty<'a>,
+    readonly: bool,
+  ) -> Ty<'a> {
+    if let Some(type_annotation) = type_annotation {
+      let annotated = self.resolve_type_annotation(type_annotation);
+      if let Some(value) = value {
+        self.exec_with_this(this, |analyzer| analyzer.exec_expression(value, Some(annotated)));
+      }
+      annotated
+    } else if let Some(value) = value {
+      // Like `const`, readonly properties keep the literal types
+      let as_const = readonly && value.is_literal();
+      self.exec_with_this(this, |analyzer| {
+        analyzer.exec_expression_with_as_const(value, None, as_const)
+      })
+    } else {
+      Ty::Any
+    }
+  }
+}
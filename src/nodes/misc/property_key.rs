@@ -1,14 +1,72 @@
-use oxc::ast::ast::PropertyKey;
+use std::ptr;
 
-use crate::{analyzer::Analyzer, ty::property_key::PropertyKeyType};
+use oxc::ast::ast::{Expression, PropertyKey};
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{property_key::PropertyKeyType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_property_key(&mut self, node: &'a PropertyKey<'a>) -> PropertyKeyType<'a> {
     let value = match node {
       PropertyKey::StaticIdentifier(node) => self.exec_identifier_name(node),
       PropertyKey::PrivateIdentifier(node) => self.exec_private_identifier(node),
+      // Literal keys are not widened
+      PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_) => {
+        self.exec_expression_with_as_const(node.to_expression(), None, true)
+      }
       node => self.exec_expression(node.to_expression(), None),
     };
     self.to_property_key(value)
   }
+
+  /// The key of a property signature. Unlike `exec_property_key`, computed keys are resolved
+  /// without executing, since the signatures in the lib are resolved while analyzing other files.
+  pub fn resolve_property_key(&mut self, node: &'a PropertyKey<'a>) -> PropertyKeyType<'a> {
+    let value = match node {
+      PropertyKey::StaticIdentifier(node) => Ty::StringLiteral(&node.name),
+      PropertyKey::PrivateIdentifier(_) => Ty::Error,
+      node => self.resolve_entity_name_expression(node.to_expression()),
+    };
+    self.to_property_key(value)
+  }
+
+  /// Resolve an expression like `Symbol.iterator` without executing it.
+  fn resolve_entity_name_expression(&mut self, node: &'a Expression<'a>) -> Ty<'a> {
+    let ty = self.resolve_entity_name_expression_impl(node);
+    if let Ty::UniqueSymbol(symbol) = ty {
+      self.unique_symbol_names.entry(symbol).or_insert(node);
+    }
+    ty
+  }
+
+  fn resolve_entity_name_expression_impl(&mut self, node: &'a Expression<'a>) -> Ty<'a> {
+    match node {
+      Expression::StringLiteral(node) => Ty::StringLiteral(&node.value),
+      Expression::NumericLiteral(node) => Ty::NumericLiteral(node.value.into()),
+      Expression::TemplateLiteral(node) if node.expressions.is_empty() => {
+        node.quasis[0].value.cooked.as_ref().map_or(Ty::String, Ty::StringLiteral)
+      }
+      Expression::Identifier(node) => {
+        let symbols = self.semantic.symbols();
+        let symbol = node.reference_id.get().and_then(|id| symbols.get_reference(id).symbol_id());
+        // The variables of the lib are swapped out once it is loaded
+        let is_lib = self.builtins.semantic.is_some_and(|lib| ptr::eq(lib, self.semantic));
+        match symbol {
+          Some(symbol) if !is_lib => self.read_variable(symbol),
+          _ => self.resolve_global_variable(&node.name),
+        }
+      }
+      Expression::StaticMemberExpression(node) => {
+        let object = self.resolve_entity_name_expression(&node.object);
+        let key = self.to_property_key(Ty::StringLiteral(&node.property.name));
+        self.get_property(object, key)
+      }
+      Expression::ParenthesizedExpression(node) => {
+        self.resolve_entity_name_expression(&node.expression)
+      }
+      _ => Ty::Error,
+    }
+  }
 }
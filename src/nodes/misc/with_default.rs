@@ -1,6 +1,9 @@
 use oxc::ast::ast::Expression;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{facts::Facts, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_with_default(&mut self, default: &'a Expression<'a>, init: Option<Ty<'a>>) -> Ty<'a> {
@@ -9,6 +12,8 @@ impl<'a> Analyzer<'a> {
     self.pop_scope();
 
     if let Some(init) = init {
+      // The default value replaces `undefined`
+      let init = self.filter_by_facts(init, Facts::EQ_UNDEFINED);
       self.into_union([default_val, init]).unwrap()
     } else {
       default_val
@@ -20,11 +20,21 @@ impl<'a> Analyzer<'a> {
       Declaration::TSInterfaceDeclaration(node) => {
         self.declare_ts_interface(node);
       }
+      Declaration::TSEnumDeclaration(node) => {
+        self.declare_ts_enum(node);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.declare_ts_module(node);
+      }
       _ => todo!(),
     }
   }
 
   pub fn init_declaration(&mut self, node: &'a Declaration<'a>) {
+    if self.is_early_type_declaration(node) {
+      // Initialized by `init_type_statements`
+      return;
+    }
     match node {
       Declaration::VariableDeclaration(node) => {
         self.init_variable_declaration(node, None);
@@ -41,6 +51,12 @@ impl<'a> Analyzer<'a> {
       Declaration::TSInterfaceDeclaration(node) => {
         self.init_ts_interface(node);
       }
+      Declaration::TSEnumDeclaration(node) => {
+        self.init_ts_enum(node);
+      }
+      Declaration::TSModuleDeclaration(node) => {
+        self.init_ts_module(node);
+      }
       _ => todo!(),
     }
   }
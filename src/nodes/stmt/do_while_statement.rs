@@ -12,6 +12,8 @@ impl<'a> Analyzer<'a> {
     self.pop_scope();
 
     self.push_loop_scope();
+    // The body is repeated only when the test is truthy
+    self.narrow_condition(&node.test, true);
     self.exec_statement(&node.body);
     self.pop_scope();
   }
@@ -5,9 +5,11 @@ use crate::analyzer::Analyzer;
 impl<'a> Analyzer<'a> {
   pub fn exec_for_of_statement(&mut self, node: &'a ForOfStatement<'a>) {
     let right = self.exec_expression(&node.right, None);
-    let iterated = self.iterate_result_union(right);
-
-    // FIXME: node.r#await
+    let iterated = if node.r#await {
+      self.async_iterate_result_union(right)
+    } else {
+      self.iterate_result_union(right)
+    };
 
     self.push_loop_scope();
 
@@ -21,6 +21,7 @@ impl<'a> Analyzer<'a> {
     if let Some(test) = &node.test {
       self.exec_expression(test, None);
       // CHECKER
+      self.narrow_condition(test, true);
     }
 
     if let Some(update) = &node.update {
@@ -6,24 +6,31 @@ impl<'a> Analyzer<'a> {
   pub fn exec_if_statement(&mut self, node: &'a IfStatement) {
     self.exec_expression(&node.test, None);
 
-    if node.alternate.is_some() {
-      self.push_exit_blocker_scope();
+    let scope_1 = if node.alternate.is_some() {
+      self.push_exit_blocker_scope()
     } else {
-      self.push_indeterminate_scope();
-    }
+      self.push_indeterminate_scope()
+    };
 
+    self.narrow_condition(&node.test, true);
     self.exec_statement(&node.consequent);
 
     if let Some(alternate) = &node.alternate {
-      let scope_1 = self.runtime_scopes.pop();
+      self.runtime_scopes.pop();
 
       self.push_exit_blocker_scope();
+      self.narrow_condition(&node.test, false);
       self.exec_statement(alternate);
       let scope_2 = self.runtime_scopes.pop();
 
       self.finalize_complementary_scopes(scope_1, scope_2);
     } else {
+      let exited = self.runtime_scopes.get(scope_1).exited == Some(true);
       self.pop_scope();
+      // Like `if (!x) return;`, the code after is only reached when the test is falsy
+      if exited {
+        self.narrow_condition(&node.test, false);
+      }
     }
   }
 }
@@ -73,8 +73,10 @@ impl<'a> Analyzer<'a> {
     self.pop_span();
   }
 
-  pub fn exec_statement(&mut self, node: &'a Statement) {
+  pub fn exec_statement(&mut self, node: &'a Statement<'a>) {
     self.declare_statement(node);
+    self.init_type_statements([node]);
     self.init_statement(node);
+    self.init_hoisted_functions([node]);
   }
 }
@@ -1,6 +1,16 @@
-use oxc::ast::ast::{ExportDefaultDeclarationKind, ModuleDeclaration};
+use oxc::{
+  ast::ast::{
+    Declaration, ExportDefaultDeclarationKind, ImportDeclarationSpecifier, ModuleDeclaration,
+    ModuleExportName, TSModuleDeclarationName,
+  },
+  semantic::SymbolId,
+};
+use oxc_ecmascript::BoundNames;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{
+  analyzer::Analyzer,
+  ty::{namespace::NamespaceType, Ty},
+};
 
 impl<'a> Analyzer<'a> {
   pub fn declare_module_declaration(&mut self, node: &'a ModuleDeclaration<'a>) {
@@ -11,25 +21,24 @@ impl<'a> Analyzer<'a> {
           let known = self.resolve_module(name);
 
           for specifier in specifiers {
-            let value = if let Some(known) = known {
-              // match specifier {
-              //   ImportDeclarationSpecifier::ImportDefaultSpecifier(_node) => known.default,
-              //   ImportDeclarationSpecifier::ImportNamespaceSpecifier(_node) => known.namespace,
-              //   ImportDeclarationSpecifier::ImportSpecifier(node) => {
-              //     let key = Ty::StringLiteral(match &node.imported {
-              //       ModuleExportName::IdentifierName(identifier) => &identifier.name,
-              //       ModuleExportName::IdentifierReference(identifier) => &identifier.name,
-              //       ModuleExportName::StringLiteral(literal) => &literal.value,
-              //     });
-              //     self.get_property(known.namespace, key)
-              //   }
-              // }
-              todo!()
-            } else {
-              Ty::Unknown
+            let local = specifier.local();
+            let (value, ty) = match (known, specifier) {
+              (None, _) => (Ty::Unknown, Some(Ty::Unknown)),
+              (Some(known), ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)) => {
+                let namespace = Ty::Namespace(known);
+                (namespace, Some(namespace))
+              }
+              (Some(known), ImportDeclarationSpecifier::ImportDefaultSpecifier(_)) => {
+                self.import_member(known, name, "default")
+              }
+              (Some(known), ImportDeclarationSpecifier::ImportSpecifier(node)) => {
+                self.import_member(known, name, node.imported.name().as_str())
+              }
             };
 
-            let local = specifier.local();
+            if let Some(ty) = ty {
+              self.type_scopes.insert_on_top(local.symbol_id(), ty);
+            }
             self.declare_binding_identifier(local, true);
             self.init_binding_identifier(local, Some(value));
           }
@@ -87,8 +96,12 @@ impl<'a> Analyzer<'a> {
         }
       }
       ModuleDeclaration::ExportDefaultDeclaration(node) => {
-        match &node.declaration {
-          ExportDefaultDeclarationKind::FunctionDeclaration(node) => self.exec_function(node, None),
+        let value = match &node.declaration {
+          ExportDefaultDeclarationKind::FunctionDeclaration(node) => match &node.id {
+            // Hoisted, and read by `export_module_declaration`
+            Some(_) => return,
+            None => self.exec_function(node, None),
+          },
           ExportDefaultDeclarationKind::ClassDeclaration(node) => {
             if node.id.is_none() {
               // Patch `export default class{}`
@@ -98,10 +111,13 @@ impl<'a> Analyzer<'a> {
             }
           }
           ExportDefaultDeclarationKind::TSInterfaceDeclaration(node) => {
-            self.init_ts_interface(node)
+            self.init_ts_interface(node);
+            return;
           }
           node => self.exec_expression(node.to_expression(), None),
         };
+        // Read by `export_module_declaration`
+        self.default_export = Some(value);
       }
       ModuleDeclaration::ExportAllDeclaration(_node) => {
         // Nothing to do
@@ -109,9 +125,137 @@ impl<'a> Analyzer<'a> {
       ModuleDeclaration::TSExportAssignment(node) => {
         self.exec_expression(&node.expression, None);
       }
-      ModuleDeclaration::TSNamespaceExportDeclaration(node) => {
-        todo!()
+      ModuleDeclaration::TSNamespaceExportDeclaration(_node) => {
+        // Nothing to do
+      }
+    }
+  }
+
+  /// Collect the exports of an analyzed module declaration into `exports`.
+  pub fn export_module_declaration(
+    &mut self,
+    node: &'a ModuleDeclaration<'a>,
+    exports: &mut NamespaceType<'a>,
+  ) {
+    match node {
+      ModuleDeclaration::ExportNamedDeclaration(node) => {
+        if let Some(source) = &node.source {
+          let known = self.resolve_module(source.value.as_str());
+          for specifier in &node.specifiers {
+            let local = specifier.local.name().as_str();
+            let exported = specifier.exported.name().as_str();
+            if let Some(known) = known {
+              if let Some(value) = known.members.get(local) {
+                exports.members.insert(exported, *value);
+              }
+              if let Some(ty) = known.types.get(local) {
+                exports.types.insert(exported, *ty);
+              }
+            } else {
+              exports.members.insert(exported, Ty::Unknown);
+            }
+          }
+        } else if let Some(declaration) = &node.declaration {
+          self.export_declaration(declaration, exports);
+        } else {
+          for specifier in &node.specifiers {
+            let ModuleExportName::IdentifierReference(local) = &specifier.local else {
+              unreachable!();
+            };
+            let reference = self.semantic.symbols().get_reference(local.reference_id());
+            let exported = specifier.exported.name().as_str();
+            if let Some(symbol) = reference.symbol_id() {
+              self.export_symbol(symbol, exported, exports);
+            } else {
+              exports.members.insert(exported, self.resolve_global_variable(&local.name));
+            }
+          }
+        }
+      }
+      ModuleDeclaration::ExportDefaultDeclaration(node) => match &node.declaration {
+        ExportDefaultDeclarationKind::TSInterfaceDeclaration(node) => {
+          if let Some(ty) = self.type_scopes.get_on_top(node.id.symbol_id()) {
+            exports.types.insert("default", ty);
+          }
+        }
+        ExportDefaultDeclarationKind::FunctionDeclaration(function) if function.id.is_some() => {
+          let symbol = function.id.as_ref().unwrap().symbol_id();
+          exports.members.insert("default", self.read_variable(symbol));
+        }
+        _ => {
+          if let Some(value) = self.default_export {
+            exports.members.insert("default", value);
+          }
+        }
+      },
+      ModuleDeclaration::ExportAllDeclaration(node) => {
+        let known = self.resolve_module(node.source.value.as_str());
+        if let Some(exported) = &node.exported {
+          let value = known.map_or(Ty::Unknown, Ty::Namespace);
+          exports.members.insert(exported.name().as_str(), value);
+        } else if let Some(known) = known {
+          for (name, value) in &known.members {
+            if *name != "default" {
+              exports.members.entry(name).or_insert(*value);
+            }
+          }
+          for (name, ty) in &known.types {
+            if *name != "default" {
+              exports.types.entry(name).or_insert(*ty);
+            }
+          }
+        }
+      }
+      ModuleDeclaration::ImportDeclaration(_)
+      | ModuleDeclaration::TSExportAssignment(_)
+      | ModuleDeclaration::TSNamespaceExportDeclaration(_) => {}
+    }
+  }
+
+  /// Collect the names declared by an analyzed declaration into `exports`.
+  pub fn export_declaration(&mut self, node: &'a Declaration<'a>, exports: &mut NamespaceType<'a>) {
+    node.bound_names(&mut |id| {
+      self.export_symbol(id.symbol_id(), id.name.as_str(), exports);
+    });
+    match node {
+      Declaration::TSTypeAliasDeclaration(node) => {
+        self.export_symbol(node.id.symbol_id(), node.id.name.as_str(), exports)
+      }
+      Declaration::TSInterfaceDeclaration(node) => {
+        self.export_symbol(node.id.symbol_id(), node.id.name.as_str(), exports)
+      }
+      Declaration::TSEnumDeclaration(node) => {
+        self.export_symbol(node.id.symbol_id(), node.id.name.as_str(), exports)
       }
+      Declaration::TSModuleDeclaration(node) => {
+        if let TSModuleDeclarationName::Identifier(id) = &node.id {
+          self.export_symbol(id.symbol_id(), id.name.as_str(), exports)
+        }
+      }
+      _ => {}
+    }
+  }
+
+  fn export_symbol(&mut self, symbol: SymbolId, name: &'a str, exports: &mut NamespaceType<'a>) {
+    if self.semantic.symbols().get_flags(symbol).is_value() {
+      exports.members.insert(name, self.read_variable(symbol));
+    }
+    if let Some(ty) = self.type_scopes.get_on_top(symbol) {
+      exports.types.insert(name, ty);
+    }
+  }
+
+  fn import_member(
+    &mut self,
+    namespace: &'a NamespaceType<'a>,
+    source: &str,
+    name: &str,
+  ) -> (Ty<'a>, Option<Ty<'a>>) {
+    let value = namespace.members.get(name).copied();
+    let ty = namespace.types.get(name).copied();
+    if value.is_none() && ty.is_none() {
+      self.add_diagnostic(format!("Module '{source}' has no exported member '{name}'"));
     }
+    (value.unwrap_or(Ty::Error), ty)
   }
 }
@@ -5,22 +5,33 @@ use crate::{analyzer::Analyzer, scope::call::CallScopeReturnType, ty::Ty};
 impl<'a> Analyzer<'a> {
   pub fn exec_return_statement(&mut self, node: &'a ReturnStatement) {
     let call_scope = self.call_scopes.last().unwrap();
+    let (is_async, is_generator) = (call_scope.is_async, call_scope.is_generator);
     match &call_scope.ret {
       CallScopeReturnType::Annotated(ty) => {
         if let Some(argument) = &node.argument {
-          self.exec_expression(argument, Some(*ty));
+          let sat = if is_generator {
+            self.get_iterator_type_args(*ty).map_or(Ty::Any, |[_, returned, _]| returned)
+          } else {
+            self.get_return_sat(is_async, *ty)
+          };
+          self.exec_expression(argument, Some(sat));
         }
       }
       CallScopeReturnType::Inferred(_) => {
-        let ty = if let Some(argument) = &node.argument {
-          self.exec_expression(argument, None)
+        let sat = call_scope.ret_sat;
+        let mut ty = if let Some(argument) = &node.argument {
+          self.exec_expression(argument, sat)
         } else {
           Ty::Undefined
         };
+        if is_async {
+          ty = self.get_to_awaited(ty);
+        }
         let call_scope = self.call_scopes.last_mut().unwrap();
         let CallScopeReturnType::Inferred(acc) = &mut call_scope.ret else { unreachable!() };
         acc.add(ty, self.allocator);
       }
     }
+    self.exit_function();
   }
 }
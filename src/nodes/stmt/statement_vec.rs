@@ -1,4 +1,7 @@
-use oxc::{allocator::Vec, ast::ast::Statement};
+use oxc::{
+  allocator::Vec,
+  ast::ast::{Declaration, ExportDefaultDeclarationKind, Expression, Statement, TSSignature},
+};
 
 use crate::analyzer::Analyzer;
 
@@ -8,8 +11,103 @@ impl<'a> Analyzer<'a> {
       self.declare_statement(statement);
     }
 
+    self.init_type_statements(statements);
+
     for statement in statements {
       self.init_statement(statement);
     }
+
+    self.init_hoisted_functions(statements);
+  }
+
+  /// Interfaces and type aliases are initialized before the other statements, since they are
+  /// referenced regardless of the order. The interfaces copy the members of their bases, so the
+  /// ones without bases come first. The type aliases come last, since they may index or map the
+  /// interfaces.
+  pub fn init_type_statements(&mut self, statements: impl IntoIterator<Item = &'a Statement<'a>>) {
+    let mut derived_interfaces = vec![];
+    let mut type_aliases = vec![];
+    for statement in statements {
+      let declaration = match statement {
+        Statement::ExportNamedDeclaration(node) => node.declaration.as_ref(),
+        _ => statement.as_declaration(),
+      };
+      match declaration.filter(|declaration| self.is_early_type_declaration(declaration)) {
+        Some(Declaration::TSInterfaceDeclaration(node)) if node.extends.is_some() => {
+          derived_interfaces.push((statement, node));
+        }
+        Some(Declaration::TSInterfaceDeclaration(node)) => {
+          self.push_span(statement);
+          self.init_ts_interface(node);
+          self.pop_span();
+        }
+        Some(Declaration::TSTypeAliasDeclaration(node)) => type_aliases.push((statement, node)),
+        _ => {}
+      }
+    }
+    for (statement, node) in derived_interfaces {
+      self.push_span(statement);
+      self.init_ts_interface(node);
+      self.pop_span();
+    }
+    for (statement, node) in type_aliases {
+      self.push_span(statement);
+      self.init_ts_type_alias(node);
+      self.pop_span();
+    }
+  }
+
+  /// Execute the hoisted functions which are not read during initialization.
+  pub fn init_hoisted_functions(
+    &mut self,
+    statements: impl IntoIterator<Item = &'a Statement<'a>>,
+  ) {
+    for statement in statements {
+      let function = match statement {
+        Statement::FunctionDeclaration(node) => node,
+        Statement::ExportNamedDeclaration(node) => match &node.declaration {
+          Some(Declaration::FunctionDeclaration(node)) => node,
+          _ => continue,
+        },
+        Statement::ExportDefaultDeclaration(node) => match &node.declaration {
+          ExportDefaultDeclarationKind::FunctionDeclaration(node) => node,
+          _ => continue,
+        },
+        _ => continue,
+      };
+      if let Some(symbol) = function.id.as_ref().and_then(|id| id.symbol_id.get()) {
+        self.push_span(statement);
+        self.init_hoisted_function(symbol);
+        self.pop_span();
+      }
+    }
+  }
+
+  /// Whether the declaration is initialized by `init_type_statements` instead of in order.
+  /// Interfaces with computed keys read values, and interfaces extending classes copy their
+  /// members, so they are initialized in order.
+  pub fn is_early_type_declaration(&self, declaration: &Declaration<'a>) -> bool {
+    match declaration {
+      Declaration::TSTypeAliasDeclaration(_) => true,
+      Declaration::TSInterfaceDeclaration(node) => {
+        let has_computed_key = node.body.body.iter().any(|signature| match signature {
+          TSSignature::TSPropertySignature(node) => node.computed,
+          TSSignature::TSMethodSignature(node) => node.computed,
+          _ => false,
+        });
+        let extends_value = node.extends.iter().flatten().any(|heritage| {
+          let Expression::Identifier(id) = &heritage.expression else {
+            return true;
+          };
+          let symbols = self.semantic.symbols();
+          let reference = id.reference_id.get().map(|reference| symbols.get_reference(reference));
+          reference
+            .and_then(|reference| reference.symbol_id())
+            .is_some_and(|symbol| symbols.get_flags(symbol).is_value())
+        });
+        !has_computed_key && !extends_value
+      }
+      _ => false,
+    }
   }
 }
@@ -1,9 +1,83 @@
 use oxc::ast::ast::SwitchStatement;
 
-use crate::analyzer::Analyzer;
+use crate::{analyzer::Analyzer, scope::control::CfScopeKind};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_switch_statement(&mut self, node: &'a SwitchStatement<'a>) {
-    todo!()
+    let discriminant = self.exec_expression(&node.discriminant, None);
+
+    let tests = node.cases.iter().filter_map(|case| case.test.as_ref()).collect::<Vec<_>>();
+
+    // The case clauses share one block scope
+    self.push_scope(CfScopeKind::Switch);
+    for case in &node.cases {
+      for statement in &case.consequent {
+        self.declare_statement(statement);
+      }
+    }
+    self.init_type_statements(node.cases.iter().flat_map(|case| &case.consequent));
+
+    // The tests are executed first, so that their types are known when narrowing, like the enum
+    // members of the later clauses for an earlier `default` clause
+    for test in &tests {
+      self.exec_expression(test, None);
+    }
+
+    // A `break` exits to the depth of the clause scopes
+    let break_depth = self.runtime_scopes.stack.len();
+    let exhaustive = node.cases.iter().any(|case| case.is_default_case())
+      || self.is_switch_exhaustive(&node.discriminant, discriminant, &tests);
+
+    // The tests of the empty clauses and the clauses falling through before a clause also enter it
+    let mut entering = vec![];
+    let mut entering_default = false;
+    let mut fallthrough = false;
+    // The exits blocked by the clauses which do not fall through
+    let mut blocked = vec![];
+    for case in &node.cases {
+      if let Some(test) = &case.test {
+        entering.push(test);
+      } else {
+        entering_default = true;
+      }
+      if case.consequent.is_empty() {
+        continue;
+      }
+
+      let scope = self.push_exit_blocker_scope();
+      match (entering_default, entering.is_empty()) {
+        (false, _) => self.narrow_switch_case(&node.discriminant, &entering, true),
+        (true, true) => self.narrow_switch_case(&node.discriminant, &tests, false),
+        (true, false) => {}
+      }
+      for statement in &case.consequent {
+        self.init_statement(statement);
+      }
+      self.runtime_scopes.pop();
+
+      let blocked_exit = self.runtime_scopes.get(scope).kind.get_blocked_exit();
+      fallthrough = self.runtime_scopes.get(scope).exited != Some(true);
+      if !fallthrough {
+        blocked.push(blocked_exit);
+      }
+      // The code after the switch is reached from the clauses which do not exit past it
+      if fallthrough || blocked_exit == Some(break_depth) {
+        self.apply_shadows([scope], false);
+      }
+
+      if !fallthrough {
+        entering.clear();
+        entering_default = false;
+      }
+    }
+
+    // The switch is left without exiting when no clause matches, or from the last clause
+    if !exhaustive || fallthrough || blocked.is_empty() {
+      blocked.push(None);
+    }
+    self.apply_blocked_exits(&blocked);
+
+    self.init_hoisted_functions(node.cases.iter().flat_map(|case| &case.consequent));
+    self.pop_scope();
   }
 }
@@ -1,10 +1,16 @@
 use oxc::ast::ast::TryStatement;
 
-use crate::{analyzer::Analyzer, ty::Ty};
+use crate::{analyzer::Analyzer, scope::control::CfScopeKind, ty::Ty};
 
 impl<'a> Analyzer<'a> {
   pub fn exec_try_statement(&mut self, node: &'a TryStatement<'a>) {
-    self.exec_block_statement(&node.block);
+    if node.handler.is_some() {
+      self.push_scope(CfScopeKind::Try);
+      self.exec_block_statement(&node.block);
+      self.pop_scope();
+    } else {
+      self.exec_block_statement(&node.block);
+    }
 
     if let Some(handler) = &node.handler {
       self.push_indeterminate_scope();
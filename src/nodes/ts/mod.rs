@@ -1,15 +1,22 @@
+mod ts_array_type;
 mod ts_as_expression;
 mod ts_conditional_type;
+mod ts_constructor_type;
+mod ts_enum_declaration;
 mod ts_function_type;
+mod ts_indexed_access_type;
 mod ts_infer_type;
 mod ts_instantiation_expression;
 mod ts_interface_declaration;
 mod ts_intersection_type;
 mod ts_literal;
+mod ts_mapped_type;
+mod ts_module_declaration;
 mod ts_non_null_expression;
 mod ts_operator_type;
 mod ts_satisfies_expression;
 mod ts_signature_vec;
+mod ts_template_literal_type;
 mod ts_tuple_element;
 mod ts_tuple_type;
 mod ts_type_alias;
@@ -18,6 +25,7 @@ mod ts_type_assertion;
 mod ts_type_literal;
 mod ts_type_parameter_declaration;
 mod ts_type_parameter_instantiation;
+mod ts_type_predicate;
 mod ts_type_query;
 mod ts_type_reference;
 mod ts_union_type;
@@ -32,7 +40,7 @@ impl<'a> Analyzer<'a> {
       TSType::TSAnyKeyword(_) => Ty::Any,
       TSType::TSBigIntKeyword(_) => Ty::BigInt,
       TSType::TSBooleanKeyword(_) => Ty::Boolean,
-      TSType::TSIntrinsicKeyword(_) => todo!(),
+      TSType::TSIntrinsicKeyword(_) => unreachable!("Handled in TSTypeAliasDeclaration"),
       TSType::TSNeverKeyword(_) => Ty::Never,
       TSType::TSNullKeyword(_) => Ty::Null,
       TSType::TSNumberKeyword(_) => Ty::Number,
@@ -52,10 +60,17 @@ impl<'a> Analyzer<'a> {
       TSType::TSTypeLiteral(node) => self.resolve_type_literal(node),
       TSType::TSInferType(node) => self.resolve_infer_type(node),
       TSType::TSFunctionType(node) => self.resolve_function_type(node),
+      TSType::TSConstructorType(node) => self.resolve_constructor_type(node),
       TSType::TSConditionalType(node) => self.resolve_conditional_type(node),
       TSType::TSTypeOperatorType(node) => self.resolve_operator_type(node),
       TSType::TSTupleType(node) => self.resolve_tuple_type(node, false),
+      TSType::TSArrayType(node) => self.resolve_array_type(node, false),
+      TSType::TSMappedType(node) => self.resolve_mapped_type(node),
+      TSType::TSIndexedAccessType(node) => self.resolve_indexed_access_type(node),
+      TSType::TSTypePredicate(node) => self.resolve_type_predicate(node),
+      TSType::TSTemplateLiteralType(node) => self.resolve_template_literal_type(node),
       TSType::TSNamedTupleMember(_) => unreachable!("Handled in TSTupleElement"),
+      TSType::TSThisType(_) => self.type_scopes.search_this().unwrap_or(Ty::Error),
 
       _ => todo!(),
     }
@@ -0,0 +1,10 @@
+use oxc::ast::ast::TSArrayType;
+
+use crate::{ty::Ty, Analyzer};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_array_type(&mut self, node: &'a TSArrayType<'a>, readonly: bool) -> Ty<'a> {
+    let element = self.resolve_type(&node.element_type);
+    self.create_array_type(element, readonly)
+  }
+}
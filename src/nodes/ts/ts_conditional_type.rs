@@ -2,11 +2,29 @@ use crate::{
   ty::{r#match::MatchResult, Ty},
   Analyzer,
 };
-use oxc::ast::ast::TSConditionalType;
+use oxc::{
+  ast::ast::{TSConditionalType, TSType, TSTypeName},
+  semantic::SymbolId,
+};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_conditional_type(&mut self, node: &'a TSConditionalType<'a>) -> Ty<'a> {
     let target = self.resolve_type(&node.check_type);
+
+    // Distributive conditional type
+    if let (Some(symbol), Ty::Union(union)) =
+      (self.get_naked_type_parameter(&node.check_type), target)
+    {
+      let mut members = vec![];
+      union.for_each(|ty| members.push(ty));
+      let mut results = vec![];
+      for member in members {
+        self.type_scopes.push_with_types([(symbol, member)].into_iter().collect());
+        results.push(self.resolve_conditional_type(node));
+        self.type_scopes.pop();
+      }
+      return self.into_union(results).unwrap_or(Ty::Never);
+    }
     let pattern = self.resolve_type(&node.extends_type);
 
     let mut matched_no_infer = None;
@@ -47,4 +65,20 @@ impl<'a> Analyzer<'a> {
 
     self.into_union(results).unwrap()
   }
+
+  /// The type parameter referenced by `node` without type arguments, like `T`.
+  pub fn get_naked_type_parameter(&self, node: &'a TSType<'a>) -> Option<SymbolId> {
+    let TSType::TSTypeReference(node) = node else {
+      return None;
+    };
+    let TSTypeName::IdentifierReference(id) = &node.type_name else {
+      return None;
+    };
+    if node.type_parameters.is_some() {
+      return None;
+    }
+    let symbols = self.semantic.symbols();
+    let symbol = symbols.get_reference(id.reference_id.get()?).symbol_id()?;
+    symbols.get_flags(symbol).is_type_parameter().then_some(symbol)
+  }
 }
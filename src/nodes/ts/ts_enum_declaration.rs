@@ -0,0 +1,183 @@
+use oxc::{
+  ast::ast::{BinaryOperator, Expression, TSEnumDeclaration, UnaryOperator},
+  span::Atom,
+};
+use oxc_ecmascript::ToInt32;
+use oxc_syntax::number::ToJsString;
+use rustc_hash::FxHashMap;
+
+use crate::{
+  ty::{namespace::NamespaceType, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  /// An enum is both a value, which is a namespace of its members, and a type, which is the union
+  /// of the member types. Members are typed as literals, so that they narrow like literals.
+  pub fn declare_ts_enum(&mut self, node: &'a TSEnumDeclaration<'a>) {
+    let symbol = node.id.symbol_id();
+    self.declare_variable(symbol, true);
+
+    let mut namespace = NamespaceType::default();
+    let mut members = FxHashMap::default();
+    let mut member_types = vec![];
+    let mut previous = None;
+    for member in &node.members {
+      let name = member.id.static_name();
+      let value = if let Some(initializer) = &member.initializer {
+        self.exec_enum_initializer(initializer, &node.id.name, &members)
+      } else {
+        // Auto-incremented from the previous member, which must be numeric
+        match previous {
+          None => Ty::NumericLiteral(0.0.into()),
+          Some(Ty::NumericLiteral(n)) => Ty::NumericLiteral((n.0 + 1.0).into()),
+          Some(_) => Ty::Number,
+        }
+      };
+      previous = Some(value);
+      member_types.push(value);
+      members.insert(name.as_str(), value);
+      namespace.members.insert(name.as_str(), value);
+      namespace.types.insert(name.as_str(), value);
+      self.accumulate_type(&member.id, value);
+
+      // Members can be referenced by name in the initializers of the latter ones
+      let scope_id = node.scope_id.get().unwrap();
+      if let Some(symbol) = self.semantic.scopes().get_binding(scope_id, name.as_str()) {
+        self.declare_variable(symbol, true);
+        self.init_variable(symbol, value);
+      }
+    }
+
+    let ty = self.into_union(member_types).unwrap_or(Ty::Number);
+    if let Ty::Union(_) = ty {
+      self.named_types.insert(ty, node.id.name.as_str());
+    }
+    self.type_scopes.insert_on_top(symbol, ty);
+
+    let value = Ty::Namespace(self.allocator.alloc(namespace));
+    self.named_values.insert(value, node.id.name.as_str());
+    self.accumulate_type(&node.id, value);
+    self.init_variable(symbol, value);
+  }
+
+  pub fn init_ts_enum(&mut self, _node: &'a TSEnumDeclaration<'a>) {
+    // Do nothing
+  }
+
+  /// Constant initializers are evaluated to literal types, and the others are computed members.
+  fn exec_enum_initializer(
+    &mut self,
+    node: &'a Expression<'a>,
+    enum_name: &'a str,
+    members: &FxHashMap<&'a str, Ty<'a>>,
+  ) -> Ty<'a> {
+    if let Some(value) = self.eval_enum_constant(node, enum_name, members) {
+      return value;
+    }
+    match self.exec_expression(node, None) {
+      // Members of other enums
+      ty @ (Ty::NumericLiteral(_) | Ty::StringLiteral(_)) => ty,
+      _ => Ty::Number,
+    }
+  }
+
+  /// Evaluate a constant enum expression, which only references the previous members by name or
+  /// like `E.A`.
+  fn eval_enum_constant(
+    &mut self,
+    node: &'a Expression<'a>,
+    enum_name: &'a str,
+    members: &FxHashMap<&'a str, Ty<'a>>,
+  ) -> Option<Ty<'a>> {
+    let member = |name: &str| {
+      members
+        .get(name)
+        .copied()
+        .filter(|ty| matches!(ty, Ty::NumericLiteral(_) | Ty::StringLiteral(_)))
+    };
+    match node {
+      Expression::NumericLiteral(node) => Some(Ty::NumericLiteral(node.value.into())),
+      Expression::StringLiteral(node) => Some(Ty::StringLiteral(&node.value)),
+      Expression::TemplateLiteral(node) if node.expressions.is_empty() => {
+        let cooked = node.quasis[0].value.cooked.as_ref()?;
+        Some(Ty::StringLiteral(cooked))
+      }
+      Expression::ParenthesizedExpression(node) => {
+        self.eval_enum_constant(&node.expression, enum_name, members)
+      }
+      Expression::Identifier(node) => match node.name.as_str() {
+        name if members.contains_key(name) => member(name),
+        "NaN" => Some(Ty::NumericLiteral(f64::NAN.into())),
+        "Infinity" => Some(Ty::NumericLiteral(f64::INFINITY.into())),
+        _ => None,
+      },
+      Expression::StaticMemberExpression(node) => match &node.object {
+        Expression::Identifier(object) if object.name == enum_name => member(&node.property.name),
+        _ => None,
+      },
+      Expression::ComputedMemberExpression(node) => match (&node.object, &node.expression) {
+        (Expression::Identifier(object), Expression::StringLiteral(key))
+          if object.name == enum_name =>
+        {
+          member(&key.value)
+        }
+        _ => None,
+      },
+      Expression::UnaryExpression(node) => {
+        let Ty::NumericLiteral(n) = self.eval_enum_constant(&node.argument, enum_name, members)?
+        else {
+          return None;
+        };
+        let value = match node.operator {
+          UnaryOperator::UnaryPlus => n.0,
+          UnaryOperator::UnaryNegation => -n.0,
+          UnaryOperator::BitwiseNot => f64::from(!n.0.to_int_32()),
+          _ => return None,
+        };
+        Some(Ty::NumericLiteral(value.into()))
+      }
+      Expression::BinaryExpression(node) => {
+        let left = self.eval_enum_constant(&node.left, enum_name, members)?;
+        let right = self.eval_enum_constant(&node.right, enum_name, members)?;
+        match (left, right) {
+          (Ty::NumericLiteral(l), Ty::NumericLiteral(r)) => {
+            let (l, r) = (l.0, r.0);
+            let shift = (r.to_int_32() as u32) & 31;
+            let value = match node.operator {
+              BinaryOperator::Addition => l + r,
+              BinaryOperator::Subtraction => l - r,
+              BinaryOperator::Multiplication => l * r,
+              BinaryOperator::Division => l / r,
+              BinaryOperator::Remainder => l % r,
+              BinaryOperator::Exponential => l.powf(r),
+              BinaryOperator::ShiftLeft => f64::from(l.to_int_32().wrapping_shl(shift)),
+              BinaryOperator::ShiftRight => f64::from(l.to_int_32().wrapping_shr(shift)),
+              BinaryOperator::ShiftRightZeroFill => {
+                f64::from((l.to_int_32() as u32).wrapping_shr(shift))
+              }
+              BinaryOperator::BitwiseAnd => f64::from(l.to_int_32() & r.to_int_32()),
+              BinaryOperator::BitwiseOR => f64::from(l.to_int_32() | r.to_int_32()),
+              BinaryOperator::BitwiseXOR => f64::from(l.to_int_32() ^ r.to_int_32()),
+              _ => return None,
+            };
+            Some(Ty::NumericLiteral(value.into()))
+          }
+          (left, right) if node.operator == BinaryOperator::Addition => {
+            let to_string = |ty: Ty<'a>| match ty {
+              Ty::NumericLiteral(n) => n.0.to_js_string(),
+              Ty::StringLiteral(s) => s.to_string(),
+              _ => unreachable!(),
+            };
+            let value = to_string(left) + &to_string(right);
+            Some(Ty::StringLiteral(
+              self.allocator.alloc(Atom::from(&*self.allocator.alloc_str(&value))),
+            ))
+          }
+          _ => None,
+        }
+      }
+      _ => None,
+    }
+  }
+}
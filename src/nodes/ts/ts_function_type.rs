@@ -16,6 +16,7 @@ impl<'a> Analyzer<'a> {
       node.this_param.as_ref().map(|n| self.ctx_ty_from_annotation(&n.type_annotation, None));
     let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
     let return_type = self.ctx_ty_from_ts_type(&node.return_type.type_annotation);
+    let predicate = self.get_type_predicate(Some(&node.return_type.type_annotation), &node.params);
 
     Ty::Function(self.allocator.alloc(CallableType {
       is_method: false,
@@ -25,6 +26,7 @@ impl<'a> Analyzer<'a> {
       params,
       rest_param,
       return_type,
+      predicate,
     }))
   }
 }
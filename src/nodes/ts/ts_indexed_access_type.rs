@@ -0,0 +1,11 @@
+use oxc::ast::ast::TSIndexedAccessType;
+
+use crate::{ty::Ty, Analyzer};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_indexed_access_type(&mut self, node: &'a TSIndexedAccessType<'a>) -> Ty<'a> {
+    let object = self.resolve_type(&node.object_type);
+    let index = self.resolve_type(&node.index_type);
+    self.get_indexed_access_type(object, index)
+  }
+}
@@ -1,48 +1,76 @@
+use std::cell::RefCell;
+
 use oxc::ast::ast::{Expression, TSInterfaceDeclaration};
 
 use crate::{
-  ty::{interface::InterfaceTypeInner, unresolved::UnresolvedType, Ty},
+  ty::{
+    generic::{GenericBody, GenericType},
+    interface::InterfaceType,
+    Ty,
+  },
   Analyzer,
 };
 
 impl<'a> Analyzer<'a> {
   pub fn declare_ts_interface(&mut self, node: &'a TSInterfaceDeclaration<'a>) {
     let symbol_id = node.id.symbol_id();
-    self
-      .type_scopes
-      .insert_on_top(symbol_id, Ty::Unresolved(UnresolvedType::UnInitType(symbol_id)));
+    let existing = self.type_scopes.get_on_top(symbol_id);
+
+    if let Some(type_parameters) = &node.type_parameters {
+      let declaration = (self.type_scopes.top(), node);
+      if let Some(Ty::Generic(GenericType { body: GenericBody::Interface(declarations), .. })) =
+        existing
+      {
+        // Declaration merging
+        declarations.borrow_mut().push(declaration);
+      } else {
+        let params = self.resolve_type_parameter_declaration(type_parameters);
+        let generic = Ty::Generic(self.allocator.alloc(GenericType {
+          name: &node.id.name,
+          params,
+          body: GenericBody::Interface(RefCell::new(vec![declaration])),
+        }));
+        self.type_scopes.insert_on_top(symbol_id, generic);
+      }
+    } else if !matches!(existing, Some(Ty::Interface(_))) {
+      let interface = Ty::Interface(self.allocator.alloc(InterfaceType::default()));
+      self.type_scopes.insert_on_top(symbol_id, interface);
+    }
   }
 
   pub fn init_ts_interface(&mut self, node: &'a TSInterfaceDeclaration<'a>) -> Ty<'a> {
-    let symbol_id = node.id.symbol_id();
-
-    let params =
-      node.type_parameters.as_ref().map(|params| self.resolve_type_parameter_declaration(params));
-
-    let ty = self.type_scopes.get_mut_on_top(symbol_id).unwrap();
-
-    let mut interface = match ty {
-      Ty::Interface(interface) => interface.0.borrow_mut(),
-      _ => unreachable!(),
-    };
-
-    let InterfaceTypeInner { callables, .. } = &mut *interface;
-    if let Some(new_record) = self.resolve_signature_vec(&node.body.body, callables) {
-      interface.record.extend(new_record);
+    let ty = self.type_scopes.get_on_top(node.id.symbol_id()).unwrap();
+    if let Ty::Interface(interface) = ty {
+      let scope = self.type_scopes.push();
+      self.type_scopes.set_this(scope, ty);
+      self.init_interface_body(node, interface);
+      self.type_scopes.pop();
     }
+    ty
+  }
 
+  /// Fill the members of one declaration into the interface.
+  pub fn init_interface_body(
+    &mut self,
+    node: &'a TSInterfaceDeclaration<'a>,
+    interface: &'a InterfaceType<'a>,
+  ) {
+    let mut bases = vec![];
     if let Some(extends) = &node.extends {
       for heritage in extends {
         match &heritage.expression {
           Expression::Identifier(id) => {
             let base = self.resolve_type_identifier_reference(id);
-            let extends = if let Some(type_parameters) = &heritage.type_parameters {
+            let base = if let Some(type_parameters) = &heritage.type_parameters {
               let type_parameters = self.resolve_type_parameter_instantiation(type_parameters);
               self.create_generic_instance(base, type_parameters)
             } else {
               base
             };
-            interface.extend(extends);
+            bases.push(match base {
+              Ty::Instance(instance) => self.unwrap_generic_instance(instance),
+              base => base,
+            });
           }
           _ => {
             // TODO: Error: An interface can only extend an identifier/qualified-name with optional type arguments.
@@ -51,6 +79,16 @@ impl<'a> Analyzer<'a> {
       }
     }
 
-    self.type_scopes.get_on_top(symbol_id).unwrap()
+    let mut callables = vec![];
+    let record = self.resolve_signature_vec(&node.body.body, &mut callables);
+
+    let mut interface = interface.0.borrow_mut();
+    for base in bases {
+      interface.extend(base);
+    }
+    interface.callables.extend(callables);
+    if let Some(record) = record {
+      interface.record.extend(record);
+    }
   }
 }
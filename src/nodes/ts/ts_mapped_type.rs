@@ -0,0 +1,242 @@
+use oxc::{
+  ast::{
+    ast::{
+      TSMappedType, TSMappedTypeModifierOperator, TSType, TSTypeOperator, TSTypeOperatorOperator,
+    },
+    AstKind,
+  },
+  span::Atom,
+};
+
+use crate::{
+  ty::{
+    facts::Facts,
+    record::{RecordPropertyValue, RecordTypeBuilder},
+    tuple::{TupleElement, TupleType},
+    Ty,
+  },
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_mapped_type(&mut self, node: &'a TSMappedType<'a>) -> Ty<'a> {
+    match &node.type_parameter.constraint {
+      Some(TSType::TSTypeOperatorType(constraint))
+        if constraint.operator == TSTypeOperatorOperator::Keyof =>
+      {
+        self.resolve_homomorphic_mapped_type(node, constraint)
+      }
+      Some(constraint) => {
+        let mut keys = self.resolve_type(constraint);
+        // Like `[P in Exclude<keyof T, K>]`
+        while let Ty::Instance(instance) = keys {
+          keys = self.unwrap_generic_instance(instance);
+        }
+        // `{ [P in K]: ... }` where `K extends keyof T` keeps the modifiers of `T`, like `Pick`
+        let source = self.get_keyof_constraint_source(constraint);
+        let source = source.and_then(|source| self.get_object_record(source));
+        let mut builder = RecordTypeBuilder::default();
+        let mut members = vec![];
+        match keys {
+          Ty::Union(union) => union.for_each(|ty| members.push(ty)),
+          Ty::Never => {}
+          key => members.push(key),
+        }
+        for key in members {
+          let original = match (&source, key) {
+            (Some(source), Ty::StringLiteral(s)) => source.string_keyed.0.get(s.as_str()),
+            (Some(source), Ty::UniqueSymbol(s)) => source.symbol_keyed.0.get(&s),
+            _ => None,
+          };
+          let property = RecordPropertyValue {
+            value: Ty::Error,
+            optional: original.is_some_and(|original| original.optional),
+            readonly: original.is_some_and(|original| original.readonly),
+          };
+          self.init_mapped_property(&mut builder, node, key, property);
+        }
+        Ty::Record(self.allocator.alloc(builder.build()))
+      }
+      None => Ty::Error,
+    }
+  }
+
+  /// `{ [K in keyof T]: ... }`, which keeps the modifiers of the properties of `T`.
+  fn resolve_homomorphic_mapped_type(
+    &mut self,
+    node: &'a TSMappedType<'a>,
+    constraint: &'a TSTypeOperator<'a>,
+  ) -> Ty<'a> {
+    let source = self.resolve_type(&constraint.type_annotation);
+
+    // Distributive over a union type argument, like `Partial<A | B>`
+    if let (Some(symbol), Ty::Union(union)) =
+      (self.get_naked_type_parameter(&constraint.type_annotation), source)
+    {
+      let mut members = vec![];
+      union.for_each(|ty| members.push(ty));
+      let mut results = vec![];
+      for member in members {
+        self.type_scopes.push_with_types([(symbol, member)].into_iter().collect());
+        results.push(self.resolve_homomorphic_mapped_type(node, constraint));
+        self.type_scopes.pop();
+      }
+      return self.into_union(results).unwrap_or(Ty::Never);
+    }
+
+    match source {
+      // Primitives are not mapped
+      Ty::BigInt
+      | Ty::Boolean
+      | Ty::Null
+      | Ty::Number
+      | Ty::String
+      | Ty::Symbol
+      | Ty::Undefined
+      | Ty::StringLiteral(_)
+      | Ty::NumericLiteral(_)
+      | Ty::BigIntLiteral(_)
+      | Ty::BooleanLiteral(_)
+      | Ty::UniqueSymbol(_)
+      | Ty::Any
+      | Ty::Never
+      | Ty::Error => source,
+      Ty::Tuple(tuple) if node.name_type.is_none() => {
+        let mut elements = vec![];
+        for (index, element) in tuple.elements.iter().enumerate() {
+          let key = if element.spread {
+            Ty::Number
+          } else {
+            Ty::StringLiteral(
+              self.allocator.alloc(Atom::from(&*self.allocator.alloc_str(&index.to_string()))),
+            )
+          };
+          let ty = self.resolve_mapped_value(node, key);
+          let optional = apply_modifier(node.optional, element.optional);
+          elements.push(TupleElement { name: element.name, spread: element.spread, optional, ty });
+        }
+        let readonly = apply_modifier(node.readonly, tuple.readonly);
+        Ty::Tuple(self.allocator.alloc(TupleType { elements, readonly }))
+      }
+      // Arrays are mapped by their element type
+      Ty::Instance(instance)
+        if node.name_type.is_none()
+          && matches!(
+            self.builtins.global_type_names.get(&instance.generic),
+            Some(&"Array" | &"ReadonlyArray")
+          ) =>
+      {
+        let ty = self.resolve_mapped_value(node, Ty::Number);
+        let readonly =
+          self.builtins.global_type_names.get(&instance.generic) == Some(&"ReadonlyArray");
+        let readonly = apply_modifier(node.readonly, readonly);
+        self.create_array_type(ty, readonly)
+      }
+      _ => {
+        let Some(record) = self.get_object_record(source) else {
+          return Ty::Error;
+        };
+        let mut builder = RecordTypeBuilder::default();
+        for (key, property) in record.string_keyed.0 {
+          let key = Ty::StringLiteral(self.allocator.alloc(Atom::from(key)));
+          self.init_mapped_property(&mut builder, node, key, property);
+        }
+        for (key, property) in record.symbol_keyed.0 {
+          self.init_mapped_property(&mut builder, node, Ty::UniqueSymbol(key), property);
+        }
+        for (key, property) in [
+          (Ty::String, record.string_mapped),
+          (Ty::Number, record.number_mapped),
+          (Ty::Symbol, record.symbol_mapped),
+        ] {
+          if let Some(property) = property {
+            self.init_mapped_property(&mut builder, node, key, property);
+          }
+        }
+        Ty::Record(self.allocator.alloc(builder.build()))
+      }
+    }
+  }
+
+  /// The `T` of a type parameter declared as `K extends keyof T`, if `node` references one.
+  fn get_keyof_constraint_source(&mut self, node: &'a TSType<'a>) -> Option<Ty<'a>> {
+    let symbol = self.get_naked_type_parameter(node)?;
+    let declaration = self.semantic.symbols().get_declaration(symbol);
+    let AstKind::TSTypeParameter(param) = self.semantic.nodes().kind(declaration) else {
+      return None;
+    };
+    match &param.constraint {
+      Some(TSType::TSTypeOperatorType(constraint))
+        if constraint.operator == TSTypeOperatorOperator::Keyof =>
+      {
+        Some(self.resolve_type(&constraint.type_annotation))
+      }
+      _ => None,
+    }
+  }
+
+  /// Add the property mapped from `key`, whose original modifiers are in `property`.
+  fn init_mapped_property(
+    &mut self,
+    builder: &mut RecordTypeBuilder<'a>,
+    node: &'a TSMappedType<'a>,
+    key: Ty<'a>,
+    property: RecordPropertyValue<'a>,
+  ) {
+    // Remapped by the `as` clause, where `never` filters the key out
+    let name = match &node.name_type {
+      Some(name_type) => {
+        self.with_mapped_key(node, key, |analyzer| analyzer.resolve_type(name_type))
+      }
+      None => key,
+    };
+    let mut value = self.resolve_mapped_value(node, key);
+
+    let optional = apply_modifier(node.optional, property.optional);
+    let readonly = apply_modifier(node.readonly, property.readonly);
+    if property.optional && !optional && !self.config.exact_optional_property_types {
+      // `-?` also removes `undefined`, unless it is written explicitly
+      value = self.filter_by_facts(value, Facts::EQ_UNDEFINED);
+    }
+    let mut names = vec![];
+    match name {
+      Ty::Union(union) => union.for_each(|ty| names.push(ty)),
+      Ty::Never => {}
+      name => names.push(name),
+    }
+    for name in names {
+      let key = self.to_property_key(name);
+      builder.init_property(self, key, value, optional, readonly);
+    }
+  }
+
+  fn resolve_mapped_value(&mut self, node: &'a TSMappedType<'a>, key: Ty<'a>) -> Ty<'a> {
+    match &node.type_annotation {
+      Some(type_annotation) => {
+        self.with_mapped_key(node, key, |analyzer| analyzer.resolve_type(type_annotation))
+      }
+      None => Ty::Any,
+    }
+  }
+
+  fn with_mapped_key<T>(
+    &mut self,
+    node: &'a TSMappedType<'a>,
+    key: Ty<'a>,
+    f: impl FnOnce(&mut Self) -> T,
+  ) -> T {
+    let symbol = node.type_parameter.name.symbol_id();
+    self.type_scopes.push_with_types([(symbol, key)].into_iter().collect());
+    let result = f(self);
+    self.type_scopes.pop();
+    result
+  }
+}
+
+fn apply_modifier(modifier: TSMappedTypeModifierOperator, original: bool) -> bool {
+  match modifier {
+    TSMappedTypeModifierOperator::True | TSMappedTypeModifierOperator::Plus => true,
+    TSMappedTypeModifierOperator::Minus => false,
+    TSMappedTypeModifierOperator::None => original,
+  }
+}
@@ -0,0 +1,88 @@
+use oxc::ast::ast::{
+  Statement, TSModuleDeclaration, TSModuleDeclarationBody, TSModuleDeclarationName,
+};
+
+use crate::{
+  ty::{namespace::NamespaceType, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  /// A namespace is a value holding its exported members, and its exported types are referenced by
+  /// qualified names. `declare module "x"` and `declare global` are not supported.
+  pub fn declare_ts_module(&mut self, node: &'a TSModuleDeclaration<'a>) {
+    if let TSModuleDeclarationName::Identifier(id) = &node.id {
+      self.declare_variable(id.symbol_id(), true);
+    }
+  }
+
+  pub fn init_ts_module(&mut self, node: &'a TSModuleDeclaration<'a>) {
+    let TSModuleDeclarationName::Identifier(id) = &node.id else {
+      return;
+    };
+    let value = self.exec_ts_module_body(node);
+    self.named_values.insert(value, id.name.as_str());
+    self.type_scopes.insert_on_top(id.symbol_id(), value);
+    self.accumulate_type(id, value);
+    self.init_variable(id.symbol_id(), value);
+  }
+
+  fn exec_ts_module_body(&mut self, node: &'a TSModuleDeclaration<'a>) -> Ty<'a> {
+    let mut namespace = NamespaceType::default();
+    match &node.body {
+      Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+        // Ambient function declarations are not bound by semantic, so they are collected here
+        // instead of becoming globals. Overloads are merged as an intersection.
+        let is_ambient_function = |statement: &Statement| matches!(statement, Statement::FunctionDeclaration(node) if node.id.as_ref().is_some_and(|id| id.symbol_id.get().is_none()));
+        for statement in &block.body {
+          if !is_ambient_function(statement) {
+            self.declare_statement(statement);
+          }
+        }
+        self.init_type_statements(&block.body);
+        for statement in &block.body {
+          if let Statement::FunctionDeclaration(node) = statement {
+            if is_ambient_function(statement) {
+              let name = node.id.as_ref().unwrap().name.as_str();
+              let value = self.exec_function(node, None);
+              let value = match namespace.members.get(name) {
+                Some(existing) => self.into_intersection([*existing, value]),
+                None => value,
+              };
+              namespace.members.insert(name, value);
+              continue;
+            }
+          }
+          self.init_statement(statement);
+        }
+        self.init_hoisted_functions(&block.body);
+        for statement in &block.body {
+          match statement {
+            _ if is_ambient_function(statement) => {}
+            // Everything in an ambient namespace is exported
+            Statement::ExportNamedDeclaration(_) | Statement::ExportDefaultDeclaration(_) => {
+              self.export_module_declaration(statement.to_module_declaration(), &mut namespace);
+            }
+            _ if node.declare => {
+              if let Some(declaration) = statement.as_declaration() {
+                self.export_declaration(declaration, &mut namespace);
+              }
+            }
+            _ => {}
+          }
+        }
+      }
+      // `namespace A.B {}`
+      Some(TSModuleDeclarationBody::TSModuleDeclaration(inner)) => {
+        if let TSModuleDeclarationName::Identifier(id) = &inner.id {
+          self.declare_ts_module(inner);
+          self.init_ts_module(inner);
+          namespace.members.insert(id.name.as_str(), self.read_variable(id.symbol_id()));
+          namespace.types.insert(id.name.as_str(), self.read_variable(id.symbol_id()));
+        }
+      }
+      None => {}
+    }
+    Ty::Namespace(self.allocator.alloc(namespace))
+  }
+}
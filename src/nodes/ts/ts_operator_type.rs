@@ -1,26 +1,34 @@
-use oxc::ast::ast::{TSType, TSTypeOperator, TSTypeOperatorOperator};
-
-use crate::{
-  ty::{unresolved::UnresolvedType, Ty},
-  Analyzer,
+use oxc::{
+  ast::ast::{TSType, TSTypeOperator, TSTypeOperatorOperator},
+  semantic::SymbolId,
 };
 
+use crate::{ty::Ty, Analyzer};
+
 impl<'a> Analyzer<'a> {
   pub fn resolve_operator_type(&mut self, node: &'a TSTypeOperator<'a>) -> Ty<'a> {
     match node.operator {
       TSTypeOperatorOperator::Keyof => {
         let ty = self.resolve_type(&node.type_annotation);
-        Ty::Unresolved(UnresolvedType::Keyof(self.allocator.alloc(ty)))
+        self.get_keyof_type(ty)
       }
       TSTypeOperatorOperator::Readonly => match &node.type_annotation {
         TSType::TSTupleType(node) => self.resolve_tuple_type(node, true),
-        TSType::TSArrayType(node) => todo!(),
+        TSType::TSArrayType(node) => self.resolve_array_type(node, true),
         _ => self.resolve_type(&node.type_annotation),
       },
       TSTypeOperatorOperator::Unique => match &node.type_annotation {
-        TSType::TSSymbolKeyword(_) => todo!(),
+        TSType::TSSymbolKeyword(_) => Ty::UniqueSymbol(self.get_unique_symbol(node)),
         _ => self.resolve_type(&node.type_annotation),
       },
     }
   }
+
+  /// Each `unique symbol` annotation declares a distinct symbol. Since it may be resolved multiple
+  /// times, the identity is stored by the node, and allocated from the end of the symbol ids so
+  /// that it never collides with a declared symbol.
+  fn get_unique_symbol(&mut self, node: &'a TSTypeOperator<'a>) -> SymbolId {
+    let count = self.unique_symbols.len() as u32;
+    *self.unique_symbols.entry(node).or_insert_with(|| SymbolId::new(u32::MAX - 1 - count))
+  }
 }
@@ -33,7 +33,7 @@ impl<'a> Analyzer<'a> {
           );
         }
         TSSignature::TSPropertySignature(node) => {
-          let key = self.exec_property_key(&node.key);
+          let key = self.resolve_property_key(&node.key);
           let value = if let Some(type_annotation) = &node.type_annotation {
             self.resolve_type_annotation(type_annotation)
           } else {
@@ -57,6 +57,10 @@ impl<'a> Analyzer<'a> {
             node.this_param.as_ref().map(|n| self.ctx_ty_from_annotation(&n.type_annotation, None));
           let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
           let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
+          let predicate = self.get_type_predicate(
+            node.return_type.as_ref().map(|n| &n.type_annotation),
+            &node.params,
+          );
 
           callables.push(Ty::Function(self.allocator.alloc(CallableType {
             is_method: false,
@@ -66,9 +70,33 @@ impl<'a> Analyzer<'a> {
             params,
             rest_param,
             return_type,
+            predicate,
+          })))
+        }
+        TSSignature::TSConstructSignatureDeclaration(node) => {
+          let type_params = node
+            .type_parameters
+            .as_ref()
+            .map(|type_params| self.resolve_type_parameter_declaration(type_params))
+            .unwrap_or_default();
+          let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
+          let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
+          let predicate = self.get_type_predicate(
+            node.return_type.as_ref().map(|n| &n.type_annotation),
+            &node.params,
+          );
+
+          callables.push(Ty::Constructor(self.allocator.alloc(CallableType {
+            is_method: false,
+            scope: self.type_scopes.top(),
+            type_params,
+            this_param: None,
+            params,
+            rest_param,
+            return_type,
+            predicate,
           })))
         }
-        TSSignature::TSConstructSignatureDeclaration(node) => todo!(),
         TSSignature::TSMethodSignature(node) => {
           let type_params = node
             .type_parameters
@@ -79,6 +107,10 @@ impl<'a> Analyzer<'a> {
             node.this_param.as_ref().map(|n| self.ctx_ty_from_annotation(&n.type_annotation, None));
           let (_, params, rest_param) = self.resolve_formal_parameters(&node.params);
           let return_type = self.ctx_ty_from_annotation(&node.return_type, None);
+          let predicate = self.get_type_predicate(
+            node.return_type.as_ref().map(|n| &n.type_annotation),
+            &node.params,
+          );
 
           let function = Ty::Function(self.allocator.alloc(CallableType {
             is_method: true,
@@ -88,9 +120,10 @@ impl<'a> Analyzer<'a> {
             params,
             rest_param,
             return_type,
+            predicate,
           }));
 
-          let key = self.exec_property_key(&node.key);
+          let key = self.resolve_property_key(&node.key);
 
           if matches!(
             key,
@@ -0,0 +1,18 @@
+use oxc::ast::ast::TSTemplateLiteralType;
+
+use crate::{ty::Ty, Analyzer};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_template_literal_type(&mut self, node: &'a TSTemplateLiteralType<'a>) -> Ty<'a> {
+    let quasis = node
+      .quasis
+      .iter()
+      .map(|quasi| quasi.value.cooked.as_ref().map_or("", |cooked| cooked.as_str()))
+      .collect::<Vec<_>>();
+    let mut types = vec![];
+    for node in &node.types {
+      types.push(self.resolve_type(node));
+    }
+    self.create_template_literal_type(&quasis, &types)
+  }
+}
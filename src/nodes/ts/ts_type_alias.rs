@@ -1,28 +1,60 @@
-use oxc::ast::ast::TSTypeAliasDeclaration;
+use std::mem;
+
+use oxc::ast::ast::{TSType, TSTypeAliasDeclaration, TSTypeName};
 
 use crate::{
-  ty::{generic::GenericType, Ty},
+  ty::{
+    generic::{GenericBody, GenericType},
+    intrinsics::IntrinsicType,
+    Ty,
+  },
   Analyzer,
 };
 
 impl<'a> Analyzer<'a> {
   pub fn declare_ts_type_alias(&mut self, node: &'a TSTypeAliasDeclaration<'a>) {
     let symbol_id = node.id.symbol_id();
-    let ty = if let Some(type_parameters) = &node.type_parameters {
+    let ty = if is_intrinsic(&node.type_annotation) {
+      IntrinsicType::from_name(&node.id.name)
+        .map_or(Ty::Error, |intrinsic| Ty::Intrinsic(self.allocator.alloc(intrinsic)))
+    } else if let Some(type_parameters) = &node.type_parameters {
       let params = self.resolve_type_parameter_declaration(type_parameters);
       Ty::Generic(self.allocator.alloc(GenericType {
         name: &node.id.name,
         params,
-        body: self.ctx_ty_from_ts_type(&node.type_annotation),
+        body: GenericBody::Type(self.ctx_ty_from_ts_type(&node.type_annotation)),
       }))
     } else {
-      self.resolve_type(&node.type_annotation)
+      // Resolved again when initialized, which reports the diagnostics
+      let diagnostics = mem::take(&mut self.diagnostics);
+      let ty = self.resolve_type(&node.type_annotation);
+      self.diagnostics = diagnostics;
+      ty
     };
     self.type_scopes.insert_on_top(symbol_id, ty);
+  }
+
+  pub fn init_ts_type_alias(&mut self, node: &'a TSTypeAliasDeclaration<'a>) {
+    // Types like `keyof User` and `User["name"]` are empty before the interfaces are initialized
+    let symbol_id = node.id.symbol_id();
+    let mut ty = self.type_scopes.get_on_top(symbol_id).unwrap();
+    if node.type_parameters.is_none() && !is_intrinsic(&node.type_annotation) {
+      ty = self.resolve_type(&node.type_annotation);
+      self.type_scopes.insert_on_top(symbol_id, ty);
+    }
     self.accumulate_type(&node.id, ty);
   }
+}
 
-  pub fn init_ts_type_alias(&mut self, _node: &'a TSTypeAliasDeclaration<'a>) {
-    // Do nothing
+/// Whether the type alias is `type Uppercase<S extends string> = intrinsic`. The parser produces a
+/// type reference named `intrinsic` outside declaration files.
+fn is_intrinsic(node: &TSType) -> bool {
+  match node {
+    TSType::TSIntrinsicKeyword(_) => true,
+    TSType::TSTypeReference(node) => {
+      node.type_parameters.is_none()
+        && matches!(&node.type_name, TSTypeName::IdentifierReference(id) if id.name == "intrinsic")
+    }
+    _ => false,
   }
 }
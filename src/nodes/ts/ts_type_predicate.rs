@@ -0,0 +1,44 @@
+use oxc::ast::ast::{
+  BindingPatternKind, FormalParameters, TSType, TSTypePredicate, TSTypePredicateName,
+};
+
+use crate::{
+  ty::{callable::TypePredicate, Ty},
+  Analyzer,
+};
+
+impl<'a> Analyzer<'a> {
+  pub fn resolve_type_predicate(&mut self, node: &'a TSTypePredicate<'a>) -> Ty<'a> {
+    // A type guard returns a boolean, and an assertion function returns nothing
+    if node.asserts {
+      Ty::Void
+    } else {
+      Ty::Boolean
+    }
+  }
+
+  /// The type predicate of a callable, if its return type is annotated as one.
+  pub fn get_type_predicate(
+    &self,
+    return_type: Option<&'a TSType<'a>>,
+    params: &'a FormalParameters<'a>,
+  ) -> Option<TypePredicate<'a>> {
+    let Some(TSType::TSTypePredicate(node)) = return_type else {
+      return None;
+    };
+    let param = match &node.parameter_name {
+      // The parser produces an identifier for `this` in a type annotation
+      TSTypePredicateName::Identifier(name) if name.name != "this" => {
+        Some(params.items.iter().position(|param| {
+          matches!(&param.pattern.kind, BindingPatternKind::BindingIdentifier(id) if id.name == name.name)
+        })?)
+      }
+      _ => None,
+    };
+    Some(TypePredicate {
+      param,
+      asserts: node.asserts,
+      ty: node.type_annotation.as_ref().map(|n| self.ctx_ty_from_ts_type(&n.type_annotation)),
+    })
+  }
+}
@@ -1,12 +1,14 @@
-use oxc::ast::ast::{IdentifierReference, TSTypeName, TSTypeReference};
+use oxc::ast::ast::{IdentifierReference, TSQualifiedName, TSTypeName, TSTypeReference};
 
 use crate::{analyzer::Analyzer, ty::Ty};
 
 impl<'a> Analyzer<'a> {
   pub fn resolve_type_reference(&mut self, node: &'a TSTypeReference<'a>) -> Ty<'a> {
     let base = match &node.type_name {
+      // The parser reads the `true` literal type as a type reference
+      TSTypeName::IdentifierReference(node) if node.name == "true" => Ty::BooleanLiteral(true),
       TSTypeName::IdentifierReference(node) => self.resolve_type_identifier_reference(node),
-      TSTypeName::QualifiedName(_node) => todo!(),
+      TSTypeName::QualifiedName(node) => self.resolve_type_qualified_name(node),
     };
 
     if let Some(type_parameters) = &node.type_parameters {
@@ -18,12 +20,44 @@ impl<'a> Analyzer<'a> {
   }
 
   pub fn resolve_type_identifier_reference(&mut self, node: &'a IdentifierReference<'a>) -> Ty<'a> {
-    let reference = self.semantic.symbols().get_reference(node.reference_id());
-    if let Some(symbol_id) = reference.symbol_id() {
+    // Serialized types have no reference ID, and are resolved as globals
+    let symbol_id = node
+      .reference_id
+      .get()
+      .and_then(|reference_id| self.semantic.symbols().get_reference(reference_id).symbol_id());
+    if let Some(symbol_id) = symbol_id {
       self.type_scopes.search(symbol_id)
     } else {
-      // TODO: Global type
-      Ty::Unknown
+      self.resolve_global_type(&node.name)
+    }
+  }
+
+  pub fn resolve_type_qualified_name(&mut self, node: &'a TSQualifiedName<'a>) -> Ty<'a> {
+    let left = match &node.left {
+      TSTypeName::IdentifierReference(node) => {
+        let symbol_id = node
+          .reference_id
+          .get()
+          .and_then(|reference_id| self.semantic.symbols().get_reference(reference_id).symbol_id());
+        match symbol_id {
+          // The type of an enum is the union of its members, whose types are in the namespace
+          Some(symbol_id) if self.semantic.symbols().get_flags(symbol_id).is_enum() => {
+            self.read_variable(symbol_id)
+          }
+          _ => self.resolve_type_identifier_reference(node),
+        }
+      }
+      TSTypeName::QualifiedName(node) => self.resolve_type_qualified_name(node),
+    };
+    match left {
+      Ty::Namespace(namespace) => {
+        namespace.types.get(node.right.name.as_str()).copied().unwrap_or_else(|| {
+          self.add_diagnostic(format!("Namespace has no exported type '{}'", node.right.name));
+          Ty::Error
+        })
+      }
+      Ty::Unknown => Ty::Unknown,
+      _ => Ty::Error,
     }
   }
 }
@@ -0,0 +1,202 @@
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{self, ModuleDeclaration, Statement},
+  parser::Parser,
+  semantic::{Semantic, SemanticBuilder},
+  span::SourceType,
+};
+use oxc_index::{define_index_type, IndexVec};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+  analyzer::{Analyzer, FileContext},
+  config::Config,
+  ty::namespace::NamespaceType,
+};
+
+define_index_type! {
+  pub struct ModuleId = u32;
+}
+
+pub struct ModuleInfo<'a> {
+  pub path: &'a str,
+  pub program: &'a ast::Program<'a>,
+  /// The resolved dependencies, keyed by the import specifiers
+  pub dependencies: FxHashMap<&'a str, ModuleId>,
+  /// The exports namespace. `None` before the module is analyzed.
+  pub exports: Option<&'a NamespaceType<'a>>,
+  /// `None` when this is the current module of the analyzer.
+  pub context: Option<FileContext<'a>>,
+}
+
+pub trait ModuleResolver {
+  /// Returns the path of the module imported by `specifier` from the module at `importer`.
+  fn resolve(&self, importer: &str, specifier: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str, &str) -> Option<String>> ModuleResolver for F {
+  fn resolve(&self, importer: &str, specifier: &str) -> Option<String> {
+    self(importer, specifier)
+  }
+}
+
+/// Analyze a set of files as a program. `files` are `(path, source)` pairs, and the paths returned
+/// by `resolver` are looked up in them.
+pub fn analyze_program<'a>(
+  allocator: &'a Allocator,
+  files: impl IntoIterator<Item = (String, String)>,
+  resolver: &impl ModuleResolver,
+  config: Config,
+) -> Analyzer<'a> {
+  let mut parsed = vec![];
+  for (path, source) in files {
+    let path = allocator.alloc_str(&path);
+    let source = allocator.alloc_str(&source);
+    let mut source_type = SourceType::from_path(&*path).unwrap_or_else(|_| SourceType::tsx());
+    if source_type.is_typescript_definition() {
+      // The semantic builder skips declaration files. Like the bundled libs, they are analyzed as
+      // regular TypeScript.
+      source_type = SourceType::ts();
+    }
+    let program = allocator.alloc(Parser::new(allocator, source, source_type).parse().program);
+    let semantic: &Semantic = allocator.alloc(SemanticBuilder::new().build(program).semantic);
+    parsed.push((path, program, semantic));
+  }
+  if parsed.is_empty() {
+    // An empty program has no modules, so there is nothing to analyze or query
+    let program = allocator.alloc(Parser::new(allocator, "", SourceType::ts()).parse().program);
+    let semantic = allocator.alloc(SemanticBuilder::new().build(program).semantic);
+    return Analyzer::new(allocator, config, semantic);
+  }
+
+  let mut analyzer = Analyzer::new(allocator, config, parsed[0].2);
+  for (index, (path, program, semantic)) in parsed.into_iter().enumerate() {
+    // The analyzer is created with the context of the first file
+    let context = (index != 0).then(|| analyzer.create_file_context(semantic));
+    analyzer.modules.push(ModuleInfo {
+      path,
+      program,
+      dependencies: Default::default(),
+      exports: None,
+      context,
+    });
+  }
+  analyzer.current_module = Some(ModuleId::new(0));
+
+  analyzer.resolve_dependencies(resolver);
+  let order = analyzer.get_modules_in_dependency_order();
+  let mut incomplete = FxHashSet::default();
+  for module in &order {
+    if analyzer.exec_module(*module) {
+      incomplete.insert(*module);
+    }
+  }
+  // A module in an import cycle is analyzed before some of its dependencies. It is analyzed again
+  // with their exports, and so are the modules depending on it.
+  if !incomplete.is_empty() {
+    for module in order {
+      let dependencies = &analyzer.modules[module].dependencies;
+      if incomplete.contains(&module) || dependencies.values().any(|dep| incomplete.contains(dep)) {
+        analyzer.exec_module(module);
+        incomplete.insert(module);
+      }
+    }
+  }
+
+  analyzer
+}
+
+impl<'a> Analyzer<'a> {
+  pub fn get_module_by_path(&self, path: &str) -> Option<ModuleId> {
+    self.modules.iter_enumerated().find(|(_, module)| module.path == path).map(|(id, _)| id)
+  }
+
+  /// Make `module` the current module, so that its analysis results can be queried.
+  pub fn select_module(&mut self, module: ModuleId) {
+    let current = self.current_module.unwrap();
+    if current != module {
+      let context = self.modules[module].context.take().unwrap();
+      let previous = self.swap_file_context(context);
+      self.modules[current].context = Some(previous);
+      self.current_module = Some(module);
+    }
+  }
+
+  fn resolve_dependencies(&mut self, resolver: &impl ModuleResolver) {
+    let paths: FxHashMap<&'a str, ModuleId> =
+      self.modules.iter_enumerated().map(|(id, module)| (module.path, id)).collect();
+    for module in self.modules.iter_mut() {
+      for statement in &module.program.body {
+        let Some(specifier) = get_import_specifier(statement) else {
+          continue;
+        };
+        if let Some(dependency) =
+          resolver.resolve(module.path, specifier).and_then(|path| paths.get(path.as_str()))
+        {
+          module.dependencies.insert(specifier, *dependency);
+        }
+      }
+    }
+  }
+
+  /// Dependencies come before their dependents. Circular imports are broken arbitrarily.
+  fn get_modules_in_dependency_order(&self) -> Vec<ModuleId> {
+    fn visit(
+      modules: &IndexVec<ModuleId, ModuleInfo>,
+      module: ModuleId,
+      visited: &mut FxHashSet<ModuleId>,
+      order: &mut Vec<ModuleId>,
+    ) {
+      if visited.insert(module) {
+        for dependency in modules[module].dependencies.values() {
+          visit(modules, *dependency, visited, order);
+        }
+        order.push(module);
+      }
+    }
+
+    let mut visited = FxHashSet::default();
+    let mut order = vec![];
+    for module in self.modules.indices() {
+      visit(&self.modules, module, &mut visited, &mut order);
+    }
+    order
+  }
+
+  /// Returns whether some dependencies of `module` are not analyzed yet.
+  fn exec_module(&mut self, module: ModuleId) -> bool {
+    self.select_module(module);
+    if self.modules[module].exports.is_some() {
+      // Analyzed again, from scratch
+      let context = self.create_file_context(self.semantic);
+      self.swap_file_context(context);
+    }
+    self.reset_runtime_states();
+    let incomplete = self.modules[module]
+      .dependencies
+      .values()
+      .any(|dependency| self.modules[*dependency].exports.is_none());
+
+    let program = self.modules[module].program;
+    self.exec_program(program);
+
+    let mut exports = NamespaceType::default();
+    for statement in &program.body {
+      if let Some(node) = statement.as_module_declaration() {
+        self.export_module_declaration(node, &mut exports);
+      }
+    }
+    self.modules[module].exports = Some(self.allocator.alloc(exports));
+    incomplete
+  }
+}
+
+fn get_import_specifier<'a>(node: &'a Statement<'a>) -> Option<&'a str> {
+  let source = match node.as_module_declaration()? {
+    ModuleDeclaration::ImportDeclaration(node) => &node.source,
+    ModuleDeclaration::ExportAllDeclaration(node) => &node.source,
+    ModuleDeclaration::ExportNamedDeclaration(node) => node.source.as_ref()?,
+    _ => return None,
+  };
+  Some(source.value.as_str())
+}
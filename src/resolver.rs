@@ -0,0 +1,240 @@
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::{
+  config::Config,
+  program::ModuleResolver,
+  utils::{normalize_path, parse_jsonc},
+};
+
+/// Extensions probed for extensionless specifiers, in order.
+const EXTENSIONS: [&str; 7] = [".ts", ".tsx", ".d.ts", ".mts", ".cts", ".d.mts", ".d.cts"];
+
+/// The `exports` conditions matched for type resolution.
+const CONDITIONS: [&str; 4] = ["types", "import", "node", "default"];
+
+/// Resolves modules like `tsc` does with `moduleResolution: bundler`.
+#[derive(Debug, Clone, Default)]
+pub struct NodeModuleResolver {
+  /// The directory of non-relative specifiers, from `baseUrl`.
+  pub base_url: Option<PathBuf>,
+  /// The directory of the substitutions in `paths`.
+  pub paths_base: Option<PathBuf>,
+  /// The `paths` patterns and their substitutions, in declaration order.
+  pub paths: Vec<(String, Vec<String>)>,
+}
+
+impl NodeModuleResolver {
+  /// Resolve with `baseUrl` and `paths` of the config.
+  pub fn from_config(config: &Config) -> Self {
+    Self {
+      base_url: config.base_url.clone(),
+      paths_base: config.paths_base.clone(),
+      paths: config.paths.clone(),
+    }
+  }
+
+  /// A non-relative specifier is looked up in `paths`, then in `baseUrl`, then in `node_modules`.
+  /// `baseUrl` is only tried when it is set, and not for the directory of `paths`.
+  pub fn resolve_path(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+    if is_relative(specifier) {
+      let dir = importer.parent()?;
+      return self.resolve_file_or_directory(&dir.join(specifier));
+    }
+
+    if let Some(resolved) = self.resolve_paths_mapping(specifier) {
+      return Some(resolved);
+    }
+    if let Some(base_url) = &self.base_url {
+      if let Some(resolved) = self.resolve_file_or_directory(&base_url.join(specifier)) {
+        return Some(resolved);
+      }
+    }
+    self.resolve_node_modules(importer, specifier)
+  }
+
+  fn resolve_paths_mapping(&self, specifier: &str) -> Option<PathBuf> {
+    let paths_base = self.paths_base.as_ref().or(self.base_url.as_ref())?;
+    // Exact patterns are preferred, then the ones with the longest prefix
+    let (_, captured, substitutions) = self
+      .paths
+      .iter()
+      .filter_map(|(pattern, substitutions)| {
+        let captured = match_star_pattern(pattern, specifier)?;
+        let prefix = pattern.find('*').unwrap_or(usize::MAX);
+        Some((prefix, captured, substitutions))
+      })
+      .max_by_key(|(prefix, _, _)| *prefix)?;
+    substitutions.iter().find_map(|substitution| {
+      let path = paths_base.join(substitution.replacen('*', captured, 1));
+      self.resolve_file_or_directory(&path)
+    })
+  }
+
+  fn resolve_node_modules(&self, importer: &Path, specifier: &str) -> Option<PathBuf> {
+    let (name, subpath) = split_package_specifier(specifier);
+    // `@scope/name` is typed by `@types/scope__name`
+    let types_name = format!("@types/{}", name.trim_start_matches('@').replace('/', "__"));
+    for dir in importer.ancestors().skip(1) {
+      let node_modules = dir.join("node_modules");
+      if !node_modules.is_dir() {
+        continue;
+      }
+      for name in [name, types_name.as_str()] {
+        let package = node_modules.join(name);
+        if package.is_dir() {
+          if let Some(resolved) = self.resolve_package(&package, subpath) {
+            return Some(resolved);
+          }
+        }
+      }
+    }
+    None
+  }
+
+  fn resolve_package(&self, package: &Path, subpath: &str) -> Option<PathBuf> {
+    let manifest = read_package_json(package);
+    if let Some(exports) = manifest.as_ref().map(|manifest| &manifest["exports"]) {
+      if !exports.is_null() {
+        // `exports` is a whitelist, other paths are not exposed
+        let target = resolve_exports(exports, &format!(".{subpath}"))?;
+        return self.resolve_file(&package.join(target));
+      }
+    }
+    if subpath.is_empty() {
+      self.resolve_directory(package)
+    } else {
+      self.resolve_file_or_directory(&package.join(&subpath[1..]))
+    }
+  }
+
+  fn resolve_file_or_directory(&self, path: &Path) -> Option<PathBuf> {
+    self.resolve_file(path).or_else(|| self.resolve_directory(path))
+  }
+
+  fn resolve_file(&self, path: &Path) -> Option<PathBuf> {
+    let path = normalize_path(path);
+    let path_str = path.to_str()?;
+    // `./a.js` is resolved to its source `./a.ts`, or its declaration `./a.d.ts`
+    for (js, ts, dts) in [
+      (".js", ".ts", ".d.ts"),
+      (".jsx", ".tsx", ".d.ts"),
+      (".mjs", ".mts", ".d.mts"),
+      (".cjs", ".cts", ".d.cts"),
+    ] {
+      if let Some(stem) = path_str.strip_suffix(js) {
+        for ext in [ts, dts] {
+          let candidate = PathBuf::from(format!("{stem}{ext}"));
+          if candidate.is_file() {
+            return Some(candidate);
+          }
+        }
+      }
+    }
+    if is_typescript_file(path_str) && path.is_file() {
+      return Some(path);
+    }
+    EXTENSIONS.iter().map(|ext| PathBuf::from(format!("{path_str}{ext}"))).find(|p| p.is_file())
+  }
+
+  fn resolve_directory(&self, path: &Path) -> Option<PathBuf> {
+    if !path.is_dir() {
+      return None;
+    }
+    if let Some(manifest) = read_package_json(path) {
+      for field in ["types", "typings", "main"] {
+        if let Some(entry) = manifest[field].as_str() {
+          if let Some(resolved) = self.resolve_file_or_directory(&path.join(entry)) {
+            return Some(resolved);
+          }
+        }
+      }
+    }
+    self.resolve_file(&path.join("index"))
+  }
+}
+
+impl ModuleResolver for NodeModuleResolver {
+  fn resolve(&self, importer: &str, specifier: &str) -> Option<String> {
+    let resolved = self.resolve_path(Path::new(importer), specifier)?;
+    resolved.to_str().map(str::to_string)
+  }
+}
+
+fn is_relative(specifier: &str) -> bool {
+  specifier == "."
+    || specifier == ".."
+    || specifier.starts_with("./")
+    || specifier.starts_with("../")
+    || specifier.starts_with('/')
+}
+
+fn is_typescript_file(path: &str) -> bool {
+  [".ts", ".tsx", ".mts", ".cts"].iter().any(|ext| path.ends_with(ext))
+}
+
+/// Split `@scope/name/sub/path` into `@scope/name` and `/sub/path`.
+fn split_package_specifier(specifier: &str) -> (&str, &str) {
+  let name_segments = if specifier.starts_with('@') { 2 } else { 1 };
+  let end = specifier.match_indices('/').nth(name_segments - 1).map_or(specifier.len(), |(i, _)| i);
+  specifier.split_at(end)
+}
+
+/// Returns the part matched by `*` in `pattern`, or the empty string for exact matches.
+fn match_star_pattern<'s>(pattern: &str, s: &'s str) -> Option<&'s str> {
+  match pattern.split_once('*') {
+    Some((prefix, suffix)) => {
+      let rest = s.strip_prefix(prefix)?.strip_suffix(suffix)?;
+      Some(rest)
+    }
+    None => (pattern == s).then_some(""),
+  }
+}
+
+fn read_package_json(dir: &Path) -> Option<Value> {
+  let source = fs::read_to_string(dir.join("package.json")).ok()?;
+  parse_jsonc(&source).ok()
+}
+
+/// Resolve `subpath` (like `.` or `./sub`) against the `exports` field of a package.
+fn resolve_exports(exports: &Value, subpath: &str) -> Option<String> {
+  let is_subpath_map = exports
+    .as_object()
+    .is_some_and(|map| map.keys().next().is_some_and(|key| key.starts_with('.')));
+  if !is_subpath_map {
+    // Shorthand for `{ ".": exports }`
+    return if subpath == "." { resolve_exports_target(exports, "") } else { None };
+  }
+
+  let map = exports.as_object()?;
+  if let Some(target) = map.get(subpath) {
+    return resolve_exports_target(target, "");
+  }
+  // The pattern with the longest prefix wins
+  let (_, captured, target) = map
+    .iter()
+    .filter_map(|(pattern, target)| {
+      let captured = match_star_pattern(pattern, subpath).filter(|_| pattern.contains('*'))?;
+      Some((pattern.find('*')?, captured, target))
+    })
+    .max_by_key(|(prefix, _, _)| *prefix)?;
+  resolve_exports_target(target, captured)
+}
+
+fn resolve_exports_target(target: &Value, captured: &str) -> Option<String> {
+  match target {
+    Value::String(target) => Some(target.replace('*', captured)),
+    Value::Array(targets) => {
+      targets.iter().find_map(|target| resolve_exports_target(target, captured))
+    }
+    Value::Object(conditions) => conditions
+      .iter()
+      .filter(|(condition, _)| CONDITIONS.contains(&condition.as_str()))
+      .find_map(|(_, target)| resolve_exports_target(target, captured)),
+    _ => None,
+  }
+}
@@ -15,6 +15,10 @@ pub struct CallScope<'a> {
 
   pub this: Ty<'a>,
   pub ret: CallScopeReturnType<'a>,
+  /// The contextual type of the returned values, when the return type is not annotated
+  pub ret_sat: Option<Ty<'a>>,
+  /// The yielded types of a generator without a return type annotation
+  pub yielded: TypeAccumulator<'a>,
 
   #[cfg(feature = "flame")]
   pub scope_guard: flame::SpanGuard,
@@ -40,6 +44,8 @@ impl<'a> CallScope<'a> {
       } else {
         CallScopeReturnType::Inferred(Default::default())
       },
+      ret_sat: None,
+      yielded: Default::default(),
 
       #[cfg(feature = "flame")]
       scope_guard: flame::start_guard(callee.debug_name.to_string()),
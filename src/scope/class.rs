@@ -0,0 +1,10 @@
+use crate::ty::Ty;
+
+/// The class whose members are being executed, which `super` refers to.
+pub struct ClassScope<'a> {
+  /// The base class. `super()` calls its constructor, and `super` in static members refers to it.
+  pub super_class: Option<Ty<'a>>,
+  /// The instance type of the base class, which `super` in instance members refers to.
+  pub super_instance: Option<Ty<'a>>,
+  pub is_static: bool,
+}
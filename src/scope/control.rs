@@ -9,6 +9,8 @@ pub enum CfScopeKind<'a> {
   Module,
   Labeled(&'a LabeledStatement<'a>),
   Function,
+  /// A `try` block with a `catch` clause, which catches the `throw` statements in it
+  Try,
   Loop,
   Switch,
 
@@ -21,6 +23,10 @@ impl<'a> CfScopeKind<'a> {
     matches!(self, CfScopeKind::Function)
   }
 
+  pub fn catches_throw(self) -> bool {
+    matches!(self, CfScopeKind::Module | CfScopeKind::Function | CfScopeKind::Try)
+  }
+
   pub fn is_breakable_without_label(self) -> bool {
     matches!(self, CfScopeKind::Loop | CfScopeKind::Switch)
   }
@@ -99,16 +105,31 @@ impl<'a> Analyzer<'a> {
         break;
       }
     }
-    self.exit_to(target_depth.unwrap());
+    self.exit_to(target_depth.unwrap() + 1);
     label_used
   }
 
   pub fn exit_to(&mut self, target_depth: usize) {
-    self.exit_to_impl(target_depth, self.runtime_scopes.stack.len(), true);
+    self.exit_to_impl(self.runtime_scopes.stack.len(), target_depth, true);
+  }
+
+  /// Exit the scopes in the current function and the function scope itself, like `return`.
+  pub fn exit_function(&mut self) {
+    let depth =
+      self.runtime_scopes.iter_stack().rposition(|scope| scope.kind.is_function()).unwrap();
+    self.exit_to(depth);
+  }
+
+  /// Exit the scopes up to the closest one catching the thrown value, and that scope itself, like
+  /// `throw`.
+  pub fn exit_throw(&mut self) {
+    let depth =
+      self.runtime_scopes.iter_stack().rposition(|scope| scope.kind.catches_throw()).unwrap();
+    self.exit_to(depth);
   }
 
   pub fn exit_to_not_must(&mut self, target_depth: usize) {
-    self.exit_to_impl(target_depth, self.runtime_scopes.stack.len(), false);
+    self.exit_to_impl(self.runtime_scopes.stack.len(), target_depth, false);
   }
 
   /// If the label is used, `true` is returned.
@@ -137,7 +158,7 @@ impl<'a> Analyzer<'a> {
         break;
       }
     }
-    self.exit_to(target_depth.unwrap());
+    self.exit_to(target_depth.unwrap() + 1);
     label_used
   }
 
@@ -148,17 +169,21 @@ impl<'a> Analyzer<'a> {
   ) {
     let blocked_1 = self.runtime_scopes.get(scope_1).kind.get_blocked_exit();
     let blocked_2 = self.runtime_scopes.get(scope_2).kind.get_blocked_exit();
-    match (blocked_1, blocked_2) {
-      (Some(blocked_1), Some(blocked_2)) => {
-        let inner = blocked_1.max(blocked_2);
-        let outer = blocked_1.min(blocked_2);
-        self.exit_to_impl(self.runtime_scopes.stack.len(), inner, true);
-        self.exit_to_impl(inner, outer, false);
-      }
-      (Some(blocked), None) | (None, Some(blocked)) => {
-        self.exit_to_impl(self.runtime_scopes.stack.len(), blocked, false);
-      }
-      (None, None) => {}
+    self.apply_blocked_exits(&[blocked_1, blocked_2]);
+  }
+
+  /// Apply the exits blocked by the branches of a statement, where `None` is a branch which does
+  /// not exit. The scopes are exited only if every branch exits them.
+  pub fn apply_blocked_exits(&mut self, blocked: &[Option<usize>]) {
+    let Some(outer) = blocked.iter().flatten().copied().min() else {
+      return;
+    };
+    if blocked.iter().all(Option::is_some) {
+      let inner = blocked.iter().flatten().copied().max().unwrap();
+      self.exit_to_impl(self.runtime_scopes.stack.len(), inner, true);
+      self.exit_to_impl(inner, outer, false);
+    } else {
+      self.exit_to_impl(self.runtime_scopes.stack.len(), outer, false);
     }
   }
 }
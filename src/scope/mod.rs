@@ -1,5 +1,7 @@
 pub mod call;
+pub mod class;
 pub mod control;
+pub mod narrow;
 pub mod runtime;
 pub mod r#type;
 pub mod variable;
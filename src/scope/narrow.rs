@@ -0,0 +1,467 @@
+use std::cell::RefCell;
+
+use oxc::{
+  ast::ast::{
+    BinaryOperator, CallExpression, Expression, LogicalOperator, MemberExpression, UnaryOperator,
+  },
+  semantic::SymbolId,
+  span::{Atom, GetSpan},
+};
+use rustc_hash::FxHashMap;
+
+use crate::{
+  analyzer::Analyzer,
+  ty::{
+    callable::{ExtractedCallable, TypePredicate},
+    facts::Facts,
+    interface::{InterfaceType, InterfaceTypeInner},
+    property_key::PropertyKeyType,
+    record::RecordPropertyValue,
+    unresolved::UnresolvedType,
+    Ty,
+  },
+};
+
+/// Narrows the type of a reference.
+type Narrow<'a, 'b> = &'b mut dyn FnMut(&mut Analyzer<'a>, Ty<'a>) -> Ty<'a>;
+
+impl<'a> Analyzer<'a> {
+  /// Narrow the references tested in `node`, assuming that it evaluates to `truthy`. The narrowed
+  /// types are written as shadows in the current scope, so they are merged back when it is popped.
+  pub fn narrow_condition(&mut self, node: &'a Expression<'a>, truthy: bool) {
+    match node {
+      Expression::ParenthesizedExpression(node) => self.narrow_condition(&node.expression, truthy),
+      Expression::UnaryExpression(node) if node.operator == UnaryOperator::LogicalNot => {
+        self.narrow_condition(&node.argument, !truthy)
+      }
+      Expression::LogicalExpression(node) => match (node.operator, truthy) {
+        // Both sides are known only when `a && b` is truthy or `a || b` is falsy
+        (LogicalOperator::And, true) | (LogicalOperator::Or, false) => {
+          self.narrow_condition(&node.left, truthy);
+          self.narrow_condition(&node.right, truthy);
+        }
+        _ => {}
+      },
+      Expression::BinaryExpression(node) if node.operator == BinaryOperator::In => {
+        if let Some(key) = self.get_literal_type(&node.left) {
+          let key = self.to_property_key(key);
+          self.narrow_reference(&node.right, &mut |analyzer, ty| {
+            analyzer.narrow_by_in(ty, key, truthy)
+          });
+        }
+      }
+      Expression::BinaryExpression(node) if node.operator == BinaryOperator::Instanceof => {
+        let Some(class) = self.get_type_by_span(node.right.span()) else {
+          return;
+        };
+        let instance = self.get_constructed_type(class, None);
+        if !matches!(instance, Ty::Error | Ty::Any) {
+          self.narrow_reference(&node.left, &mut |analyzer, ty| {
+            analyzer.narrow_by_type(ty, instance, truthy)
+          });
+        }
+      }
+      Expression::BinaryExpression(node) => {
+        let (truthy, strict) = match node.operator {
+          BinaryOperator::Equality => (truthy, false),
+          BinaryOperator::StrictEquality => (truthy, true),
+          BinaryOperator::Inequality => (!truthy, false),
+          BinaryOperator::StrictInequality => (!truthy, true),
+          _ => return,
+        };
+        for (left, right) in [(&node.left, &node.right), (&node.right, &node.left)] {
+          if let (Some(target), Some(tag)) = (get_typeof_argument(left), get_string_literal(right))
+          {
+            self.narrow_reference(target, &mut |analyzer, ty| {
+              analyzer.narrow_by_typeof(ty, tag, truthy)
+            });
+          } else if let Some(nullish) = self.get_nullish_literal(right) {
+            let excluded = match (strict, nullish, truthy) {
+              (false, _, true) => Facts::NOT_NULLISH,
+              (false, _, false) => Facts::IS_NULLISH,
+              (true, Ty::Null, true) => Facts::NE_NULL,
+              (true, Ty::Null, false) => Facts::EQ_NULL,
+              (true, _, true) => Facts::NE_UNDEFINED,
+              (true, _, false) => Facts::EQ_UNDEFINED,
+            };
+            self.narrow_reference(left, &mut |analyzer, ty| analyzer.filter_by_facts(ty, excluded));
+          } else if let Some(value) = self.get_literal_type(right) {
+            // Like `x.kind === "circle"`, which also narrows `x` to the members of that kind
+            self.narrow_reference(left, &mut |analyzer, ty| {
+              analyzer.narrow_by_equality(ty, value, truthy)
+            });
+          }
+        }
+      }
+      Expression::CallExpression(node) => {
+        if let Some((predicate, ty)) = self.get_call_predicate(node) {
+          if !predicate.asserts {
+            self.narrow_by_predicate(node, predicate, ty, truthy);
+          }
+        }
+      }
+      _ => {
+        self.narrow_reference(node, &mut |analyzer, ty| analyzer.narrow_by_truthy(ty, truthy));
+      }
+    }
+  }
+
+  /// Narrow the argument of a call to an assertion function, like `assertIsFoo(x)`. The narrowed
+  /// types hold for everything after the call in the current scope.
+  pub fn narrow_assertion_call(&mut self, node: &'a CallExpression<'a>) {
+    if let Some((predicate, ty)) = self.get_call_predicate(node) {
+      if predicate.asserts {
+        self.narrow_by_predicate(node, predicate, ty, true);
+      }
+    }
+  }
+
+  /// The type predicate of the callee, with its type resolved. The callee may be a union of
+  /// callables with the same predicate, like a method called on a union.
+  fn get_call_predicate(
+    &mut self,
+    node: &'a CallExpression<'a>,
+  ) -> Option<(TypePredicate<'a>, Option<Ty<'a>>)> {
+    let callee = self.get_type_by_span(node.callee.span())?;
+    let callables = match self.extract_callable_function(callee)? {
+      ExtractedCallable::Single(callable) => vec![callable],
+      ExtractedCallable::Union(callables) => callables
+        .into_iter()
+        .map(|callable| match callable {
+          ExtractedCallable::Single(callable) => Some(callable),
+          _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?,
+      _ => return None,
+    };
+
+    let predicate = callables[0].predicate?;
+    let mut types = vec![];
+    for callable in callables {
+      let other = callable.predicate?;
+      if other.param != predicate.param
+        || other.asserts != predicate.asserts
+        || other.ty.is_some() != predicate.ty.is_some()
+      {
+        return None;
+      }
+      if let Some(ty) = other.ty {
+        let scope = match &node.type_parameters {
+          Some(type_args) if !callable.type_params.is_empty() => {
+            let type_args = self.resolve_type_parameter_instantiation(type_args);
+            self.instantiate_generic_params(&callable.type_params, &type_args)
+          }
+          _ => self.type_scopes.empty_scope,
+        };
+        types.push(self.resolve_ctx_ty(scope, ty));
+      }
+    }
+    Some((predicate, self.into_union(types)))
+  }
+
+  /// Narrow the argument of a call with the type predicate of the callee being `truthy`.
+  fn narrow_by_predicate(
+    &mut self,
+    node: &'a CallExpression<'a>,
+    predicate: TypePredicate<'a>,
+    ty: Option<Ty<'a>>,
+    truthy: bool,
+  ) {
+    let target = match predicate.param {
+      Some(index) => match node.arguments.get(index) {
+        Some(argument) if !argument.is_spread() => argument.to_expression(),
+        _ => return,
+      },
+      // `this is T` narrows the object of the method call
+      None => match node.callee.without_parentheses().as_member_expression() {
+        Some(member) => member.object(),
+        None => return,
+      },
+    };
+    match ty {
+      Some(ty) => {
+        self.narrow_reference(target, &mut |analyzer, t| analyzer.narrow_by_type(t, ty, truthy));
+      }
+      // `asserts x` asserts the argument like a condition
+      None => self.narrow_condition(target, truthy),
+    }
+  }
+
+  /// Narrow the reference `node`, assuming that it is `nullish`, like the left side of `??`.
+  pub fn narrow_condition_nullish(&mut self, node: &'a Expression<'a>, nullish: bool) {
+    self.narrow_reference(node, &mut |analyzer, ty| analyzer.narrow_by_nullish(ty, nullish));
+  }
+
+  /// Narrow the references in the discriminant of a `switch`, assuming that it equals one of
+  /// `tests` when `matched`, or none of them otherwise.
+  pub fn narrow_switch_case(
+    &mut self,
+    discriminant: &'a Expression<'a>,
+    tests: &[&'a Expression<'a>],
+    matched: bool,
+  ) {
+    if let Some(target) = get_typeof_argument(discriminant) {
+      let tags = tests.iter().filter_map(|test| get_string_literal(test)).collect::<Vec<_>>();
+      if matched {
+        if tags.len() != tests.len() {
+          // Matched by a non-literal test
+          return;
+        }
+        self.narrow_reference(target, &mut |analyzer, ty| {
+          let types =
+            tags.iter().map(|tag| analyzer.narrow_by_typeof(ty, tag, true)).collect::<Vec<_>>();
+          analyzer.into_union(types).unwrap_or(Ty::Never)
+        });
+      } else {
+        self.narrow_reference(target, &mut |analyzer, ty| {
+          tags.iter().fold(ty, |ty, tag| analyzer.narrow_by_typeof(ty, tag, false))
+        });
+      }
+    } else {
+      let values = tests.iter().filter_map(|test| self.get_literal_type(test)).collect::<Vec<_>>();
+      if matched {
+        if values.len() != tests.len() {
+          return;
+        }
+        self.narrow_reference(discriminant, &mut |analyzer, ty| {
+          let types = values
+            .iter()
+            .map(|value| analyzer.narrow_by_equality(ty, *value, true))
+            .collect::<Vec<_>>();
+          analyzer.into_union(types).unwrap_or(Ty::Never)
+        });
+      } else {
+        self.narrow_reference(discriminant, &mut |analyzer, ty| {
+          values.iter().fold(ty, |ty, value| analyzer.narrow_by_equality(ty, *value, false))
+        });
+      }
+    }
+  }
+
+  /// Whether the `tests` of a `switch` cover all the possible values of the discriminant, whose
+  /// type is `ty`.
+  pub fn is_switch_exhaustive(
+    &mut self,
+    discriminant: &'a Expression<'a>,
+    ty: Ty<'a>,
+    tests: &[&'a Expression<'a>],
+  ) -> bool {
+    let remaining = if let Some(target) = get_typeof_argument(discriminant) {
+      let Some(ty) = self.get_type_by_span(target.span()) else {
+        return false;
+      };
+      let tags = tests.iter().filter_map(|test| get_string_literal(test)).collect::<Vec<_>>();
+      tags.into_iter().fold(ty, |ty, tag| self.narrow_by_typeof(ty, tag, false))
+    } else {
+      let values = tests.iter().filter_map(|test| self.get_literal_type(test)).collect::<Vec<_>>();
+      values.into_iter().fold(ty, |ty, value| self.narrow_by_equality(ty, value, false))
+    };
+    remaining == Ty::Never
+  }
+
+  /// The unit type of a literal, or of an expression typed as a literal, like an enum member.
+  fn get_literal_type(&mut self, node: &'a Expression<'a>) -> Option<Ty<'a>> {
+    match node {
+      Expression::StringLiteral(node) => Some(Ty::StringLiteral(&node.value)),
+      Expression::NumericLiteral(node) => Some(Ty::NumericLiteral(node.value.into())),
+      Expression::BigIntLiteral(node) => Some(Ty::BigIntLiteral(&node.raw)),
+      Expression::BooleanLiteral(node) => Some(Ty::BooleanLiteral(node.value)),
+      Expression::ParenthesizedExpression(node) => self.get_literal_type(&node.expression),
+      _ => match self.get_type_by_span(node.span())? {
+        ty @ (Ty::StringLiteral(_)
+        | Ty::NumericLiteral(_)
+        | Ty::BigIntLiteral(_)
+        | Ty::BooleanLiteral(_)
+        | Ty::UniqueSymbol(_)) => Some(ty),
+        _ => None,
+      },
+    }
+  }
+
+  /// `null`, `undefined` or `void 0`.
+  fn get_nullish_literal(&self, node: &'a Expression<'a>) -> Option<Ty<'a>> {
+    match node {
+      Expression::NullLiteral(_) => Some(Ty::Null),
+      Expression::Identifier(node) if node.name == "undefined" => {
+        let reference = self.semantic.symbols().get_reference(node.reference_id());
+        reference.symbol_id().is_none().then_some(Ty::Undefined)
+      }
+      Expression::UnaryExpression(node) if node.operator == UnaryOperator::Void => {
+        Some(Ty::Undefined)
+      }
+      Expression::ParenthesizedExpression(node) => self.get_nullish_literal(&node.expression),
+      _ => None,
+    }
+  }
+
+  /// Narrow a reference like `x` or `x.a.b`. Properties are narrowed by replacing them in a copy of
+  /// the object type, which is written to the root variable.
+  fn narrow_reference(&mut self, node: &'a Expression<'a>, narrow: Narrow<'a, '_>) {
+    let mut keys = vec![];
+    let Some(symbol) = self.get_reference_path(node, &mut keys) else {
+      return;
+    };
+    let ty = self.read_variable(symbol);
+    let narrowed = self.narrow_property_path(ty, &keys, narrow);
+    self.shadow_variable(symbol, narrowed);
+  }
+
+  /// Reset the narrowed type of an assigned property like `x.a.b` to the declared one, like the
+  /// assignments to variables.
+  pub fn reset_narrowed_property(&mut self, node: &'a MemberExpression<'a>) {
+    let (object, key) = match node {
+      MemberExpression::StaticMemberExpression(node) if !node.optional => {
+        (&node.object, &node.property.name)
+      }
+      MemberExpression::ComputedMemberExpression(node) if !node.optional => {
+        let Expression::StringLiteral(key) = &node.expression else {
+          return;
+        };
+        (&node.object, &key.value)
+      }
+      _ => return,
+    };
+    let mut keys = vec![];
+    let Some(symbol) = self.get_reference_path(object, &mut keys) else {
+      return;
+    };
+    keys.push(key);
+
+    let mut declared = self.read_declared_variable(symbol);
+    for key in &keys {
+      declared = self.get_property(declared, PropertyKeyType::StringLiteral(key));
+    }
+    let ty = self.read_variable(symbol);
+    let reset = self.narrow_property_path(ty, &keys, &mut |_, _| declared);
+    if reset != ty {
+      self.shadow_variable(symbol, reset);
+    }
+  }
+
+  fn get_reference_path(
+    &self,
+    node: &'a Expression<'a>,
+    keys: &mut Vec<&'a Atom<'a>>,
+  ) -> Option<SymbolId> {
+    let symbol = match node {
+      Expression::Identifier(node) => {
+        let reference = self.semantic.symbols().get_reference(node.reference_id());
+        reference.symbol_id()?
+      }
+      Expression::ParenthesizedExpression(node) => {
+        self.get_reference_path(&node.expression, keys)?
+      }
+      Expression::StaticMemberExpression(node) if !node.optional => {
+        let symbol = self.get_reference_path(&node.object, keys)?;
+        keys.push(&node.property.name);
+        symbol
+      }
+      Expression::ComputedMemberExpression(node) if !node.optional => {
+        let Expression::StringLiteral(key) = &node.expression else {
+          return None;
+        };
+        let symbol = self.get_reference_path(&node.object, keys)?;
+        keys.push(&key.value);
+        symbol
+      }
+      _ => return None,
+    };
+    Some(symbol)
+  }
+
+  fn narrow_property_path(
+    &mut self,
+    ty: Ty<'a>,
+    keys: &[&'a Atom<'a>],
+    narrow: Narrow<'a, '_>,
+  ) -> Ty<'a> {
+    let Some((key, rest)) = keys.split_first() else {
+      return narrow(self, ty);
+    };
+    match ty {
+      Ty::Union(u) => {
+        let mut types = vec![];
+        u.for_each(|t| types.push(t));
+        let types =
+          types.into_iter().map(|t| self.narrow_property_path(t, keys, narrow)).collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      Ty::Instance(_) | Ty::Unresolved(UnresolvedType::UnInitType(_)) => {
+        let resolved = self.get_lowest_type(ty);
+        if resolved == ty {
+          return ty;
+        }
+        let narrowed = self.narrow_property_path(resolved, keys, narrow);
+        // Keep the instance, so that it can still be printed by name
+        if narrowed == resolved {
+          ty
+        } else {
+          narrowed
+        }
+      }
+      Ty::Record(record) => {
+        let mut record = record.clone();
+        match self.narrow_property(&mut record.string_keyed.0, key, rest, narrow) {
+          Some(Ty::Never) => Ty::Never,
+          Some(_) => Ty::Record(self.allocator.alloc(record)),
+          None => ty,
+        }
+      }
+      Ty::Interface(interface) => {
+        let inner = interface.0.borrow();
+        let mut record = inner.record.clone();
+        let callables = inner.callables.clone();
+        let unresolved_extends = inner.unresolved_extends.clone();
+        drop(inner);
+        match self.narrow_property(&mut record.string_keyed.0, key, rest, narrow) {
+          Some(Ty::Never) => Ty::Never,
+          Some(_) => {
+            let inner = InterfaceTypeInner { record, callables, unresolved_extends };
+            Ty::Interface(self.allocator.alloc(InterfaceType(RefCell::new(inner))))
+          }
+          None => ty,
+        }
+      }
+      _ => ty,
+    }
+  }
+
+  /// Returns the narrowed property if it is changed. An object whose property is narrowed to
+  /// `never` is also `never`.
+  fn narrow_property(
+    &mut self,
+    properties: &mut FxHashMap<&'a str, RecordPropertyValue<'a>>,
+    key: &'a Atom<'a>,
+    rest: &[&'a Atom<'a>],
+    narrow: Narrow<'a, '_>,
+  ) -> Option<Ty<'a>> {
+    let property = properties.get_mut(key.as_str())?;
+    let value = property.value;
+    let narrowed = self.narrow_property_path(value, rest, narrow);
+    (narrowed != value).then(|| {
+      property.value = narrowed;
+      narrowed
+    })
+  }
+}
+
+fn get_typeof_argument<'a>(node: &'a Expression<'a>) -> Option<&'a Expression<'a>> {
+  match node {
+    Expression::UnaryExpression(node) if node.operator == UnaryOperator::Typeof => {
+      Some(&node.argument)
+    }
+    Expression::ParenthesizedExpression(node) => get_typeof_argument(&node.expression),
+    _ => None,
+  }
+}
+
+fn get_string_literal<'a>(node: &'a Expression<'a>) -> Option<&'a str> {
+  match node {
+    Expression::StringLiteral(node) => Some(node.value.as_str()),
+    Expression::TemplateLiteral(node) if node.expressions.is_empty() => {
+      node.quasis[0].value.cooked.as_ref().map(|cooked| cooked.as_str())
+    }
+    Expression::ParenthesizedExpression(node) => get_string_literal(&node.expression),
+    _ => None,
+  }
+}
@@ -91,7 +91,8 @@ impl<'a> Analyzer<'a> {
     let id = self.runtime_scopes.pop();
     let scope = self.runtime_scopes.get(id);
     debug_assert!(scope.kind.get_blocked_exit().is_none());
-    if !scope.kind.is_function() {
+    // The code after a scope which must exit is not reached from it
+    if !scope.kind.is_function() && scope.exited != Some(true) {
       self.apply_shadows([id], false);
     }
   }
@@ -101,7 +102,15 @@ impl<'a> Analyzer<'a> {
     scope_1: RuntimeScopeId,
     scope_2: RuntimeScopeId,
   ) {
-    self.apply_shadows([scope_1, scope_2], true);
+    let exited_1 = self.runtime_scopes.get(scope_1).exited == Some(true);
+    let exited_2 = self.runtime_scopes.get(scope_2).exited == Some(true);
+    match (exited_1, exited_2) {
+      (false, false) => self.apply_shadows([scope_1, scope_2], true),
+      // The code after is only reached from the other scope
+      (true, false) => self.apply_shadows([scope_2], true),
+      (false, true) => self.apply_shadows([scope_1], true),
+      (true, true) => {}
+    }
     self.apply_complementary_blocked_exits(scope_1, scope_2);
   }
 }
@@ -1,6 +1,6 @@
 use std::{collections::hash_map::Entry, mem};
 
-use oxc::semantic::SymbolId;
+use oxc::semantic::{Semantic, SymbolId};
 use oxc_index::{define_index_type, IndexVec};
 use rustc_hash::FxHashMap;
 
@@ -10,13 +10,16 @@ define_index_type! {
   pub struct TypeScopeId = u32;
 }
 
-#[derive(Debug, Default)]
+#[derive(Default)]
 struct TypeScope<'a> {
   types: FxHashMap<SymbolId, Ty<'a>>,
   parent: Option<TypeScopeId>,
+  /// The type of `this` in the interface or class declared in this scope
+  this: Option<Ty<'a>>,
+  /// Only set on the root scope of a file
+  semantic: Option<&'a Semantic<'a>>,
 }
 
-#[derive(Debug)]
 pub struct TypeScopeTree<'a> {
   nodes: IndexVec<TypeScopeId, TypeScope<'a>>,
   root: TypeScopeId,
@@ -35,7 +38,13 @@ impl<'a> TypeScopeTree<'a> {
   }
 
   pub fn create_scope(&mut self) -> TypeScopeId {
-    self.nodes.push(TypeScope { types: Default::default(), parent: None })
+    self.nodes.push(TypeScope::default())
+  }
+
+  /// Create a detached root scope for a file. Types declared in this scope are resolved with the
+  /// given semantic.
+  pub fn create_root(&mut self, semantic: &'a Semantic<'a>) -> TypeScopeId {
+    self.nodes.push(TypeScope { semantic: Some(semantic), ..Default::default() })
   }
 
   pub fn push(&mut self) -> TypeScopeId {
@@ -43,7 +52,7 @@ impl<'a> TypeScopeTree<'a> {
   }
 
   pub fn push_with_types(&mut self, types: FxHashMap<SymbolId, Ty<'a>>) -> TypeScopeId {
-    let id = self.nodes.push(TypeScope { types, parent: Some(self.top) });
+    let id = self.nodes.push(TypeScope { types, parent: Some(self.top), ..Default::default() });
     self.top = id;
     id
   }
@@ -73,6 +82,21 @@ impl<'a> TypeScopeTree<'a> {
     Ty::Unresolved(UnresolvedType::UnInitType(symbol))
   }
 
+  /// The type of `this` in the innermost interface or class.
+  pub fn search_this(&self) -> Option<Ty<'a>> {
+    let mut scope = self.top;
+    loop {
+      if let Some(ty) = self.nodes[scope].this {
+        return Some(ty);
+      }
+      scope = self.nodes[scope].parent?;
+    }
+  }
+
+  pub fn set_this(&mut self, scope: TypeScopeId, ty: Ty<'a>) {
+    self.nodes[scope].this = Some(ty);
+  }
+
   pub fn insert_on_scope(
     &mut self,
     scope: TypeScopeId,
@@ -102,6 +126,10 @@ impl<'a> TypeScopeTree<'a> {
     self.nodes[self.top].types.get_mut(&symbol)
   }
 
+  pub fn root(&self) -> TypeScopeId {
+    self.root
+  }
+
   pub fn top(&self) -> TypeScopeId {
     self.top
   }
@@ -113,4 +141,19 @@ impl<'a> TypeScopeTree<'a> {
   pub fn set_parent(&mut self, scope: TypeScopeId, parent: TypeScopeId) {
     self.nodes[scope].parent = Some(parent);
   }
+
+  pub fn set_semantic(&mut self, scope: TypeScopeId, semantic: &'a Semantic<'a>) {
+    self.nodes[scope].semantic = Some(semantic);
+  }
+
+  /// Returns the semantic of the file which the scope belongs to.
+  pub fn get_semantic(&self, mut scope: TypeScopeId) -> Option<&'a Semantic<'a>> {
+    loop {
+      let node = &self.nodes[scope];
+      if let Some(semantic) = node.semantic {
+        return Some(semantic);
+      }
+      scope = node.parent?;
+    }
+  }
 }
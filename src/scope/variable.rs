@@ -52,43 +52,72 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// Shadows override the declared type of typed variables, so that they can be narrowed.
   pub fn read_variable(&self, symbol: SymbolId) -> Ty<'a> {
+    for scope in self.runtime_scopes.iter_stack().rev() {
+      if let Some(variable) = scope.variables.get(&symbol) {
+        return variable.value;
+      }
+    }
     if let Some(resolved) = self.variables.get(&symbol) {
       *resolved
+    } else if self.is_symbol_var(symbol) {
+      // Var declaration like:
+      // ```ts
+      // read(a)
+      // while (a) { var a; }
+      // ```
+      Ty::Any
     } else {
-      for scope in self.runtime_scopes.iter_stack().rev() {
-        if let Some(variable) = scope.variables.get(&symbol) {
-          return variable.value;
-        }
-      }
-      if self.is_symbol_var(symbol) {
-        // Var declaration like:
-        // ```ts
-        // read(a)
-        // while (a) { var a; }
-        // ```
-        Ty::Any
-      } else {
-        unreachable!("Variable not found: {:?}", self.semantic.symbols().get_name(symbol));
-      }
+      unreachable!("Variable not found: {:?}", self.semantic.symbols().get_name(symbol));
     }
   }
 
   pub fn write_variable(&mut self, symbol: SymbolId, value: Ty<'a>) {
-    if let Some(_resolved) = self.variables.get_mut(&symbol) {
-      // Do nothing
+    if let Some(resolved) = self.variables.get(&symbol) {
       // CHECKER: Should check type compatibility
+      // Assignments reset the narrowed type to the declared one
+      let declared = *resolved;
+      if self.is_variable_narrowed(symbol) {
+        self.shadow_variable(symbol, declared);
+      }
     } else {
-      self
-        .runtime_scopes
-        .get_current_mut()
-        .variables
-        .entry(symbol)
-        .and_modify(|variable| variable.value = value)
-        .or_insert(Variable::shadow(value));
+      self.shadow_variable(symbol, value);
     }
   }
 
+  /// The type of a variable without narrowing: the declared type of a typed variable, or the
+  /// inferred one of an untyped variable.
+  pub fn read_declared_variable(&self, symbol: SymbolId) -> Ty<'a> {
+    if let Some(declared) = self.variables.get(&symbol) {
+      return *declared;
+    }
+    for scope in self.runtime_scopes.iter_stack().rev() {
+      if let Some(variable) = scope.variables.get(&symbol) {
+        if !variable.is_shadow {
+          return variable.value;
+        }
+      }
+    }
+    self.read_variable(symbol)
+  }
+
+  /// Write the value of a variable in the current scope, which is also used to narrow typed
+  /// variables.
+  pub fn shadow_variable(&mut self, symbol: SymbolId, value: Ty<'a>) {
+    self
+      .runtime_scopes
+      .get_current_mut()
+      .variables
+      .entry(symbol)
+      .and_modify(|variable| variable.value = value)
+      .or_insert(Variable::shadow(value));
+  }
+
+  fn is_variable_narrowed(&self, symbol: SymbolId) -> bool {
+    self.runtime_scopes.iter_stack().any(|scope| scope.variables.contains_key(&symbol))
+  }
+
   pub fn apply_shadows<const N: usize>(
     &mut self,
     scopes: [RuntimeScopeId; N],
@@ -110,7 +139,7 @@ impl<'a> Analyzer<'a> {
         values.push(self.read_variable(symbol));
       }
       let value = self.into_union(values).unwrap();
-      self.write_variable(symbol, value);
+      self.shadow_variable(symbol, value);
     }
   }
 
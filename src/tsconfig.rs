@@ -0,0 +1,319 @@
+use std::{
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde_json::{Map, Value};
+
+use crate::{
+  config::{Config, Jsx, Lib, Target},
+  utils::{normalize_path, parse_jsonc},
+};
+
+/// The extensions of the files picked by `include`.
+const SOURCE_EXTENSIONS: [&str; 4] = [".ts", ".tsx", ".mts", ".cts"];
+
+/// `exclude` when it is not specified.
+const DEFAULT_EXCLUDE: [&str; 3] = ["node_modules", "bower_components", "jspm_packages"];
+
+/// A `tsconfig.json` merged with the configs it extends. Paths are absolute.
+#[derive(Debug, Default)]
+struct TsConfig {
+  compiler_options: Map<String, Value>,
+  /// The directory that `paths` is relative to, when there is no `baseUrl`
+  paths_base: Option<PathBuf>,
+  files: Option<Vec<PathBuf>>,
+  include: Option<Vec<String>>,
+  exclude: Option<Vec<String>>,
+}
+
+impl Config {
+  /// Load the config from a `tsconfig.json`, following its `extends` chain.
+  pub fn from_tsconfig(path: impl AsRef<Path>) -> io::Result<Self> {
+    let path = normalize_path(&std::path::absolute(path.as_ref())?);
+    let dir = path.parent().unwrap_or(Path::new("/"));
+    let tsconfig = load_tsconfig(&path, &mut vec![])?;
+    let options = &tsconfig.compiler_options;
+    let get_bool = |name: &str| options.get(name).and_then(Value::as_bool);
+
+    let mut config = Config::default();
+    if let Some(target) = options.get("target").and_then(Value::as_str) {
+      config.target = parse_target(target).ok_or_else(|| invalid_option("target", target))?;
+    }
+    if let Some(lib) = options.get("lib").and_then(Value::as_array) {
+      let mut libs = vec![];
+      for lib in lib.iter().filter_map(Value::as_str).filter_map(parse_lib) {
+        if !libs.contains(&lib) {
+          libs.push(lib);
+        }
+      }
+      config.lib = Some(libs);
+    }
+    let strict = get_bool("strict").unwrap_or(false);
+    config.strict_null_checks = get_bool("strictNullChecks").unwrap_or(strict);
+    config.exact_optional_property_types = get_bool("exactOptionalPropertyTypes").unwrap_or(false);
+    config.no_unchecked_indexed_access = get_bool("noUncheckedIndexedAccess").unwrap_or(false);
+    if let Some(jsx) = options.get("jsx").and_then(Value::as_str) {
+      config.jsx = Some(parse_jsx(jsx).ok_or_else(|| invalid_option("jsx", jsx))?);
+    }
+    config.jsx_import_source =
+      options.get("jsxImportSource").and_then(Value::as_str).map(str::to_string);
+
+    config.base_url = options.get("baseUrl").and_then(Value::as_str).map(PathBuf::from);
+    config.paths_base = config.base_url.clone().or(tsconfig.paths_base);
+    if let Some(paths) = options.get("paths").and_then(Value::as_object) {
+      config.paths = paths
+        .iter()
+        .map(|(pattern, substitutions)| {
+          let substitutions = substitutions.as_array().into_iter().flatten();
+          let substitutions = substitutions.filter_map(Value::as_str).map(str::to_string).collect();
+          (pattern.clone(), substitutions)
+        })
+        .collect();
+    }
+
+    config.files = tsconfig.files.unwrap_or_default();
+    config.include = match tsconfig.include {
+      Some(include) => include,
+      // Everything is included without `files` and `include`
+      None if config.files.is_empty() => vec![resolve_pattern(dir, "**/*")],
+      None => vec![],
+    };
+    config.exclude = tsconfig.exclude.unwrap_or_else(|| {
+      DEFAULT_EXCLUDE.iter().map(|pattern| resolve_pattern(dir, pattern)).collect()
+    });
+    Ok(config)
+  }
+
+  /// The source files of the program, which are `files` plus the files matched by `include` and
+  /// not by `exclude`. Sorted.
+  pub fn source_files(&self) -> io::Result<Vec<PathBuf>> {
+    let include = build_glob_set(&self.include)?;
+    let exclude = build_glob_set(&self.exclude)?;
+    // `node_modules/**/*` also excludes the `node_modules` directory itself, so it is not walked
+    let excluded_dirs = build_glob_set(
+      &self
+        .exclude
+        .iter()
+        .filter_map(|pattern| pattern.strip_suffix("/**/*").map(str::to_string))
+        .collect::<Vec<_>>(),
+    )?;
+
+    let mut result = self.files.clone();
+    for pattern in &self.include {
+      let root = get_pattern_root(pattern);
+      if root.is_dir() {
+        collect_files(&root, &include, &exclude, &excluded_dirs, &mut result)?;
+      }
+    }
+    result.sort();
+    result.dedup();
+    Ok(result)
+  }
+}
+
+fn load_tsconfig(path: &Path, visiting: &mut Vec<PathBuf>) -> io::Result<TsConfig> {
+  if visiting.iter().any(|visited| visited == path) {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      format!("Circular `extends` in {}", path.display()),
+    ));
+  }
+  visiting.push(path.to_path_buf());
+
+  let source = fs::read_to_string(path)?;
+  let json = parse_jsonc(&source).map_err(io::Error::from)?;
+  let dir = path.parent().unwrap_or(Path::new("/"));
+
+  let mut tsconfig = TsConfig::default();
+  let extends = match &json["extends"] {
+    Value::String(extends) => vec![extends.as_str()],
+    Value::Array(extends) => extends.iter().filter_map(Value::as_str).collect(),
+    _ => vec![],
+  };
+  for extends in extends {
+    let base_path = resolve_extends(dir, extends).ok_or_else(|| {
+      io::Error::new(io::ErrorKind::NotFound, format!("Cannot find base config `{extends}`"))
+    })?;
+    let base = load_tsconfig(&base_path, visiting)?;
+    tsconfig.compiler_options.extend(base.compiler_options);
+    tsconfig.paths_base = base.paths_base.or(tsconfig.paths_base);
+    tsconfig.files = base.files.or(tsconfig.files);
+    tsconfig.include = base.include.or(tsconfig.include);
+    tsconfig.exclude = base.exclude.or(tsconfig.exclude);
+  }
+
+  if let Some(options) = json["compilerOptions"].as_object() {
+    for (name, value) in options {
+      let value = match (name.as_str(), value) {
+        // Paths in a base config are relative to the base config
+        ("baseUrl", Value::String(base_url)) => {
+          Value::String(normalize_path(&dir.join(base_url)).to_string_lossy().into_owned())
+        }
+        ("paths", _) => {
+          tsconfig.paths_base = Some(dir.to_path_buf());
+          value.clone()
+        }
+        _ => value.clone(),
+      };
+      tsconfig.compiler_options.insert(name.clone(), value);
+    }
+  }
+  let get_strings = |name: &str| {
+    json[name].as_array().map(|array| array.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+  };
+  if let Some(files) = get_strings("files") {
+    tsconfig.files = Some(files.into_iter().map(|file| normalize_path(&dir.join(file))).collect());
+  }
+  if let Some(include) = get_strings("include") {
+    tsconfig.include =
+      Some(include.into_iter().map(|pattern| resolve_pattern(dir, pattern)).collect());
+  }
+  if let Some(exclude) = get_strings("exclude") {
+    tsconfig.exclude =
+      Some(exclude.into_iter().map(|pattern| resolve_pattern(dir, pattern)).collect());
+  }
+
+  visiting.pop();
+  Ok(tsconfig)
+}
+
+/// `extends` is either a path, or a module specifier resolved in `node_modules`.
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+  let with_json = |path: PathBuf| {
+    if path.is_file() {
+      Some(path)
+    } else {
+      let mut with_json = path.into_os_string();
+      with_json.push(".json");
+      Some(PathBuf::from(with_json)).filter(|path| path.is_file())
+    }
+  };
+  if extends.starts_with("./") || extends.starts_with("../") || Path::new(extends).is_absolute() {
+    return with_json(normalize_path(&dir.join(extends)));
+  }
+  dir.ancestors().find_map(|ancestor| {
+    let path = ancestor.join("node_modules").join(extends);
+    with_json(path.clone()).or_else(|| with_json(path.join("tsconfig.json")))
+  })
+}
+
+/// Make `pattern` absolute. A pattern which looks like a directory includes all files in it.
+fn resolve_pattern(dir: &Path, pattern: &str) -> String {
+  let path = normalize_path(&dir.join(pattern));
+  let mut pattern = path.to_string_lossy().into_owned();
+  let last = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+  if !last.contains('*') && !last.contains('?') && !last.contains('.') {
+    pattern.push_str("/**/*");
+  }
+  pattern
+}
+
+/// The directory before the first wildcard in an absolute pattern.
+fn get_pattern_root(pattern: &str) -> PathBuf {
+  Path::new(pattern)
+    .components()
+    .take_while(|component| {
+      let component = component.as_os_str().to_string_lossy();
+      !component.contains('*') && !component.contains('?')
+    })
+    .collect()
+}
+
+fn build_glob_set(patterns: &[String]) -> io::Result<GlobSet> {
+  let mut builder = GlobSetBuilder::new();
+  for pattern in patterns {
+    // Like in `tsc`, `*` does not match directory separators
+    let glob = GlobBuilder::new(pattern)
+      .literal_separator(true)
+      .build()
+      .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    builder.add(glob);
+  }
+  builder.build().map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
+
+fn collect_files(
+  dir: &Path,
+  include: &GlobSet,
+  exclude: &GlobSet,
+  excluded_dirs: &GlobSet,
+  result: &mut Vec<PathBuf>,
+) -> io::Result<()> {
+  for entry in fs::read_dir(dir)? {
+    let path = entry?.path();
+    if exclude.is_match(&path) {
+      continue;
+    }
+    if path.is_dir() {
+      if !excluded_dirs.is_match(&path) {
+        collect_files(&path, include, exclude, excluded_dirs, result)?;
+      }
+    } else if include.is_match(&path) {
+      let name = path.to_string_lossy();
+      if SOURCE_EXTENSIONS.iter().any(|ext| name.ends_with(ext)) {
+        result.push(path);
+      }
+    }
+  }
+  Ok(())
+}
+
+fn invalid_option(name: &str, value: &str) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, format!("Invalid `{name}`: {value}"))
+}
+
+fn parse_target(target: &str) -> Option<Target> {
+  Some(match target.to_ascii_lowercase().as_str() {
+    // ES3 is no longer supported, and is treated as ES5
+    "es3" | "es5" => Target::ES5,
+    "es6" | "es2015" => Target::ES2015,
+    "es2016" => Target::ES2016,
+    "es2017" => Target::ES2017,
+    "es2018" => Target::ES2018,
+    "es2019" => Target::ES2019,
+    "es2020" => Target::ES2020,
+    "es2021" => Target::ES2021,
+    "es2022" => Target::ES2022,
+    "es2023" => Target::ES2023,
+    "es2024" => Target::ES2024,
+    "esnext" => Target::ESNext,
+    _ => return None,
+  })
+}
+
+/// Sub-libs like `es2015.promise` and `dom.iterable` have no bundled counterpart, and load their
+/// parent lib instead.
+fn parse_lib(lib: &str) -> Option<Lib> {
+  let lib = lib.to_ascii_lowercase();
+  let parent = lib.split('.').next().unwrap_or_default();
+  Some(match parent {
+    "es5" => Lib::ES5,
+    "es6" | "es2015" => Lib::ES2015,
+    "es7" | "es2016" => Lib::ES2016,
+    "es2017" => Lib::ES2017,
+    "es2018" => Lib::ES2018,
+    "es2019" => Lib::ES2019,
+    "es2020" => Lib::ES2020,
+    "es2021" => Lib::ES2021,
+    "es2022" => Lib::ES2022,
+    // There is no bundled ES2024 lib
+    "es2023" | "es2024" => Lib::ES2023,
+    "esnext" => Lib::ESNext,
+    "dom" => Lib::Dom,
+    "webworker" => Lib::WebWorker,
+    _ => return None,
+  })
+}
+
+fn parse_jsx(jsx: &str) -> Option<Jsx> {
+  Some(match jsx.to_ascii_lowercase().as_str() {
+    "preserve" => Jsx::Preserve,
+    "react" => Jsx::React,
+    "react-jsx" => Jsx::ReactJsx,
+    "react-jsxdev" => Jsx::ReactJsxDev,
+    "react-native" => Jsx::ReactNative,
+    _ => return None,
+  })
+}
@@ -20,12 +20,12 @@ impl<'a> TypeAccumulator<'a> {
       TypeAccumulator::Single(t) => {
         if *t != ty {
           let union = allocator.alloc(UnionType::default());
-          union.add(*t);
-          union.add(ty);
+          add_flattened(union, *t);
+          add_flattened(union, ty);
           *self = TypeAccumulator::Union(union);
         }
       }
-      TypeAccumulator::Union(union) => union.add(ty),
+      TypeAccumulator::Union(union) => add_flattened(union, ty),
       TypeAccumulator::FrozenUnion(_) => unreachable!(),
     }
   }
@@ -67,3 +67,11 @@ impl<'a> TypeAccumulator<'a> {
     }
   }
 }
+
+/// Unions are flattened into the accumulated union.
+fn add_flattened<'a>(union: &mut UnionType<'a>, ty: Ty<'a>) {
+  match ty {
+    Ty::Union(u) => u.for_each(|ty| union.add(ty)),
+    _ => union.add(ty),
+  }
+}
@@ -0,0 +1,67 @@
+use oxc::span::Atom;
+
+use super::{
+  callable::ExtractedCallable, facts::Facts, property_key::PropertyKeyType,
+  unresolved::UnresolvedType, Ty,
+};
+use crate::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// The type of `await target`, which unwraps thenables recursively like `Awaited<T>`.
+  pub fn get_to_awaited(&mut self, target: Ty<'a>) -> Ty<'a> {
+    if self.is_type_parameter_dependent(target) {
+      return Ty::Unresolved(UnresolvedType::Awaited(self.allocator.alloc(target)));
+    }
+    match target {
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types = members.into_iter().map(|ty| self.get_to_awaited(ty)).collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      Ty::Instance(instance) if self.is_promise_generic(instance.generic) => {
+        let value = instance.args.first().copied().unwrap_or(Ty::Error);
+        self.get_to_awaited(value)
+      }
+      Ty::Record(_) | Ty::Interface(_) | Ty::Intersection(_) | Ty::Instance(_) => {
+        match self.get_thenable_value(target) {
+          Some(value) => self.get_to_awaited(value),
+          None => target,
+        }
+      }
+      _ => target,
+    }
+  }
+
+  /// Returns `T | PromiseLike<T>`, the contextual type of an awaited or returned value.
+  pub fn get_awaitable_type(&mut self, value: Ty<'a>) -> Ty<'a> {
+    let promise_like = self.resolve_global_type("PromiseLike");
+    let promise_like = self.create_generic_instance(promise_like, vec![value]);
+    self.into_union([value, promise_like]).unwrap()
+  }
+
+  fn is_promise_generic(&mut self, generic: Ty<'a>) -> bool {
+    generic == self.resolve_global_type("Promise")
+      || generic == self.resolve_global_type("PromiseLike")
+  }
+
+  /// The type of the first parameter of the `onfulfilled` callback of `target.then`, or `None` if
+  /// `target` is not a thenable.
+  fn get_thenable_value(&mut self, target: Ty<'a>) -> Option<Ty<'a>> {
+    let key = PropertyKeyType::StringLiteral(self.allocator.alloc(Atom::from("then")));
+    let then = self.get_property(target, key);
+    let then = self.extract_callable_function(then)?;
+    if matches!(then, ExtractedCallable::Any) {
+      return None;
+    }
+    let scope = self.type_scopes.create_scope();
+    let (_, onfulfilled) = *self.get_callable_parameter_types(scope, &then).first()?;
+    // The callback is usually optional
+    let onfulfilled = self.filter_by_facts(onfulfilled, Facts::EQ_UNDEFINED | Facts::EQ_NULL);
+    let onfulfilled = self.extract_callable_function(onfulfilled)?;
+    // The callback is created in `scope`, so it is instantiated in another scope
+    let scope = self.type_scopes.create_scope();
+    let (spread, value) = *self.get_callable_parameter_types(scope, &onfulfilled).first()?;
+    Some(if spread { self.iterate_result_union(value) } else { value })
+  }
+}
@@ -5,11 +5,11 @@ use oxc::{
     NONE,
   },
   semantic::SymbolId,
-  span::SPAN,
+  span::{GetSpan, SPAN},
 };
 use rustc_hash::FxHashMap;
 
-use super::{ctx::CtxTy, generic::GenericParam, Ty};
+use super::{ctx::CtxTy, facts::Facts, generic::GenericParam, Ty};
 use crate::{
   analyzer::Analyzer,
   scope::r#type::TypeScopeId,
@@ -27,6 +27,17 @@ pub struct CallableType<'a, const CTOR: bool> {
   pub params: Vec<(bool, CtxTy<'a>)>,
   pub rest_param: Option<CtxTy<'a>>,
   pub return_type: CtxTy<'a>,
+  pub predicate: Option<TypePredicate<'a>>,
+}
+
+/// The type predicate of a type guard (`x is T`) or an assertion function (`asserts x is T`,
+/// `asserts x`).
+#[derive(Debug, Clone, Copy)]
+pub struct TypePredicate<'a> {
+  /// The index of the parameter. `None` for `this`.
+  pub param: Option<usize>,
+  pub asserts: bool,
+  pub ty: Option<CtxTy<'a>>,
 }
 
 pub type FunctionType<'a> = CallableType<'a, false>;
@@ -43,21 +54,36 @@ impl<'a> Analyzer<'a> {
     }
 
     let scope = self.instantiate_generic_params(&callable.type_params, type_args);
+    Some(self.rescope_callable(callable, scope, vec![]))
+  }
+
+  /// Resolve the types of `callable` in `scope`, which is placed under the scope of the callable.
+  pub fn rescope_callable<const CTOR: bool>(
+    &mut self,
+    callable: &CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    type_params: Vec<GenericParam<'a>>,
+  ) -> &'a CallableType<'a, CTOR> {
     self.type_scopes.set_parent(scope, callable.scope);
     let this_type = callable.this_param.map(|ty| ty.with_scope(scope));
     let params =
       callable.params.iter().map(|(optional, ty)| (*optional, ty.with_scope(scope))).collect();
     let rest_param = callable.rest_param.map(|ty| ty.with_scope(scope));
     let return_type = callable.return_type.with_scope(scope);
-    Some(self.allocator.alloc(CallableType {
+    let predicate = callable.predicate.map(|predicate| TypePredicate {
+      ty: predicate.ty.map(|ty| ty.with_scope(scope)),
+      ..predicate
+    });
+    self.allocator.alloc(CallableType {
       is_method: callable.is_method,
       scope,
-      type_params: vec![],
+      type_params,
       this_param: this_type,
       params,
       rest_param,
       return_type,
-    }))
+      predicate,
+    })
   }
 
   pub fn serialize_callable_type<const CTOR: bool>(
@@ -110,11 +136,43 @@ impl<'a> Analyzer<'a> {
           )
         }),
       ),
-      self.ast_builder.ts_type_annotation(SPAN, self.serialize_ctx_ty(callable.return_type)),
+      self.ast_builder.ts_type_annotation(SPAN, {
+        if let Some(predicate) = callable.predicate {
+          self.serialize_type_predicate(predicate)
+        } else {
+          self.serialize_ctx_ty(callable.return_type)
+        }
+      }),
+    )
+  }
+
+  fn serialize_type_predicate(&mut self, predicate: TypePredicate<'a>) -> TSType<'a> {
+    let parameter_name = match predicate.param {
+      Some(i) => self
+        .ast_builder
+        .ts_type_predicate_name_identifier_name(SPAN, &*self.allocator.alloc(format!("a{i}"))),
+      None => self.ast_builder.ts_type_predicate_name_this_type(SPAN),
+    };
+    let type_annotation =
+      predicate.ty.map(|ty| self.ast_builder.ts_type_annotation(SPAN, self.serialize_ctx_ty(ty)));
+    self.ast_builder.ts_type_type_predicate(
+      SPAN,
+      parameter_name,
+      predicate.asserts,
+      type_annotation,
     )
   }
 }
 
+/// The signature which a function expression is checked against.
+#[derive(Debug)]
+pub struct ContextualSignature<'a> {
+  /// `(spread, type)` of the parameters
+  pub params: Vec<(bool, Ty<'a>)>,
+  pub this: Option<Ty<'a>>,
+  pub return_type: Option<Ty<'a>>,
+}
+
 #[derive(Debug)]
 pub enum ExtractedCallable<'a, const CTOR: bool> {
   Any,
@@ -154,6 +212,15 @@ macro_rules! impl_extract_callable {
               _ => Some(ExtractedCallable::Overloaded(res)),
             }
           }
+          Ty::Interface(i) => {
+            let callables = i.0.borrow().callables.clone();
+            let mut res: Vec<_> = callables.into_iter().filter_map(|ty| self.$name(ty)).collect();
+            match res.len() {
+              0 => None,
+              1 => res.pop(),
+              _ => Some(ExtractedCallable::Overloaded(res)),
+            }
+          }
           Ty::Instance(i) => {
             let unwrapped = self.unwrap_generic_instance(i);
             self.$name(unwrapped)
@@ -176,15 +243,21 @@ impl<'a> Analyzer<'a> {
   ) -> Vec<(bool, Ty<'a>)> {
     match callable {
       ExtractedCallable::Any => vec![(true, Ty::Any)],
-      ExtractedCallable::Single(callable) => callable
-        .params
-        .iter()
-        .copied()
-        .map(|(optional, ty)| {
-          let ty = self.resolve_ctx_ty(scope, ty);
-          (false, self.get_optional_type(optional, ty))
-        })
-        .collect(),
+      ExtractedCallable::Single(callable) => {
+        let mut params = callable
+          .params
+          .iter()
+          .copied()
+          .map(|(optional, ty)| {
+            let ty = self.resolve_ctx_ty(scope, ty);
+            (false, self.get_optional_type(optional, ty))
+          })
+          .collect::<Vec<_>>();
+        if let Some(rest) = callable.rest_param {
+          params.push((true, self.resolve_ctx_ty(scope, rest)));
+        }
+        params
+      }
       ExtractedCallable::Overloaded(callables) => {
         let res = self.get_transposed_callable_parameter_types(scope, callables);
         res.into_iter().map(|u| (false, self.into_union(u).unwrap())).collect()
@@ -196,12 +269,63 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// The signature in the contextual type `sat` of a function expression with `param_count`
+  /// parameters, which types its unannotated parameters, `this`, and return values.
+  pub fn get_contextual_signature(
+    &mut self,
+    sat: Ty<'a>,
+    param_count: usize,
+  ) -> Option<ContextualSignature<'a>> {
+    // Optional callbacks
+    let sat = self.filter_by_facts(sat, Facts::EQ_UNDEFINED | Facts::EQ_NULL);
+    let callable = self.extract_callable_function(sat)?;
+    Some(self.select_contextual_signature(&callable, param_count))
+  }
+
+  /// Overloads and union members are chosen by whether they accept `param_count` parameters.
+  fn select_contextual_signature(
+    &mut self,
+    callable: &ExtractedCallable<'a, false>,
+    param_count: usize,
+  ) -> ContextualSignature<'a> {
+    match callable {
+      ExtractedCallable::Any => {
+        ContextualSignature { params: vec![(true, Ty::Any)], this: None, return_type: None }
+      }
+      ExtractedCallable::Single(single) => {
+        let scope = self.type_scopes.create_scope();
+        let params = self.get_callable_parameter_types(scope, callable);
+        // Uninferred type parameters are not usable
+        let mut resolve = |ty| {
+          let ty = self.resolve_ctx_ty(scope, ty);
+          (!self.is_type_parameter_dependent(ty)).then_some(ty)
+        };
+        let this = single.this_param.and_then(&mut resolve);
+        let return_type = resolve(single.return_type);
+        ContextualSignature { params, this, return_type }
+      }
+      ExtractedCallable::Overloaded(callables) | ExtractedCallable::Union(callables) => {
+        let selected = callables
+          .iter()
+          .find(|callable| match callable {
+            ExtractedCallable::Single(single) => {
+              param_count <= single.params.len() || single.rest_param.is_some()
+            }
+            _ => true,
+          })
+          .unwrap_or(&callables[0]);
+        self.select_contextual_signature(selected, param_count)
+      }
+    }
+  }
+
   fn get_transposed_callable_parameter_types<const CTOR: bool>(
     &mut self,
     scope: TypeScopeId,
     callables: &Vec<ExtractedCallable<'a, CTOR>>,
   ) -> Vec<Vec<Ty<'a>>> {
-    let callables = callables.iter().map(|c| self.get_callable_parameter_types(scope, c));
+    let callables =
+      callables.iter().map(|c| self.get_callable_parameter_types(scope, c)).collect::<Vec<_>>();
     let mut res = Vec::new();
     for callable in callables {
       for _ in res.len()..callable.len() {
@@ -209,7 +333,9 @@ impl<'a> Analyzer<'a> {
       }
       for (i, (spread, item)) in callable.into_iter().enumerate() {
         if spread {
-          todo!()
+          // The rest parameter takes the position of its first element
+          let element = self.iterate_result_union(item);
+          res[i].push(element);
         } else {
           res[i].push(item);
         }
@@ -228,64 +354,258 @@ impl<'a> Analyzer<'a> {
     ret_sat: Option<Ty<'a>>,
   ) -> Option<Ty<'a>> {
     if let Some(callable) = callable {
-      match callable {
-        ExtractedCallable::Any => {
-          self.exec_arguments(arguments, None);
-          None
-        }
-        ExtractedCallable::Single(callable) => {
-          self.exec_call_on_single(callable, type_args, this_arg, arguments, ret_sat)
-        }
-        ExtractedCallable::Overloaded(callables) => {
-          for callable in callables {
-            if let Some(ret) =
-              self.exec_call(Some(callable), type_args, this_arg, arguments, ret_sat)
-            {
-              return Some(ret);
-            }
-          }
-          None
-        }
-        ExtractedCallable::Union(callables) => {
-          let mut ret_types = Vec::new();
-          for callable in callables {
-            let ret = self.exec_call(Some(callable), type_args, this_arg, arguments, ret_sat)?;
-            ret_types.push(ret);
-          }
-          self.into_union(ret_types)
-        }
-      }
+      self.exec_call_impl(&callable, type_args, this_arg, arguments, ret_sat)
     } else {
       self.exec_arguments(arguments, None);
       None
     }
   }
 
-  fn exec_call_on_single<const CTOR: bool>(
+  fn exec_call_impl<const CTOR: bool>(
     &mut self,
-    callable: &'a CallableType<'a, CTOR>,
+    callable: &ExtractedCallable<'a, CTOR>,
     type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
     this_arg: Ty<'a>,
     arguments: &'a allocator::Vec<'a, Argument<'a>>,
     ret_sat: Option<Ty<'a>>,
   ) -> Option<Ty<'a>> {
-    if callable.type_params.is_empty() {
+    match callable {
+      ExtractedCallable::Any => {
+        self.exec_arguments(arguments, None);
+        None
+      }
+      ExtractedCallable::Single(callable) => {
+        self.exec_call_on_single(callable, type_args, this_arg, arguments, ret_sat)
+      }
+      ExtractedCallable::Overloaded(callables) => {
+        let selected = self.resolve_overload(callables, type_args, arguments)?;
+        let ret = self.exec_call_impl(selected, type_args, this_arg, arguments, ret_sat);
+        for arg in arguments {
+          self.executed_arguments.remove(&arg.span());
+        }
+        ret
+      }
+      ExtractedCallable::Union(callables) => {
+        let mut ret_types = Vec::new();
+        for callable in callables {
+          let ret = self.exec_call_impl(callable, type_args, this_arg, arguments, ret_sat)?;
+          ret_types.push(ret);
+        }
+        self.into_union(ret_types)
+      }
+    }
+  }
+
+  /// Select the first overload whose parameters accept the arguments, or the first one accepting
+  /// the number of them if none does.
+  ///
+  /// The arguments other than callbacks are executed once here, with the parameter types of that
+  /// fallback, and are reused when the selected overload is called. Callbacks are executed only
+  /// for the selected overload, since their types depend on its parameters.
+  fn resolve_overload<'b, const CTOR: bool>(
+    &mut self,
+    callables: &'b [ExtractedCallable<'a, CTOR>],
+    type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+  ) -> Option<&'b ExtractedCallable<'a, CTOR>> {
+    let fitting = callables
+      .iter()
+      .filter(|callable| match callable {
+        ExtractedCallable::Single(single) => Self::check_arity(single, arguments),
+        _ => true,
+      })
+      .collect::<Vec<_>>();
+    let fallback = fitting.first().copied().or(callables.first())?;
+
+    let (scope, params) = match fallback {
+      ExtractedCallable::Single(single) => {
+        let (scope, params) = self.instantiate_call_parameters(single, type_args);
+        (scope, Some((*single, params)))
+      }
+      _ => (self.type_scopes.empty_scope, None),
+    };
+    let mut arg_types = vec![];
+    for (index, arg) in arguments.iter().enumerate() {
+      if matches!(arg, Argument::FunctionExpression(_) | Argument::ArrowFunctionExpression(_)) {
+        arg_types.push(None);
+        continue;
+      }
+      let (sat, as_const) = match &params {
+        Some((single, params)) => match self.get_argument_parameter(params, index) {
+          Some(param) => self.get_argument_sat(single, scope, param, arg),
+          None => (None, false),
+        },
+        None => (None, false),
+      };
+      let ty = self.exec_argument(arg, sat, as_const);
+      self.executed_arguments.insert(arg.span(), ty);
+      arg_types.push(Some(ty));
+    }
+
+    for callable in fitting {
+      if self.is_overload_applicable(callable, type_args, arguments, &arg_types) {
+        return Some(callable);
+      }
+    }
+    Some(fallback)
+  }
+
+  /// Whether the types of the executed arguments match the parameters. `None` arguments are
+  /// callbacks, which are checked by their signatures.
+  fn is_overload_applicable<const CTOR: bool>(
+    &mut self,
+    callable: &ExtractedCallable<'a, CTOR>,
+    type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    arg_types: &[Option<Ty<'a>>],
+  ) -> bool {
+    let ExtractedCallable::Single(callable) = callable else {
+      return true;
+    };
+    let (_, params) = self.instantiate_call_parameters(callable, type_args);
+    for (index, (arg, ty)) in arguments.iter().zip(arg_types).enumerate() {
+      if matches!(arg, Argument::SpreadElement(_)) {
+        break;
+      }
+      let Some(param) = self.get_argument_parameter(&params, index) else {
+        break;
+      };
+      let applicable = match ty {
+        Some(ty) => self.match_covariant_types(1, *ty, param).matched(),
+        None => self.is_callback_applicable(arg, param),
+      };
+      if !applicable {
+        return false;
+      }
+    }
+    true
+  }
+
+  /// Callbacks are not executed for every overload. Instead, they must not require more
+  /// parameters than the contextual signature has, and must be annotated with a type predicate if
+  /// the contextual signature has one.
+  fn is_callback_applicable(&mut self, arg: &'a Argument<'a>, param: Ty<'a>) -> bool {
+    let (params, return_type) = match arg {
+      Argument::FunctionExpression(node) => (&node.params, &node.return_type),
+      Argument::ArrowFunctionExpression(node) => (&node.params, &node.return_type),
+      _ => unreachable!(),
+    };
+    let param = self.filter_by_facts(param, Facts::EQ_UNDEFINED | Facts::EQ_NULL);
+    let Some(ExtractedCallable::Single(signature)) = self.extract_callable_function(param) else {
+      return true;
+    };
+    let required = params
+      .items
+      .iter()
+      .filter(|param| !param.pattern.optional && !param.pattern.kind.is_assignment_pattern())
+      .count();
+    let has_predicate = matches!(
+      return_type.as_ref().map(|n| &n.type_annotation),
+      Some(TSType::TSTypePredicate(node)) if !node.asserts
+    );
+    (required <= signature.params.len() || signature.rest_param.is_some())
+      && (has_predicate || !signature.predicate.is_some_and(|predicate| !predicate.asserts))
+  }
+
+  /// Whether the number of arguments fits the parameters.
+  fn check_arity<const CTOR: bool>(
+    callable: &CallableType<'a, CTOR>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+  ) -> bool {
+    if arguments.iter().any(|arg| matches!(arg, Argument::SpreadElement(_))) {
+      return true;
+    }
+    let required = callable.params.iter().rposition(|(optional, _)| !optional).map_or(0, |i| i + 1);
+    arguments.len() >= required
+      && (callable.rest_param.is_some() || arguments.len() <= callable.params.len())
+  }
+
+  /// The scope of a call to `callable` and its parameter types in it. The type parameters are
+  /// instantiated with `type_args`, or are left to be inferred.
+  fn instantiate_call_parameters<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+  ) -> (TypeScopeId, Vec<(bool, Ty<'a>)>) {
+    let scope = if callable.type_params.is_empty() {
       // Not generic
-      let params = self.get_callable_parameter_types(
-        self.type_scopes.empty_scope,
-        &ExtractedCallable::Single(callable),
-      );
-      self.exec_arguments(arguments, Some(params));
-      Some(self.resolve_ctx_ty(self.type_scopes.empty_scope, callable.return_type))
+      self.type_scopes.empty_scope
     } else if let Some(type_args) = type_args {
       // Generic, and type arguments are provided
       let type_args = self.resolve_type_parameter_instantiation(type_args);
-      let scope = self.instantiate_generic_params(&callable.type_params, &type_args);
-      let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+      self.instantiate_generic_params(&callable.type_params, &type_args)
+    } else {
+      // Generic, and need inference
+      let scope = self.type_scopes.create_scope();
+      for param in &callable.type_params {
+        self.type_scopes.insert_on_scope(
+          scope,
+          param.symbol_id,
+          Ty::Unresolved(UnresolvedType::InferType(param.symbol_id)),
+        );
+      }
+      scope
+    };
+    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+    (scope, params)
+  }
+
+  /// The parameter type of the argument at `index`. The arguments after the fixed parameters are
+  /// matched against the rest parameter elements.
+  fn get_argument_parameter(&mut self, params: &[(bool, Ty<'a>)], index: usize) -> Option<Ty<'a>> {
+    match params.get(index) {
+      Some((false, param)) => Some(*param),
+      _ => match params.last() {
+        Some((true, rest)) => Some(self.iterate_result_union(*rest)),
+        _ => None,
+      },
+    }
+  }
+
+  /// The contextual type of an argument, other than a callback, and whether its literals are kept.
+  fn get_argument_sat<const CTOR: bool>(
+    &mut self,
+    callable: &CallableType<'a, CTOR>,
+    scope: TypeScopeId,
+    param: Ty<'a>,
+    arg: &'a Argument<'a>,
+  ) -> (Option<Ty<'a>>, bool) {
+    if callable.type_params.is_empty() {
+      return (Some(param), false);
+    }
+    let sat = match param {
+      // A tuple in the constraint of a naked type parameter, like `T extends unknown[] | []`
+      Ty::Unresolved(UnresolvedType::InferType(symbol)) => callable
+        .type_params
+        .iter()
+        .find(|param| param.symbol_id == symbol)
+        .and_then(|param| param.constraint)
+        .map(|constraint| self.resolve_ctx_ty(scope, constraint)),
+      // Array literals may be typed as tuples, like the entries of `new Map([["a", 1]])`
+      _ if matches!(arg, Argument::ArrayExpression(_)) => Some(param),
+      _ => None,
+    };
+    // A constrained type parameter keeps literals, like `K` of `K extends keyof T`
+    let as_const = matches!(param, Ty::Unresolved(UnresolvedType::InferType(_)))
+      && sat.is_some()
+      && !matches!(arg, Argument::SpreadElement(_))
+      && arg.to_expression().is_literal();
+    (sat, as_const)
+  }
+
+  fn exec_call_on_single<const CTOR: bool>(
+    &mut self,
+    callable: &'a CallableType<'a, CTOR>,
+    type_args: &'a Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+    this_arg: Ty<'a>,
+    arguments: &'a allocator::Vec<'a, Argument<'a>>,
+    ret_sat: Option<Ty<'a>>,
+  ) -> Option<Ty<'a>> {
+    if callable.type_params.is_empty() || type_args.is_some() {
+      let (scope, params) = self.instantiate_call_parameters(callable, type_args);
       self.exec_arguments(arguments, Some(params));
       Some(self.resolve_ctx_ty(scope, callable.return_type))
     } else {
-      // Generic, and need inference
       self.exec_call_with_inference(callable, this_arg, arguments, ret_sat)
     }
   }
@@ -300,20 +620,12 @@ impl<'a> Analyzer<'a> {
     // # Inference
     // See https://gitnation.com/contents/lets-make-a-generic-inference-algorithm
     //
-    // - Non-context-aware arguments first (TODO)
+    // - Non-context-aware arguments first
     // - Type inferred from input type is the upper-bound (specificity < 0)
     // - Type inferred from output type is the lower-bound (specificity > 0)
     // - Choose the widest type *FROM* output type inferred from output type
 
-    let scope = self.type_scopes.create_scope();
-    for param in &callable.type_params {
-      self.type_scopes.insert_on_scope(
-        scope,
-        param.symbol_id,
-        Ty::Unresolved(UnresolvedType::InferType(param.symbol_id)),
-      );
-    }
-    let params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
+    let (scope, mut params) = self.instantiate_call_parameters(callable, &None);
 
     #[derive(Default)]
     struct InferenceState<'a> {
@@ -363,17 +675,48 @@ impl<'a> Analyzer<'a> {
       Some(())
     }
 
-    for (arg, (_, param)) in arguments.iter().zip(params.iter()) {
-      match arg {
-        Argument::SpreadElement(node) => {
-          todo!()
-        }
-        node => {
-          let arg = self.exec_expression(node.to_expression(), None);
-          let result = self.match_covariant_types(1, arg, *param);
-          handle_match_result(self, &mut inferred, result);
+    // Callbacks are executed after the other arguments, so that their parameters take the types
+    // inferred from them, like `U` of `arr.reduce(callback, initial)`
+    let is_callback = |arg: &Argument| {
+      matches!(arg, Argument::FunctionExpression(_) | Argument::ArrowFunctionExpression(_))
+    };
+    let ordered = arguments
+      .iter()
+      .enumerate()
+      .filter(|(_, arg)| !is_callback(arg))
+      .chain(arguments.iter().enumerate().filter(|(_, arg)| is_callback(arg)));
+    let mut callbacks_reached = false;
+    for (index, arg) in ordered {
+      if is_callback(arg) && !callbacks_reached {
+        callbacks_reached = true;
+        if !inferred.is_empty() {
+          for (symbol, state) in &inferred {
+            let ty = state.upmost_output.or(state.lowest_input).unwrap();
+            self.type_scopes.insert_on_scope(scope, *symbol, ty);
+          }
+          params = self.get_callable_parameter_types(scope, &ExtractedCallable::Single(callable));
         }
       }
+      let Some(param) = self.get_argument_parameter(&params, index) else {
+        continue;
+      };
+      let arg = match arg {
+        Argument::SpreadElement(_) => {
+          // Each iterated value is matched against the parameter
+          let arg = self.exec_argument(arg, None, false);
+          self.iterate_result_union(arg)
+        }
+        // Callbacks take the parameter types which are already known, like `T` of `arr.map`
+        Argument::FunctionExpression(_) | Argument::ArrowFunctionExpression(_) => {
+          self.exec_argument(arg, Some(param), false)
+        }
+        _ => {
+          let (sat, as_const) = self.get_argument_sat(callable, scope, param, arg);
+          self.exec_argument(arg, sat, as_const)
+        }
+      };
+      let result = self.match_covariant_types(1, arg, param);
+      handle_match_result(self, &mut inferred, result);
     }
 
     if let Some(ret_sat) = ret_sat {
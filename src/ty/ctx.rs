@@ -1,3 +1,5 @@
+use std::mem;
+
 use oxc::{
   allocator::{self, CloneIn},
   ast::ast::{TSType, TSTypeAnnotation},
@@ -17,9 +19,7 @@ impl<'a> PartialEq for CtxTy<'a> {
   fn eq(&self, other: &Self) -> bool {
     match (self, other) {
       (CtxTy::Static(a), CtxTy::Static(b)) => a == b,
-      (CtxTy::WithCtx(a, an), CtxTy::WithCtx(b, bn)) => {
-        a == b && (an as *const _) == (bn as *const _)
-      }
+      (CtxTy::WithCtx(a, an), CtxTy::WithCtx(b, bn)) => a == b && std::ptr::eq(*an, *bn),
       _ => false,
     }
   }
@@ -46,6 +46,8 @@ impl<'a> Analyzer<'a> {
   ) -> CtxTy<'a> {
     match (node, inferred) {
       (Some(node), _) => self.ctx_ty_from_ts_type(&node.type_annotation),
+      // Only the types referencing type parameters need to be resolved again when instantiated
+      (None, Some(ty)) if !self.contains_type_parameter(ty) => CtxTy::Static(ty),
       (None, Some(ty)) => {
         // TODO: perf
         let node = self.allocator.alloc(self.serialize_type(ty));
@@ -59,15 +61,35 @@ impl<'a> Analyzer<'a> {
     match ty {
       CtxTy::Static(ty) => ty,
       CtxTy::WithCtx(creation_scope, node) => {
-        let old_top = self.type_scopes.replace_top(creation_scope);
-        self.type_scopes.push_existing(instantiation_scope);
-        let ty = self.resolve_type(node);
-        self.type_scopes.replace_top(old_top);
-        ty
+        self.with_type_scope(creation_scope, instantiation_scope, |analyzer| {
+          analyzer.resolve_type(node)
+        })
       }
     }
   }
 
+  /// Run `f` with `instantiation_scope` on top of `creation_scope`. The semantic is switched to
+  /// the one of the file where `creation_scope` is created.
+  pub fn with_type_scope<T>(
+    &mut self,
+    creation_scope: TypeScopeId,
+    instantiation_scope: TypeScopeId,
+    f: impl FnOnce(&mut Self) -> T,
+  ) -> T {
+    let old_semantic = self
+      .type_scopes
+      .get_semantic(creation_scope)
+      .map(|semantic| mem::replace(&mut self.semantic, semantic));
+    let old_top = self.type_scopes.replace_top(creation_scope);
+    self.type_scopes.push_existing(instantiation_scope);
+    let result = f(self);
+    self.type_scopes.replace_top(old_top);
+    if let Some(old_semantic) = old_semantic {
+      self.semantic = old_semantic;
+    }
+    result
+  }
+
   pub fn serialize_ctx_ty(&mut self, ty: CtxTy<'a>) -> TSType<'a> {
     match ty {
       CtxTy::Static(ty) => self.serialize_type(ty),
@@ -1,6 +1,6 @@
 use bitflags::bitflags;
 
-use super::Ty;
+use super::{unresolved::UnresolvedType, Ty};
 use crate::analyzer::Analyzer;
 
 bitflags! {
@@ -67,26 +67,26 @@ impl<'a> Analyzer<'a> {
       Ty::Any => Facts::NONE,
       Ty::Unknown => Facts::NONE,
       Ty::Never => Facts::T_NE_ALL,
-      Ty::Void => Facts::FALSY | Facts::T_NE_ALL,
+      Ty::Void => self.get_facts(Ty::Undefined),
 
-      Ty::BigInt => Facts::T_EQ_BIGINT | Facts::T_NE_ALL & !Facts::T_EQ_BIGINT,
-      Ty::Boolean => Facts::T_EQ_BOOLEAN | Facts::T_NE_ALL & !Facts::T_EQ_BOOLEAN,
+      Ty::BigInt => Facts::T_EQ_BIGINT | Facts::T_NE_ALL & !Facts::T_NE_BIGINT,
+      Ty::Boolean => Facts::T_EQ_BOOLEAN | Facts::T_NE_ALL & !Facts::T_NE_BOOLEAN,
       Ty::Null => {
         Facts::EQ_NULL
           | Facts::IS_NULLISH
           | Facts::FALSY
           | Facts::T_EQ_OBJECT
-          | Facts::T_NE_ALL & !Facts::NE_NULL & !Facts::T_NE_OBJECT
+          | Facts::T_NE_ALL & !Facts::NE_NULL & !Facts::T_NE_OBJECT & !Facts::NOT_NULLISH
       }
-      Ty::Number => Facts::T_EQ_NUMBER | Facts::T_NE_ALL & !Facts::T_EQ_NUMBER,
-      Ty::Object => Facts::T_EQ_OBJECT | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_OBJECT,
-      Ty::String => Facts::T_EQ_STRING | Facts::T_NE_ALL & !Facts::T_EQ_STRING,
-      Ty::Symbol => Facts::T_EQ_SYMBOL | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_SYMBOL,
+      Ty::Number => Facts::T_EQ_NUMBER | Facts::T_NE_ALL & !Facts::T_NE_NUMBER,
+      Ty::Object => Facts::T_EQ_OBJECT | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_OBJECT,
+      Ty::String => Facts::T_EQ_STRING | Facts::T_NE_ALL & !Facts::T_NE_STRING,
+      Ty::Symbol => Facts::T_EQ_SYMBOL | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_SYMBOL,
       Ty::Undefined => {
         Facts::EQ_UNDEFINED
           | Facts::IS_NULLISH
           | Facts::FALSY
-          | Facts::T_NE_ALL & !Facts::NE_UNDEFINED
+          | Facts::T_NE_ALL & !Facts::NE_UNDEFINED & !Facts::NOT_NULLISH
       }
 
       Ty::StringLiteral(s) => self.get_facts(Ty::String) | Facts::truthy(s.len() > 0),
@@ -94,10 +94,22 @@ impl<'a> Analyzer<'a> {
       Ty::BigIntLiteral(_) => self.get_facts(Ty::BigInt),
       Ty::BooleanLiteral(b) => self.get_facts(Ty::Boolean) | Facts::truthy(b),
       Ty::UniqueSymbol(_) => self.get_facts(Ty::Symbol),
+      Ty::TemplateLiteral(t) => {
+        // Numbers and bigints are never empty strings
+        let non_empty = t.quasis.iter().any(|q| !q.is_empty())
+          || t.types.iter().any(|ty| matches!(ty, Ty::Number | Ty::BigInt));
+        if non_empty {
+          self.get_facts(Ty::String) | Facts::truthy(true)
+        } else {
+          self.get_facts(Ty::String)
+        }
+      }
 
-      Ty::Record(_) | Ty::Interface(_) | Ty::Tuple(_) => self.get_facts(Ty::Object),
-      Ty::Function(_) | Ty::Constructor(_) => {
-        Facts::T_EQ_FUNCTION | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_EQ_FUNCTION
+      Ty::Record(_) | Ty::Tuple(_) => self.get_facts(Ty::Object),
+      Ty::Interface(i) if i.0.borrow().callables.is_empty() => self.get_facts(Ty::Object),
+      // Interfaces with call or construct signatures are functions, like classes
+      Ty::Interface(_) | Ty::Function(_) | Ty::Constructor(_) => {
+        Facts::T_EQ_FUNCTION | Facts::TRUTHY | Facts::T_NE_ALL & !Facts::T_NE_FUNCTION
       }
 
       Ty::Union(union) => {
@@ -112,6 +124,8 @@ impl<'a> Analyzer<'a> {
       }
 
       Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Namespace(_) => Facts::NONE,
+      // Constraints of generic parameters are not tracked yet
+      Ty::Unresolved(UnresolvedType::GenericParam(_)) => Facts::NONE,
 
       Ty::Instance(_) | Ty::Unresolved(_) => {
         let lowest = self.get_lowest_type(ty);
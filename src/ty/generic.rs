@@ -1,8 +1,15 @@
 use std::cell::RefCell;
 
-use oxc::{ast::ast::TSType, semantic::SymbolId, span::Atom};
+use oxc::{
+  ast::ast::{TSInterfaceDeclaration, TSType, TSTypeName, TSTypeOperatorOperator},
+  semantic::SymbolId,
+  span::{Atom, SPAN},
+};
 
-use super::{ctx::CtxTy, intersection::IntersectionType, union::UnionType, Ty};
+use super::{
+  ctx::CtxTy, interface::InterfaceType, intersection::IntersectionType, union::UnionType,
+  unresolved::UnresolvedType, Ty,
+};
 use crate::{analyzer::Analyzer, scope::r#type::TypeScopeId};
 
 #[derive(Debug, Clone)]
@@ -15,11 +22,26 @@ pub struct GenericParam<'a> {
   pub r#const: bool,
 }
 
+#[derive(Debug, Clone)]
+pub enum GenericBody<'a> {
+  Type(CtxTy<'a>),
+  /// The declarations of a generic interface, which may be merged. Each declaration has its own
+  /// type parameter symbols.
+  Interface(RefCell<Vec<(TypeScopeId, &'a TSInterfaceDeclaration<'a>)>>),
+  /// The instance type of a generic class, whose members reference the type parameters. `scope` is
+  /// where the class is declared.
+  Class {
+    instance: &'a InterfaceType<'a>,
+    symbol_id: SymbolId,
+    scope: TypeScopeId,
+  },
+}
+
 #[derive(Debug, Clone)]
 pub struct GenericType<'a> {
   pub name: &'a Atom<'a>,
   pub params: Vec<GenericParam<'a>>,
-  pub body: CtxTy<'a>,
+  pub body: GenericBody<'a>,
 }
 
 #[derive(Debug, Clone)]
@@ -73,31 +95,150 @@ impl<'a> Analyzer<'a> {
       Ty::Intrinsic(_) => {}
       _ => return Ty::Error,
     }
-    Ty::Instance(self.allocator.alloc(GenericInstanceType {
+    // Type parameters are only meaningful in the file declaring them, so they are not shared
+    let shared = !args.iter().any(|arg| matches!(arg, Ty::Unresolved(_)));
+    if shared {
+      if let Some(instance) = self.generic_instances.get(&(generic, args.clone())) {
+        return *instance;
+      }
+    }
+    let instance = Ty::Instance(self.allocator.alloc(GenericInstanceType {
       generic,
-      args,
+      args: args.clone(),
       unwrapped: RefCell::new(None),
-    }))
+    }));
+    if shared {
+      self.generic_instances.insert((generic, args), instance);
+    }
+    instance
   }
 
-  pub fn unwrap_generic_instance(&mut self, instance: &GenericInstanceType<'a>) -> Ty<'a> {
-    *instance.unwrapped.borrow_mut().get_or_insert_with(|| {
-      match instance.generic {
-        Ty::Unresolved(_) => {
-          unreachable!("Generic itself should always be resolved when analyzing declaration")
-        }
+  pub fn unwrap_generic_instance(&mut self, instance: &'a GenericInstanceType<'a>) -> Ty<'a> {
+    if let Some(unwrapped) = *instance.unwrapped.borrow() {
+      return unwrapped;
+    }
+    let unwrapped = match instance.generic {
+      Ty::Unresolved(_) => {
+        unreachable!("Generic itself should always be resolved when analyzing declaration")
+      }
 
-        // instance.generic is a generic type
-        Ty::Generic(generic) => {
+      // instance.generic is a generic type
+      Ty::Generic(generic) => match &generic.body {
+        GenericBody::Type(body) => {
           let scope = self.instantiate_generic_params(&generic.params, &instance.args);
-          self.resolve_ctx_ty(scope, generic.body)
+          self.resolve_ctx_ty(scope, *body)
+        }
+        GenericBody::Interface(declarations) => {
+          let interface = self.allocator.alloc(InterfaceType::default());
+          // The body may reference the instance itself, like `concat(): T[]` in `Array<T>`
+          *instance.unwrapped.borrow_mut() = Some(Ty::Interface(interface));
+          for (creation_scope, node) in declarations.borrow().iter() {
+            let params = node.type_parameters.as_ref().unwrap();
+            let scope = self.type_scopes.create_scope();
+            self.type_scopes.set_this(scope, Ty::Instance(instance));
+            for (index, param) in params.params.iter().enumerate() {
+              let arg = instance.args.get(index).copied().unwrap_or(Ty::Error);
+              self.type_scopes.insert_on_scope(scope, param.name.symbol_id(), arg);
+            }
+            self.with_type_scope(*creation_scope, scope, |analyzer| {
+              analyzer.init_interface_body(node, interface)
+            });
+          }
+          Ty::Interface(interface)
+        }
+        GenericBody::Class { instance: class, scope, .. } => {
+          let is_itself = generic.params.len() == instance.args.len()
+            && generic.params.iter().zip(&instance.args).all(|(param, arg)| {
+              *arg == Ty::Unresolved(UnresolvedType::UnInitType(param.symbol_id))
+            });
+          if is_itself {
+            // Like `this` in the class body, which is the class being executed
+            Ty::Interface(class)
+          } else {
+            self.instantiate_class_instance(&generic.params, class, *scope, instance)
+          }
         }
-        Ty::Intrinsic(_) => todo!(),
+      },
+      Ty::Intrinsic(intrinsic) => {
+        let arg = instance.args.first().copied().unwrap_or(Ty::Error);
+        self.apply_intrinsic_type(intrinsic, arg)
+      }
+
+      // instance.generic is a generic value (function or constructor or compound of them)
+      _ => self.instantiate_generic_value(instance.generic, &instance.args),
+    };
+    *instance.unwrapped.borrow_mut() = Some(unwrapped);
+    unwrapped
+  }
+
+  /// Substitute the type arguments into the members of a generic class.
+  fn instantiate_class_instance(
+    &mut self,
+    params: &Vec<GenericParam<'a>>,
+    class: &'a InterfaceType<'a>,
+    creation_scope: TypeScopeId,
+    instance: &'a GenericInstanceType<'a>,
+  ) -> Ty<'a> {
+    let interface = self.allocator.alloc(InterfaceType::default());
+    // The members may reference the instance itself, like `next: Node<T>`
+    *instance.unwrapped.borrow_mut() = Some(Ty::Interface(interface));
+    let (mut record, callables) = {
+      let inner = class.0.borrow();
+      (inner.record.clone(), inner.callables.clone())
+    };
+    for property in record.values_mut() {
+      property.value =
+        self.instantiate_class_member(params, &instance.args, creation_scope, property.value);
+    }
+    let callables = callables
+      .into_iter()
+      .map(|ty| self.instantiate_class_member(params, &instance.args, creation_scope, ty))
+      .collect();
+    let mut inner = interface.0.borrow_mut();
+    inner.record = record;
+    inner.callables = callables;
+    Ty::Interface(interface)
+  }
 
-        // instance.generic is a generic value (function or constructor or compound of them)
-        _ => self.instantiate_generic_value(instance.generic, &instance.args),
+  fn instantiate_class_member(
+    &mut self,
+    params: &Vec<GenericParam<'a>>,
+    args: &Vec<Ty<'a>>,
+    creation_scope: TypeScopeId,
+    ty: Ty<'a>,
+  ) -> Ty<'a> {
+    match ty {
+      // Methods are resolved lazily, in a scope where the type parameters are bound
+      Ty::Function(f) => {
+        let scope = self.instantiate_generic_params(params, args);
+        Ty::Function(self.rescope_callable(f, scope, f.type_params.clone()))
       }
-    })
+      Ty::Constructor(c) => {
+        let scope = self.instantiate_generic_params(params, args);
+        Ty::Constructor(self.rescope_callable(c, scope, c.type_params.clone()))
+      }
+      // Overloads of a method
+      Ty::Intersection(intersection) => {
+        let object_like = intersection
+          .object_like
+          .iter()
+          .map(|ty| self.instantiate_class_member(params, args, creation_scope, *ty))
+          .collect();
+        Ty::Intersection(self.allocator.alloc(Box::new(IntersectionType {
+          kind: intersection.kind,
+          object_like,
+          unresolved: intersection.unresolved.clone(),
+        })))
+      }
+      ty if self.contains_type_parameter(ty) => {
+        let scope = self.instantiate_generic_params(params, args);
+        self.with_type_scope(creation_scope, scope, |analyzer| {
+          let node = analyzer.allocator.alloc(analyzer.serialize_type(ty));
+          analyzer.resolve_type(node)
+        })
+      }
+      ty => ty,
+    }
   }
 
   // pub fn instantiate_generic_type(&mut self, instance: &GenericInstanceType<'a>) -> Ty<'a> {
@@ -164,11 +305,89 @@ impl<'a> Analyzer<'a> {
       .unwrap_or_else(|| Ty::Record(self.allocator.alloc(Default::default())))
   }
 
-  pub fn serialize_instance_type(&mut self, instance: &GenericInstanceType<'a>) -> TSType<'a> {
+  pub fn serialize_instance_type(&mut self, instance: &'a GenericInstanceType<'a>) -> TSType<'a> {
+    // Only generic interfaces are printed by name, while generic type aliases are expanded
+    if let (Ty::Generic(GenericType { body: GenericBody::Interface(_), .. }), Some(name)) =
+      (instance.generic, self.builtins.global_type_names.get(&instance.generic).copied())
+    {
+      return match (name, instance.args.as_slice()) {
+        ("Array", [element]) => {
+          let element = self.serialize_array_element_type(*element);
+          self.ast_builder.ts_type_array_type(SPAN, element)
+        }
+        ("ReadonlyArray", [element]) => {
+          let element = self.serialize_array_element_type(*element);
+          self.ast_builder.ts_type_type_operator(
+            SPAN,
+            TSTypeOperatorOperator::Readonly,
+            self.ast_builder.ts_type_array_type(SPAN, element),
+          )
+        }
+        _ => {
+          let mut params = self.ast_builder.vec();
+          for arg in &instance.args {
+            params.push(self.serialize_type(*arg));
+          }
+          let params = self.ast_builder.ts_type_parameter_instantiation(SPAN, params);
+          self.serialize_global_type_reference(name, Some(self.ast_builder.alloc(params)))
+        }
+      };
+    }
+
+    // Generic classes are printed by name, and resolved by the reference to the class if possible
+    if let Ty::Generic(GenericType {
+      name,
+      body: GenericBody::Class { symbol_id, scope, .. },
+      ..
+    }) = instance.generic
+    {
+      let mut params = self.ast_builder.vec();
+      for arg in &instance.args {
+        params.push(self.serialize_type(*arg));
+      }
+      let params =
+        self.ast_builder.alloc(self.ast_builder.ts_type_parameter_instantiation(SPAN, params));
+      let is_same_file = self
+        .type_scopes
+        .get_semantic(*scope)
+        .is_some_and(|semantic| std::ptr::eq(semantic, self.semantic));
+      if is_same_file && !self.semantic.symbols().get_resolved_reference_ids(*symbol_id).is_empty()
+      {
+        let name = self.ast_builder.alloc(self.serialize_identifier_reference(*symbol_id));
+        return self.ast_builder.ts_type_type_reference(
+          SPAN,
+          TSTypeName::IdentifierReference(name),
+          Some(params),
+        );
+      }
+      return self.serialize_global_type_reference(name.as_str(), Some(params));
+    }
+
+    // Intrinsic types are deferred for type parameters, like `Uppercase<T>`
+    if let (Ty::Intrinsic(intrinsic), [arg]) = (instance.generic, instance.args.as_slice()) {
+      if self.is_type_parameter_dependent(*arg) {
+        return self.serialize_intrinsic_instance(intrinsic, *arg);
+      }
+    }
+
     let unwrapped = self.unwrap_generic_instance(instance);
     self.serialize_type(unwrapped)
   }
 
+  /// Compound element types are parenthesized, like `(string | number)[]`.
+  fn serialize_array_element_type(&mut self, element: Ty<'a>) -> TSType<'a> {
+    let node = self.serialize_type(element);
+    match node {
+      TSType::TSUnionType(_)
+      | TSType::TSIntersectionType(_)
+      | TSType::TSFunctionType(_)
+      | TSType::TSConstructorType(_)
+      | TSType::TSConditionalType(_)
+      | TSType::TSTypeOperatorType(_) => self.ast_builder.ts_type_parenthesized_type(SPAN, node),
+      node => node,
+    }
+  }
+
   pub fn serialize_generic_type(&mut self, generic: &GenericType<'a>) -> TSType<'a> {
     todo!()
   }
@@ -1,4 +1,6 @@
-use super::{property_key::PropertyKeyType, Ty};
+use oxc_syntax::number::ToJsString;
+
+use super::{property_key::PropertyKeyType, unresolved::UnresolvedType, Ty};
 use crate::Analyzer;
 
 impl<'a> Analyzer<'a> {
@@ -14,7 +16,9 @@ impl<'a> Analyzer<'a> {
       Ty::BigInt | Ty::BigIntLiteral(_) => self.get_property(self.builtins.bigint_prototype, key),
       Ty::Number | Ty::NumericLiteral(_) => self.get_property(self.builtins.number_prototype, key),
       Ty::Object => self.get_property(self.builtins.object_prototype, key),
-      Ty::String | Ty::StringLiteral(_) => self.get_property(self.builtins.string_prototype, key),
+      Ty::String | Ty::StringLiteral(_) | Ty::TemplateLiteral(_) => {
+        self.get_property(self.builtins.string_prototype, key)
+      }
       Ty::Symbol | Ty::UniqueSymbol(_) => self.get_property(self.builtins.symbol_prototype, key),
       Ty::Function(_) | Ty::Constructor(_) => {
         self.get_property(self.builtins.function_prototype, key)
@@ -25,7 +29,7 @@ impl<'a> Analyzer<'a> {
       Ty::Tuple(t) => t.get_property(key, self),
 
       Ty::Union(u) => self.get_union_property(u, key),
-      Ty::Intersection(_) => todo!(),
+      Ty::Intersection(i) => self.get_intersection_property(i, key),
 
       Ty::Instance(i) => {
         let unwrapped = self.unwrap_generic_instance(i);
@@ -33,7 +37,7 @@ impl<'a> Analyzer<'a> {
       }
 
       Ty::Generic(_) | Ty::Intrinsic(_) => Ty::Error,
-      Ty::Namespace(_) => todo!(),
+      Ty::Namespace(n) => n.get_property(key),
 
       Ty::Unresolved(_) => {
         let lowest = self.get_lowest_type(target);
@@ -41,4 +45,63 @@ impl<'a> Analyzer<'a> {
       }
     }
   }
+
+  /// Whether reading `key` from `target` is resolved by an index signature, where the property
+  /// may be absent.
+  pub fn is_index_signature_access(&mut self, target: Ty<'a>, key: PropertyKeyType<'a>) -> bool {
+    match target {
+      Ty::Record(_) | Ty::Interface(_) | Ty::Intersection(_) => {
+        let Some(record) = self.get_object_record(target) else {
+          return false;
+        };
+        match key {
+          PropertyKeyType::AnyString | PropertyKeyType::AnyNumber | PropertyKeyType::AnySymbol => {
+            true
+          }
+          PropertyKeyType::StringLiteral(s) => !record.string_keyed.0.contains_key(s.as_str()),
+          PropertyKeyType::NumericLiteral(n) => {
+            !record.string_keyed.0.contains_key(n.0.to_js_string().as_str())
+          }
+          PropertyKeyType::UniqueSymbol(s) => !record.symbol_keyed.0.contains_key(&s),
+          PropertyKeyType::Error => false,
+        }
+      }
+      Ty::Tuple(_) => matches!(key, PropertyKeyType::AnyNumber),
+      Ty::Instance(instance) => {
+        let unwrapped = self.unwrap_generic_instance(instance);
+        self.is_index_signature_access(unwrapped, key)
+      }
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        members.into_iter().any(|ty| self.is_index_signature_access(ty, key))
+      }
+      _ => false,
+    }
+  }
+
+  /// The indexed access type `object[index]`, which is distributive over the members of `index`.
+  pub fn get_indexed_access_type(&mut self, object: Ty<'a>, index: Ty<'a>) -> Ty<'a> {
+    if self.is_type_parameter_dependent(object) || self.is_type_parameter_dependent(index) {
+      return Ty::Unresolved(UnresolvedType::IndexedAccess(
+        self.allocator.alloc(object),
+        self.allocator.alloc(index),
+      ));
+    }
+    match index {
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types = members
+          .into_iter()
+          .map(|member| self.get_indexed_access_type(object, member))
+          .collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ => {
+        let key = self.to_property_key(index);
+        self.get_property(object, key)
+      }
+    }
+  }
 }
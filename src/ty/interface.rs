@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 
-use oxc::ast::ast::TSType;
+use oxc::{ast::ast::TSType, span::SPAN};
 
 use crate::Analyzer;
 
@@ -19,7 +19,7 @@ pub struct InterfaceType<'a>(pub RefCell<InterfaceTypeInner<'a>>);
 impl<'a> InterfaceTypeInner<'a> {
   pub fn extend(&mut self, ty: Ty<'a>) {
     match ty {
-      Ty::Record(r) => {}
+      Ty::Record(r) => self.record.extend(r.clone()),
       Ty::Constructor(_) | Ty::Function(_) => self.callables.push(ty.clone()),
       Ty::Interface(i) => {
         let i = i.0.borrow();
@@ -57,6 +57,21 @@ impl<'a> InterfaceType<'a> {
 
 impl<'a> Analyzer<'a> {
   pub fn serialize_interface_type(&mut self, interface: &InterfaceType<'a>) -> TSType<'a> {
-    todo!()
+    let (record, callables) = {
+      let inner = interface.0.borrow();
+      (inner.record.clone(), inner.callables.clone())
+    };
+    let mut types = self.ast_builder.vec();
+    if !record.is_empty() || callables.is_empty() {
+      types.push(self.serialize_record_type(&record));
+    }
+    for callable in callables {
+      types.push(self.serialize_type(callable));
+    }
+    if types.len() == 1 {
+      types.pop().unwrap()
+    } else {
+      self.ast_builder.ts_type_intersection_type(SPAN, types)
+    }
   }
 }
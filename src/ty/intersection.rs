@@ -5,7 +5,7 @@ use oxc::{
   span::{Atom, SPAN},
 };
 
-use super::{unresolved::UnresolvedType, Ty};
+use super::{property_key::PropertyKeyType, unresolved::UnresolvedType, Ty};
 use crate::{analyzer::Analyzer, utils::F64WithEq};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -95,7 +95,7 @@ impl<'a> IntersectionTypeBuilder<'a> {
         Ty::Null => IntersectionBuilderState::Null,
         Ty::Number => IntersectionBuilderState::Number(None),
         Ty::Object => IntersectionBuilderState::ObjectKeyword,
-        Ty::String => IntersectionBuilderState::String(None),
+        Ty::String | Ty::TemplateLiteral(_) => IntersectionBuilderState::String(None),
         Ty::Symbol => IntersectionBuilderState::Symbol(None),
         Ty::Undefined => IntersectionBuilderState::Undefined,
 
@@ -151,9 +151,20 @@ impl<'a> IntersectionTypeBuilder<'a> {
   fn build_without_union(
     allocator: &'a Allocator,
     kind: IntersectionBuilderState<'a>,
-    object_like: Vec<Ty<'a>>,
+    mut object_like: Vec<Ty<'a>>,
     unresolved: Vec<UnresolvedType<'a>>,
   ) -> Ty<'a> {
+    // `{}` only excludes `null` and `undefined`, like `T & {}` in `NonNullable<T>`
+    let is_empty_object = |ty: &Ty<'a>| match ty {
+      Ty::Record(record) => record.is_empty(),
+      Ty::Interface(interface) => interface.is_empty(),
+      _ => false,
+    };
+    if kind == IntersectionBuilderState::ObjectLike && object_like.iter().all(is_empty_object) {
+      object_like.truncate(1);
+    } else {
+      object_like.retain(|ty| !is_empty_object(ty));
+    }
     let primitive_only = object_like.is_empty();
     let kind = match kind {
       // Ignore complex types
@@ -310,6 +321,28 @@ impl<'a> Analyzer<'a> {
     }
   }
 
+  /// The property exists if any member has it.
+  pub fn get_intersection_property(
+    &mut self,
+    intersection: &IntersectionType<'a>,
+    key: PropertyKeyType<'a>,
+  ) -> Ty<'a> {
+    let mut members = vec![];
+    intersection.for_each(|ty| members.push(ty));
+    let mut properties = vec![];
+    for ty in members {
+      let property = self.get_property(ty, key);
+      if !matches!(property, Ty::Error) {
+        properties.push(property);
+      }
+    }
+    if properties.is_empty() {
+      Ty::Error
+    } else {
+      self.into_intersection(properties)
+    }
+  }
+
   pub fn serialize_intersection_type(&mut self, intersection: &IntersectionType<'a>) -> TSType<'a> {
     let mut types = self.ast_builder.vec();
     intersection.for_each(|ty| types.push(self.serialize_type(ty)));
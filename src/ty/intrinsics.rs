@@ -1,15 +1,118 @@
-use oxc::ast::ast::TSType;
+use oxc::{
+  ast::ast::TSType,
+  span::{Atom, SPAN},
+};
 
+use super::Ty;
 use crate::analyzer::Analyzer;
 
+/// The types declared as `type Uppercase<S extends string> = intrinsic`.
 #[derive(Debug, Clone, Copy)]
-pub struct IntrinsicType {
-  name: &'static str,
-  handler: fn(&str) -> String,
+pub enum IntrinsicType {
+  /// The string manipulation types, like `Uppercase`
+  StringMapping { name: &'static str, handler: fn(&str) -> String },
+  /// `Awaited`, which is a recursive conditional type in tsc's lib
+  Awaited,
+  /// `NoInfer`, which only blocks inference and is the type argument itself otherwise
+  NoInfer,
+}
+
+impl IntrinsicType {
+  pub fn from_name(name: &str) -> Option<Self> {
+    let (name, handler): (_, fn(&str) -> String) = match name {
+      "Uppercase" => ("Uppercase", |s| s.to_uppercase()),
+      "Lowercase" => ("Lowercase", |s| s.to_lowercase()),
+      "Capitalize" => ("Capitalize", |s| map_first_char(s, char::to_uppercase)),
+      "Uncapitalize" => ("Uncapitalize", |s| map_first_char(s, char::to_lowercase)),
+      "Awaited" => return Some(Self::Awaited),
+      "NoInfer" => return Some(Self::NoInfer),
+      _ => return None,
+    };
+    Some(Self::StringMapping { name, handler })
+  }
+
+  fn name(&self) -> &'static str {
+    match self {
+      Self::StringMapping { name, .. } => name,
+      Self::Awaited => "Awaited",
+      Self::NoInfer => "NoInfer",
+    }
+  }
+
+  /// Whether only the first character is mapped.
+  fn is_first_char_only(&self) -> bool {
+    matches!(self.name(), "Capitalize" | "Uncapitalize")
+  }
+}
+
+fn map_first_char<I: Iterator<Item = char>>(s: &str, f: fn(char) -> I) -> String {
+  let mut chars = s.chars();
+  match chars.next() {
+    Some(first) => f(first).chain(chars).collect(),
+    None => String::new(),
+  }
 }
 
 impl<'a> Analyzer<'a> {
+  /// Apply the intrinsic type to `arg`, which is distributive over union members.
+  pub fn apply_intrinsic_type(&mut self, intrinsic: &IntrinsicType, arg: Ty<'a>) -> Ty<'a> {
+    let handler = match intrinsic {
+      IntrinsicType::StringMapping { handler, .. } => *handler,
+      IntrinsicType::Awaited => return self.get_to_awaited(arg),
+      IntrinsicType::NoInfer => return arg,
+    };
+    match arg {
+      Ty::StringLiteral(s) => {
+        let mapped = self.allocator.alloc_str(&handler(s));
+        Ty::StringLiteral(self.allocator.alloc(Atom::from(&*mapped)))
+      }
+      Ty::TemplateLiteral(template) => {
+        // The holes are kept as is
+        let quasis = template
+          .quasis
+          .iter()
+          .enumerate()
+          .map(|(index, quasi)| {
+            if intrinsic.is_first_char_only() && index > 0 {
+              *quasi
+            } else {
+              &*self.allocator.alloc_str(&handler(quasi))
+            }
+          })
+          .collect::<Vec<_>>();
+        self.create_template_literal_type(&quasis, &template.types)
+      }
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types = members
+          .into_iter()
+          .map(|ty| self.apply_intrinsic_type(intrinsic, ty))
+          .collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      Ty::Instance(instance) => {
+        let unwrapped = self.unwrap_generic_instance(instance);
+        self.apply_intrinsic_type(intrinsic, unwrapped)
+      }
+      Ty::String | Ty::Any | Ty::Never | Ty::Error => arg,
+      // The lowest type of a type parameter dependent argument
+      Ty::Unresolved(_) => Ty::String,
+      _ => Ty::Error,
+    }
+  }
+
   pub fn serialize_intrinsic_type(&mut self, intrinsic: &IntrinsicType) -> TSType<'a> {
-    todo!()
+    self.serialize_global_type_reference(intrinsic.name(), None)
+  }
+
+  pub fn serialize_intrinsic_instance(
+    &mut self,
+    intrinsic: &IntrinsicType,
+    arg: Ty<'a>,
+  ) -> TSType<'a> {
+    let arg = self.serialize_type(arg);
+    let params = self.ast_builder.ts_type_parameter_instantiation(SPAN, self.ast_builder.vec1(arg));
+    self.serialize_global_type_reference(intrinsic.name(), Some(self.ast_builder.alloc(params)))
   }
 }
@@ -0,0 +1,133 @@
+use oxc::span::Atom;
+
+use super::{record::RecordType, unresolved::UnresolvedType, Ty};
+use crate::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// The union of the keys of `ty`, which is deferred when `ty` depends on a type parameter.
+  pub fn get_keyof_type(&mut self, ty: Ty<'a>) -> Ty<'a> {
+    if self.is_type_parameter_dependent(ty) {
+      return Ty::Unresolved(UnresolvedType::Keyof(self.allocator.alloc(ty)));
+    }
+    match ty {
+      Ty::Error => Ty::Error,
+      Ty::Any | Ty::Never => self.into_union([Ty::String, Ty::Number, Ty::Symbol]).unwrap(),
+      Ty::Unknown | Ty::Void | Ty::Null | Ty::Undefined | Ty::Object => Ty::Never,
+      Ty::Function(_) | Ty::Constructor(_) => Ty::Never,
+
+      Ty::Boolean | Ty::BooleanLiteral(_) => self.get_keyof_type(self.builtins.boolean_prototype),
+      Ty::BigInt | Ty::BigIntLiteral(_) => self.get_keyof_type(self.builtins.bigint_prototype),
+      Ty::Number | Ty::NumericLiteral(_) => self.get_keyof_type(self.builtins.number_prototype),
+      Ty::String | Ty::StringLiteral(_) | Ty::TemplateLiteral(_) => {
+        self.get_keyof_type(self.builtins.string_prototype)
+      }
+      Ty::Symbol | Ty::UniqueSymbol(_) => self.get_keyof_type(self.builtins.symbol_prototype),
+
+      Ty::Record(record) => self.get_record_keys(record),
+      Ty::Interface(interface) => {
+        let record = interface.0.borrow().record.clone();
+        self.get_record_keys(&record)
+      }
+      Ty::Tuple(tuple) => {
+        // The element indices, and the keys of the array type
+        let mut keys = vec![];
+        let mut element_types = vec![];
+        for (index, element) in tuple.elements.iter().enumerate() {
+          if element.spread {
+            element_types.push(self.iterate_result_union(element.ty));
+          } else {
+            keys.push(Ty::StringLiteral(
+              self.allocator.alloc(Atom::from(&*self.allocator.alloc_str(&index.to_string()))),
+            ));
+            element_types.push(element.ty);
+          }
+        }
+        let element = self.into_union(element_types).unwrap_or(Ty::Never);
+        let array = self.create_array_type(element, tuple.readonly);
+        keys.push(self.get_keyof_type(array));
+        self.into_union(keys).unwrap_or(Ty::Never)
+      }
+
+      // Only the keys present in all the members
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let key_sets = members
+          .into_iter()
+          .map(|member| {
+            let keys = self.get_keyof_type(member);
+            let mut set = vec![];
+            match keys {
+              Ty::Union(union) => union.for_each(|ty| set.push(ty)),
+              Ty::Never => {}
+              keys => set.push(keys),
+            }
+            set
+          })
+          .collect::<Vec<_>>();
+        let mut keys = vec![];
+        for set in &key_sets {
+          for key in set {
+            if key_sets.iter().all(|other| is_key_covered(*key, other)) {
+              keys.push(*key);
+            }
+          }
+        }
+        self.into_union(keys).unwrap_or(Ty::Never)
+      }
+      // The keys present in any of the members
+      Ty::Intersection(intersection) => {
+        let mut members = vec![];
+        intersection.for_each(|ty| members.push(ty));
+        let keys =
+          members.into_iter().map(|member| self.get_keyof_type(member)).collect::<Vec<_>>();
+        self.into_union(keys).unwrap_or(Ty::Never)
+      }
+
+      Ty::Instance(_) | Ty::Unresolved(_) => {
+        let lowest = self.get_lowest_type(ty);
+        if lowest == ty {
+          Ty::Never
+        } else {
+          self.get_keyof_type(lowest)
+        }
+      }
+
+      Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Namespace(_) => Ty::Error,
+    }
+  }
+
+  fn get_record_keys(&mut self, record: &RecordType<'a>) -> Ty<'a> {
+    let mut keys = vec![];
+    for key in record.string_keyed.0.keys() {
+      keys.push(Ty::StringLiteral(self.allocator.alloc(Atom::from(*key))));
+    }
+    for key in record.symbol_keyed.0.keys() {
+      keys.push(Ty::UniqueSymbol(*key));
+    }
+    // A string index signature also accepts numeric keys
+    if record.string_mapped.is_some() {
+      keys.push(Ty::String);
+      keys.push(Ty::Number);
+    } else if record.number_mapped.is_some() {
+      keys.push(Ty::Number);
+    }
+    if record.symbol_mapped.is_some() {
+      keys.push(Ty::Symbol);
+    }
+    self.into_union(keys).unwrap_or(Ty::Never)
+  }
+}
+
+/// Whether `key` is one of `keys`, or accepted by an index signature key type in `keys`.
+fn is_key_covered<'a>(key: Ty<'a>, keys: &[Ty<'a>]) -> bool {
+  keys.iter().any(|k| {
+    *k == key
+      || matches!(
+        (key, k),
+        (Ty::StringLiteral(_) | Ty::Number | Ty::NumericLiteral(_), Ty::String)
+          | (Ty::NumericLiteral(_), Ty::Number)
+          | (Ty::UniqueSymbol(_), Ty::Symbol)
+      )
+  })
+}
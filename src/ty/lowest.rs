@@ -1,3 +1,5 @@
+use oxc::semantic::SymbolId;
+
 use crate::Analyzer;
 
 use super::{unresolved::UnresolvedType, Ty};
@@ -8,32 +10,43 @@ impl<'a> Analyzer<'a> {
   pub fn get_lowest_type(&mut self, ty: Ty<'a>) -> Ty<'a> {
     match ty {
       Ty::Instance(i) => self.unwrap_generic_instance(i),
-      Ty::Generic(g) => todo!(),
-      Ty::Intrinsic(i) => todo!(),
-      Ty::Namespace(_) => Ty::Error,
+      Ty::Generic(_) | Ty::Intrinsic(_) | Ty::Namespace(_) => Ty::Error,
 
       Ty::Unresolved(unresolved) => match unresolved {
         UnresolvedType::UnInitVariable(_) => Ty::Unknown,
         UnresolvedType::UnInitType(symbol) => match self.type_scopes.search(symbol) {
-          Ty::Unresolved(UnresolvedType::UnInitType(s)) if s == symbol => Ty::Unknown,
+          Ty::Unresolved(UnresolvedType::UnInitType(s)) if s == symbol => {
+            self.get_type_parameter_constraint(symbol)
+          }
           ty => ty,
         },
-        UnresolvedType::GenericParam(symbol) => {
-          // if let Some(constraint) =
-          //   self.type_scopes.get_on_scope(self.type_scopes.generic_constraints, symbol)
-          // {
-          //   self.resolve_ctx_ty(self.type_scopes.generic_constraints, *constraint)
-          // } else {
-          //   Ty::Unknown
-          // }
-          todo!()
+        UnresolvedType::GenericParam(symbol) => self.get_type_parameter_constraint(symbol),
+        UnresolvedType::Keyof(ty) => {
+          let ty = self.get_lowest_type(*ty);
+          self.get_keyof_type(ty)
+        }
+        UnresolvedType::IndexedAccess(object, index) => {
+          let object = self.get_lowest_type(*object);
+          let index = self.get_lowest_type(*index);
+          self.get_indexed_access_type(object, index)
+        }
+        UnresolvedType::Awaited(ty) => {
+          let ty = self.get_lowest_type(*ty);
+          self.get_to_awaited(ty)
         }
-        UnresolvedType::Keyof(_) => Ty::String,
-        UnresolvedType::InferType(_) => Ty::Unknown,
-        UnresolvedType::Placeholder(_) => unreachable!(),
+        UnresolvedType::InferType(_) | UnresolvedType::Placeholder(_) => Ty::Unknown,
       },
 
       ty => ty,
     }
   }
+
+  /// An uninstantiated type parameter is at least its constraint, like `string` for
+  /// `T extends string`.
+  fn get_type_parameter_constraint(&mut self, symbol: SymbolId) -> Ty<'a> {
+    match self.generic_constraints.get(&symbol).copied() {
+      Some(constraint) => self.resolve_ctx_ty(self.type_scopes.empty_scope, constraint),
+      None => Ty::Unknown,
+    }
+  }
 }
@@ -1,9 +1,16 @@
 use std::{collections::hash_map::Entry, hash::Hash};
 
 use oxc::semantic::SymbolId;
+use oxc_syntax::number::ToJsString;
 use rustc_hash::FxHashMap;
 
-use super::{callable::CallableType, record::KeyedPropertyMap, unresolved::UnresolvedType, Ty};
+use super::{
+  callable::CallableType,
+  record::{KeyedPropertyMap, RecordType},
+  tuple::{TupleElement, TupleType},
+  unresolved::UnresolvedType,
+  Ty,
+};
 use crate::Analyzer;
 
 pub enum MatchResult<'a> {
@@ -52,6 +59,26 @@ impl<'a> Analyzer<'a> {
     specificity: i32,
     target: Ty<'a>,
     pattern: Ty<'a>,
+  ) -> MatchResult<'a> {
+    let structural = |ty| matches!(ty, Ty::Instance(_) | Ty::Interface(_));
+    if !structural(target) && !structural(pattern) {
+      return self.match_covariant_types_impl(specificity, target, pattern);
+    }
+    // Like `maybeKeys` in tsc, a pair is assumed to match when it is encountered again while
+    // matching itself, like `Array<T>` against `ReadonlyArray<U>` via their methods.
+    if !self.match_assumptions.insert((target, pattern)) {
+      return MatchResult::Matched;
+    }
+    let result = self.match_covariant_types_impl(specificity, target, pattern);
+    self.match_assumptions.remove(&(target, pattern));
+    result
+  }
+
+  fn match_covariant_types_impl(
+    &mut self,
+    specificity: i32,
+    target: Ty<'a>,
+    pattern: Ty<'a>,
   ) -> MatchResult<'a> {
     match (target, pattern) {
       (Ty::Generic(_) | Ty::Intrinsic(_), _) => MatchResult::Error,
@@ -76,9 +103,12 @@ impl<'a> Analyzer<'a> {
         (UnresolvedType::Placeholder(_), _) | (_, UnresolvedType::Placeholder(_)) => {
           MatchResult::Unmatched
         }
-        _ => todo!(),
+        // Deferred types, like a conditional type over an uninstantiated type parameter
+        _ => MatchResult::Error,
       },
-      (Ty::Unresolved(_), _) | (_, Ty::Unresolved(_)) => todo!(),
+      (Ty::Unresolved(UnresolvedType::Placeholder(_)), _)
+      | (_, Ty::Unresolved(UnresolvedType::Placeholder(_))) => MatchResult::Unmatched,
+      (Ty::Unresolved(_), _) | (_, Ty::Unresolved(_)) => MatchResult::Error,
 
       (Ty::Union(target), pattern) => {
         let mut error = false;
@@ -203,6 +233,20 @@ impl<'a> Analyzer<'a> {
         let pattern = self.unwrap_generic_instance(pattern);
         self.match_covariant_types(specificity, target, pattern)
       }
+      (Ty::Tuple(target), Ty::Instance(pattern))
+        if matches!(
+          self.builtins.global_type_names.get(&pattern.generic),
+          Some(&"Array" | &"ReadonlyArray")
+        ) =>
+      {
+        let readonly =
+          self.builtins.global_type_names.get(&pattern.generic) == Some(&"ReadonlyArray");
+        if target.readonly && !readonly {
+          return MatchResult::Unmatched;
+        }
+        let element = target.iterate_result_union(self);
+        self.match_covariant_types(specificity + 1, element, pattern.args[0])
+      }
       (Ty::Instance(target), pattern) => {
         let target = self.unwrap_generic_instance(target);
         self.match_covariant_types(specificity, target, pattern)
@@ -212,6 +256,15 @@ impl<'a> Analyzer<'a> {
         self.match_covariant_types(specificity, target, pattern)
       }
 
+      (Ty::Interface(target), pattern @ (Ty::Interface(_) | Ty::Record(_))) => {
+        let target = Ty::Record(self.allocator.alloc(target.0.borrow().record.clone()));
+        self.match_covariant_types(specificity, target, pattern)
+      }
+      (target @ Ty::Record(_), Ty::Interface(pattern)) => {
+        let pattern = Ty::Record(self.allocator.alloc(pattern.0.borrow().record.clone()));
+        self.match_covariant_types(specificity, target, pattern)
+      }
+
       (Ty::Record(target), Ty::Record(pattern)) => {
         let mut builder = BuilderBySpecificity::default();
 
@@ -228,7 +281,7 @@ impl<'a> Analyzer<'a> {
           &pattern.symbol_keyed,
         );
 
-        // TODO: Check mapped properties
+        self.match_record_mapped_properties(&mut builder, specificity + 1, target, pattern);
 
         builder.into_result()
       }
@@ -236,12 +289,33 @@ impl<'a> Analyzer<'a> {
       (Ty::Record(_), Ty::Object) => MatchResult::Matched,
       (_, Ty::Record(_)) | (Ty::Record(_), _) => MatchResult::Unmatched,
 
-      (Ty::Interface(target), Ty::Interface(pattern)) => todo!(),
+      (Ty::Interface(target), pattern @ (Ty::Function(_) | Ty::Constructor(_))) => {
+        // Like tsc, only the last signature of the same kind is matched
+        let callable = target
+          .0
+          .borrow()
+          .callables
+          .iter()
+          .rev()
+          .find(|callable| {
+            matches!(
+              (callable, pattern),
+              (Ty::Function(_), Ty::Function(_)) | (Ty::Constructor(_), Ty::Constructor(_))
+            )
+          })
+          .copied();
+        match callable {
+          Some(target) => self.match_covariant_types(specificity, target, pattern),
+          None => MatchResult::Unmatched,
+        }
+      }
       (Ty::Object, Ty::Interface(pattern)) => MatchResult::from(pattern.is_empty()),
       (Ty::Interface(_), Ty::Object) => MatchResult::Matched,
       (_, Ty::Interface(_)) | (Ty::Interface(_), _) => MatchResult::Unmatched,
 
-      (Ty::Tuple(target), Ty::Tuple(pattern)) => todo!(),
+      (Ty::Tuple(target), Ty::Tuple(pattern)) => {
+        self.match_tuple_types(specificity, target, pattern)
+      }
       (_, Ty::Tuple(_)) | (Ty::Tuple(_), _) => MatchResult::Unmatched,
 
       (Ty::Function(target), Ty::Function(pattern)) => {
@@ -256,6 +330,12 @@ impl<'a> Analyzer<'a> {
       (Ty::Constructor(_), Ty::Object) => MatchResult::Matched,
       (Ty::Constructor(_), _) | (_, Ty::Constructor(_)) => MatchResult::Unmatched,
 
+      (Ty::StringLiteral(target), Ty::TemplateLiteral(pattern)) => {
+        self.match_template_literal(specificity, target, pattern)
+      }
+      (Ty::TemplateLiteral(_), pattern) => MatchResult::from(pattern == Ty::String),
+      (_, Ty::TemplateLiteral(_)) => MatchResult::Unmatched,
+
       (Ty::Undefined, Ty::Void) => MatchResult::Matched,
       (Ty::Undefined, _) => MatchResult::Unmatched,
       (_, Ty::Void) => MatchResult::Unmatched,
@@ -324,20 +404,19 @@ impl<'a> Analyzer<'a> {
     let target_scope = self.type_scopes.create_scope();
     let pattern_scope = self.type_scopes.create_scope();
     for (target, pattern) in target.type_params.iter().zip(pattern.type_params.iter()) {
-      if target.constraint == pattern.constraint {
-        continue;
-      }
-      // Note: Contravariance - `pattern.constraint extends target.constraint`
-      let target_ty =
-        target.constraint.map_or(Ty::Unknown, |ty| self.resolve_ctx_ty(target_scope, ty));
-      let pattern_ty =
-        pattern.constraint.map_or(Ty::Unknown, |ty| self.resolve_ctx_ty(pattern_scope, ty));
-      match self.match_contravariant_types(specificity, target_ty, pattern_ty) {
-        MatchResult::Error => return MatchResult::Error,
-        MatchResult::Unmatched => return MatchResult::Unmatched,
-        MatchResult::Matched => {}
-        MatchResult::Inferred(_) => {
-          // Somehow in TypeScript this is ignored
+      if target.constraint != pattern.constraint {
+        // Note: Contravariance - `pattern.constraint extends target.constraint`
+        let target_ty =
+          target.constraint.map_or(Ty::Unknown, |ty| self.resolve_ctx_ty(target_scope, ty));
+        let pattern_ty =
+          pattern.constraint.map_or(Ty::Unknown, |ty| self.resolve_ctx_ty(pattern_scope, ty));
+        match self.match_contravariant_types(specificity, target_ty, pattern_ty) {
+          MatchResult::Error => return MatchResult::Error,
+          MatchResult::Unmatched => return MatchResult::Unmatched,
+          MatchResult::Matched => {}
+          MatchResult::Inferred(_) => {
+            // Somehow in TypeScript this is ignored
+          }
         }
       }
 
@@ -376,16 +455,33 @@ impl<'a> Analyzer<'a> {
           MatchResult::Matched => {}
           MatchResult::Inferred(map) => inferred.extend(map),
         }
-      } else if *target_optional {
-        // Optional parameter, matched
+      } else if *target_optional || pattern.rest_param.is_some() {
+        // Optional parameter, or matched by the rest parameter below
       } else {
-        // TODO: Check rest parameter
         return MatchResult::Unmatched;
       }
     }
 
-    // Step4: Match rest parameter
-    // TODO: Check rest parameter
+    // Step4: Match rest parameter, against the remaining parameters as a tuple
+    if let Some(pattern_rest) = pattern.rest_param {
+      let mut elements = vec![];
+      for (optional, ty) in target.params.iter().skip(pattern.params.len()) {
+        let ty = self.resolve_ctx_ty(target_scope, *ty);
+        elements.push(TupleElement { name: None, spread: false, optional: *optional, ty });
+      }
+      if let Some(target_rest) = target.rest_param {
+        let ty = self.resolve_ctx_ty(target_scope, target_rest);
+        elements.push(TupleElement { name: None, spread: true, optional: false, ty });
+      }
+      let target_ty = Ty::Tuple(self.allocator.alloc(TupleType { elements, readonly: false }));
+      let pattern_ty = self.resolve_ctx_ty(pattern_scope, pattern_rest);
+      match self.match_parameter_types(bivariant, specificity, pattern_ty, target_ty) {
+        MatchResult::Error => return MatchResult::Error,
+        MatchResult::Unmatched => return MatchResult::Unmatched,
+        MatchResult::Matched => {}
+        MatchResult::Inferred(map) => inferred.extend(map),
+      }
+    }
 
     // Step5: Match return type
     {
@@ -399,6 +495,68 @@ impl<'a> Analyzer<'a> {
       }
     }
 
+    // Step6: A type guard is only matched by a type guard
+    match (target.predicate, pattern.predicate) {
+      (None, Some(_)) => return MatchResult::Unmatched,
+      // FIXME: `this is S[]` of `Array.prototype.every` recurses infinitely with fresh placeholders
+      (Some(target), Some(pattern)) if target.param.is_some() && pattern.param.is_some() => {
+        if let (Some(target), Some(pattern)) = (target.ty, pattern.ty) {
+          let target_ty = self.resolve_ctx_ty(target_scope, target);
+          let pattern_ty = self.resolve_ctx_ty(pattern_scope, pattern);
+          match self.match_covariant_types(specificity, target_ty, pattern_ty) {
+            MatchResult::Error => return MatchResult::Error,
+            MatchResult::Unmatched => return MatchResult::Unmatched,
+            MatchResult::Matched => {}
+            MatchResult::Inferred(map) => inferred.extend(map),
+          }
+        }
+      }
+      _ => {}
+    }
+
+    MatchResult::Inferred(inferred)
+  }
+
+  fn match_tuple_types(
+    &mut self,
+    specificity: i32,
+    target: &'a TupleType<'a>,
+    pattern: &'a TupleType<'a>,
+  ) -> MatchResult<'a> {
+    if target.readonly && !pattern.readonly {
+      return MatchResult::Unmatched;
+    }
+    let specificity = specificity + 1;
+
+    // Variadic tuples are only matched by their element types
+    let is_variadic = |tuple: &TupleType| tuple.elements.iter().any(|element| element.spread);
+    if is_variadic(target) || is_variadic(pattern) {
+      let target = target.iterate_result_union(self);
+      let pattern = pattern.iterate_result_union(self);
+      return self.match_covariant_types(specificity, target, pattern);
+    }
+
+    if target.elements.len() > pattern.elements.len() {
+      return MatchResult::Unmatched;
+    }
+    let mut inferred = FxHashMap::default();
+    for (index, pattern) in pattern.elements.iter().enumerate() {
+      let Some(target) = target.elements.get(index) else {
+        if pattern.optional {
+          continue;
+        }
+        return MatchResult::Unmatched;
+      };
+      if target.optional && !pattern.optional {
+        return MatchResult::Unmatched;
+      }
+      match self.match_covariant_types(specificity, target.ty, pattern.ty) {
+        MatchResult::Error => return MatchResult::Error,
+        MatchResult::Unmatched => return MatchResult::Unmatched,
+        MatchResult::Matched => {}
+        MatchResult::Inferred(map) => inferred.extend(map),
+      }
+    }
     MatchResult::Inferred(inferred)
   }
 
@@ -418,6 +576,47 @@ impl<'a> Analyzer<'a> {
       }
     }
   }
+
+  /// The properties of `target` which are not keyed in `pattern` are matched against its index
+  /// signatures, like `{ a: 1 }` against `{ [s: string]: T }`.
+  fn match_record_mapped_properties(
+    &mut self,
+    builder: &mut BuilderBySpecificity<'a>,
+    specificity: i32,
+    target: &RecordType<'a>,
+    pattern: &RecordType<'a>,
+  ) {
+    for (key, property) in &target.string_keyed.0 {
+      if pattern.string_keyed.0.contains_key(key) {
+        continue;
+      }
+      let is_numeric = key.parse::<f64>().is_ok_and(|n| n.to_js_string() == *key);
+      let mapped = if is_numeric { pattern.number_mapped.as_ref() } else { None };
+      if let Some(mapped) = mapped.or(pattern.string_mapped.as_ref()) {
+        let result = self.match_covariant_types(specificity, property.value, mapped.value);
+        builder.add(result);
+      }
+    }
+    for (key, property) in &target.symbol_keyed.0 {
+      if pattern.symbol_keyed.0.contains_key(key) {
+        continue;
+      }
+      if let Some(mapped) = &pattern.symbol_mapped {
+        let result = self.match_covariant_types(specificity, property.value, mapped.value);
+        builder.add(result);
+      }
+    }
+    for (target, pattern) in [
+      (&target.string_mapped, pattern.string_mapped.as_ref()),
+      (&target.number_mapped, pattern.number_mapped.as_ref().or(pattern.string_mapped.as_ref())),
+      (&target.symbol_mapped, pattern.symbol_mapped.as_ref()),
+    ] {
+      if let (Some(target), Some(pattern)) = (target, pattern) {
+        let result = self.match_covariant_types(specificity, target.value, pattern.value);
+        builder.add(result);
+      }
+    }
+  }
 }
 
 #[derive(Debug, Default)]
@@ -1,4 +1,5 @@
 pub mod accumulator;
+pub mod awaited;
 pub mod callable;
 pub mod ctx;
 pub mod facts;
@@ -7,13 +8,16 @@ pub mod get_property;
 pub mod interface;
 pub mod intersection;
 pub mod intrinsics;
+pub mod keyof;
 pub mod lowest;
 pub mod r#match;
 pub mod namespace;
+pub mod narrow;
 pub mod operations;
 pub mod print;
 pub mod property_key;
 pub mod record;
+pub mod template_literal;
 pub mod tuple;
 pub mod union;
 pub mod unresolved;
@@ -21,7 +25,7 @@ pub mod widen;
 
 use std::{hash, mem};
 
-use callable::{ConstructorType, FunctionType};
+use callable::{ConstructorType, ExtractedCallable, FunctionType};
 use generic::{GenericInstanceType, GenericType};
 use interface::InterfaceType;
 use intersection::IntersectionType;
@@ -30,6 +34,7 @@ use namespace::NamespaceType;
 use oxc::{semantic::SymbolId, span::Atom};
 use property_key::PropertyKeyType;
 use record::RecordType;
+use template_literal::TemplateLiteralType;
 use tuple::TupleType;
 use union::UnionType;
 use unresolved::UnresolvedType;
@@ -62,6 +67,7 @@ pub enum Ty<'a> {
   BigIntLiteral(&'a Atom<'a>),
   BooleanLiteral(bool),
   UniqueSymbol(SymbolId),
+  TemplateLiteral(&'a TemplateLiteralType<'a>),
 
   /* Object like */
   Record(&'a RecordType<'a>),
@@ -106,16 +112,21 @@ impl<'a> PartialEq for Ty<'a> {
       (Ty::BigIntLiteral(a), Ty::BigIntLiteral(b)) => a == b,
       (Ty::BooleanLiteral(a), Ty::BooleanLiteral(b)) => a == b,
       (Ty::UniqueSymbol(a), Ty::UniqueSymbol(b)) => a == b,
-      (Ty::Record(a), Ty::Record(b)) => a as *const _ == b,
-      (Ty::Interface(a), Ty::Interface(b)) => a as *const _ == b,
-      (Ty::Function(a), Ty::Function(b)) => a as *const _ == b,
-      (Ty::Constructor(a), Ty::Constructor(b)) => a as *const _ == b,
-      (Ty::Union(a), Ty::Union(b)) => a as *const _ == b,
-      (Ty::Intersection(a), Ty::Intersection(b)) => a as *const _ == b,
-      (Ty::Instance(a), Ty::Instance(b)) => a as *const _ == b,
-      (Ty::Generic(a), Ty::Generic(b)) => a as *const _ == b,
-      (Ty::Intrinsic(a), Ty::Intrinsic(b)) => a as *const _ == b,
-      (Ty::Namespace(a), Ty::Namespace(b)) => a as *const _ == b,
+      (Ty::TemplateLiteral(a), Ty::TemplateLiteral(b)) => std::ptr::eq(*a, *b),
+      (Ty::Record(a), Ty::Record(b)) => std::ptr::eq(*a, *b),
+      (Ty::Interface(a), Ty::Interface(b)) => std::ptr::eq(*a, *b),
+      (Ty::Tuple(a), Ty::Tuple(b)) => std::ptr::eq(*a, *b),
+      (Ty::Function(a), Ty::Function(b)) => std::ptr::eq(*a, *b),
+      (Ty::Constructor(a), Ty::Constructor(b)) => std::ptr::eq(*a, *b),
+      (Ty::Union(a), Ty::Union(b)) => std::ptr::eq(*a, *b),
+      (Ty::Intersection(a), Ty::Intersection(b)) => std::ptr::eq(*a, *b),
+      // Instantiations are compared structurally, since they are created on each reference
+      (Ty::Instance(a), Ty::Instance(b)) => {
+        std::ptr::eq(*a, *b) || (a.generic == b.generic && a.args == b.args)
+      }
+      (Ty::Generic(a), Ty::Generic(b)) => std::ptr::eq(*a, *b),
+      (Ty::Intrinsic(a), Ty::Intrinsic(b)) => std::ptr::eq(*a, *b),
+      (Ty::Namespace(a), Ty::Namespace(b)) => std::ptr::eq(*a, *b),
       (Ty::Unresolved(a), Ty::Unresolved(b)) => a == b,
       _ => false,
     }
@@ -133,16 +144,21 @@ impl<'a> hash::Hash for Ty<'a> {
       Ty::BigIntLiteral(atom) => atom.hash(state),
       Ty::BooleanLiteral(b) => b.hash(state),
       Ty::UniqueSymbol(id) => id.hash(state),
-      Ty::Record(r) => (r as *const _ as usize).hash(state),
-      Ty::Interface(i) => (i as *const _ as usize).hash(state),
-      Ty::Function(f) => (f as *const _ as usize).hash(state),
-      Ty::Constructor(c) => (c as *const _ as usize).hash(state),
-      Ty::Union(u) => (u as *const _ as usize).hash(state),
-      Ty::Intersection(i) => (i as *const _ as usize).hash(state),
-      Ty::Instance(i) => (i as *const _ as usize).hash(state),
-      Ty::Generic(g) => (g as *const _ as usize).hash(state),
-      Ty::Intrinsic(i) => (i as *const _ as usize).hash(state),
-      Ty::Namespace(n) => (n as *const _ as usize).hash(state),
+      Ty::TemplateLiteral(t) => (*t as *const _ as usize).hash(state),
+      Ty::Record(r) => (*r as *const _ as usize).hash(state),
+      Ty::Interface(i) => (*i as *const _ as usize).hash(state),
+      Ty::Tuple(t) => (*t as *const _ as usize).hash(state),
+      Ty::Function(f) => (*f as *const _ as usize).hash(state),
+      Ty::Constructor(c) => (*c as *const _ as usize).hash(state),
+      Ty::Union(u) => (*u as *const _ as usize).hash(state),
+      Ty::Intersection(i) => (*i as *const _ as usize).hash(state),
+      Ty::Instance(i) => {
+        i.generic.hash(state);
+        i.args.hash(state);
+      }
+      Ty::Generic(g) => (*g as *const _ as usize).hash(state),
+      Ty::Intrinsic(i) => (*i as *const _ as usize).hash(state),
+      Ty::Namespace(n) => (*n as *const _ as usize).hash(state),
       Ty::Unresolved(u) => u.hash(state),
       _ => {}
     }
@@ -158,17 +174,162 @@ impl<'a> Analyzer<'a> {
     // Do nothing
   }
 
+  /// The union of the values produced by iterating `target`, like the element type of an array.
   pub fn iterate_result_union(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
+    match target {
+      Ty::Error | Ty::Any | Ty::Never => target,
+      Ty::String | Ty::StringLiteral(_) | Ty::TemplateLiteral(_) => Ty::String,
+      Ty::Tuple(tuple) => tuple.iterate_result_union(self),
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types = members.into_iter().map(|ty| self.iterate_result_union(ty)).collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ => match self.get_iterator(target, false) {
+        Some(iterator) => {
+          self.get_iterator_type_args(iterator).map_or(Ty::Any, |[yielded, _, _]| yielded)
+        }
+        // Array-likes without `[Symbol.iterator]`, like arrays in ES5
+        None => self.get_property(target, PropertyKeyType::AnyNumber),
+      },
+    }
+  }
+
+  /// The union of the values produced by `for await`, which uses `[Symbol.asyncIterator]` or
+  /// awaits the values of `[Symbol.iterator]`.
+  pub fn async_iterate_result_union(&mut self, target: Ty<'a>) -> Ty<'a> {
+    match target {
+      Ty::Error | Ty::Any | Ty::Never => target,
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let types =
+          members.into_iter().map(|ty| self.async_iterate_result_union(ty)).collect::<Vec<_>>();
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ => match self.get_iterator(target, true) {
+        Some(iterator) => {
+          self.get_iterator_type_args(iterator).map_or(Ty::Any, |[yielded, _, _]| yielded)
+        }
+        None => {
+          let iterated = self.iterate_result_union(target);
+          self.get_to_awaited(iterated)
+        }
+      },
+    }
+  }
+
+  /// The iterator returned by `target[Symbol.iterator]()`, or `target[Symbol.asyncIterator]()` if
+  /// `is_async`. `None` if `target` is not iterable.
+  fn get_iterator(&mut self, target: Ty<'a>, is_async: bool) -> Option<Ty<'a>> {
+    let symbol =
+      if is_async { self.builtins.symbol_async_iterator } else { self.builtins.symbol_iterator }?;
+    let method = self.get_property(target, PropertyKeyType::UniqueSymbol(symbol));
+    self.get_method_return_type(method)
+  }
+
+  /// The return type of calling `method` without arguments.
+  fn get_method_return_type(&mut self, method: Ty<'a>) -> Option<Ty<'a>> {
+    let callable = match self.extract_callable_function(method)? {
+      ExtractedCallable::Any => return Some(Ty::Any),
+      ExtractedCallable::Single(callable) => callable,
+      ExtractedCallable::Overloaded(overloads) => match overloads.first()? {
+        ExtractedCallable::Single(callable) => *callable,
+        _ => return None,
+      },
+      ExtractedCallable::Union(_) => return None,
+    };
+    let scope = self.type_scopes.create_scope();
+    Some(self.resolve_ctx_ty(scope, callable.return_type))
+  }
+
+  /// The `[T, TReturn, TNext]` of an iterator type like `Generator<T, TReturn, TNext>`, read from
+  /// its `next` method. `None` if `target` is not an iterator.
+  pub fn get_iterator_type_args(&mut self, target: Ty<'a>) -> Option<[Ty<'a>; 3]> {
+    let key = PropertyKeyType::StringLiteral(self.allocator.alloc(Atom::from("next")));
+    let next = self.get_property(target, key);
+    let result = self.get_method_return_type(next)?;
+    // The result of an async iterator is a promise
+    let mut result = self.get_to_awaited(result);
+    while let Ty::Instance(instance) = result {
+      result = self.unwrap_generic_instance(instance);
+    }
+
+    // `IteratorResult<T, TReturn>` is split by `done`
+    let mut members = vec![];
+    match result {
+      Ty::Union(union) => union.for_each(|ty| members.push(ty)),
+      result => members.push(result),
+    }
+    let mut yielded = vec![];
+    let mut returned = vec![];
+    for member in members {
+      let key = PropertyKeyType::StringLiteral(self.allocator.alloc(Atom::from("done")));
+      let done = self.get_property(member, key);
+      let key = PropertyKeyType::StringLiteral(self.allocator.alloc(Atom::from("value")));
+      let value = self.get_property(member, key);
+      if matches!(done, Ty::BooleanLiteral(true)) {
+        returned.push(value);
+      } else {
+        yielded.push(value);
+      }
+    }
+    let yielded = self.into_union(yielded).unwrap_or(Ty::Never);
+    let returned = self.into_union(returned).unwrap_or(Ty::Never);
+
+    // `next(...[value]: [] | [TNext])`
+    let scope = self.type_scopes.create_scope();
+    let callable = self.extract_callable_function(next)?;
+    let next = match self.get_callable_parameter_types(scope, &callable).first() {
+      Some((true, rest)) => self.iterate_result_union(*rest),
+      Some((false, param)) => *param,
+      None => Ty::Any,
+    };
+
+    Some([yielded, returned, next])
   }
 
+  /// The types of the first `len` iterated values of `target`, and the type of the rest values if
+  /// `need_rest`, like `const [a, b, ...rest] = target`.
   pub fn destruct_as_array(
     &mut self,
     target: Ty<'a>,
     len: usize,
     need_rest: bool,
   ) -> (Vec<Ty<'a>>, Option<Ty<'a>>) {
-    todo!()
+    match target {
+      Ty::Error | Ty::Any => {
+        (vec![target; len], need_rest.then(|| self.create_array_type(target, false)))
+      }
+      Ty::Tuple(tuple) => tuple.destruct(len, need_rest, self),
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let mut values = vec![vec![]; len];
+        let mut rests = vec![];
+        for member in members {
+          let (member_values, member_rest) = self.destruct_as_array(member, len, need_rest);
+          for (types, value) in values.iter_mut().zip(member_values) {
+            types.push(value);
+          }
+          rests.extend(member_rest);
+        }
+        let values =
+          values.into_iter().map(|types| self.into_union(types).unwrap_or(Ty::Never)).collect();
+        let rest = need_rest.then(|| self.into_union(rests).unwrap_or(Ty::Never));
+        (values, rest)
+      }
+      _ => {
+        let element = self.iterate_result_union(target);
+        let value = if self.config.no_unchecked_indexed_access {
+          self.into_union([element, Ty::Undefined]).unwrap()
+        } else {
+          element
+        };
+        (vec![value; len], need_rest.then(|| self.create_array_type(element, false)))
+      }
+    }
   }
 
   pub fn get_to_numeric(&mut self, target: Ty<'a>) -> Ty<'a> {
@@ -182,8 +343,4 @@ impl<'a> Analyzer<'a> {
   pub fn get_to_boolean(&mut self, target: Ty<'a>) -> Ty<'a> {
     self.test_nullish(target).map_or(Ty::Boolean, Ty::BooleanLiteral)
   }
-
-  pub fn get_to_awaited(&mut self, target: Ty<'a>) -> Ty<'a> {
-    todo!()
-  }
 }
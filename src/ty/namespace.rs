@@ -1,16 +1,42 @@
-use oxc::ast::ast::TSType;
+use oxc::{ast::ast::TSType, span::SPAN};
 use rustc_hash::FxHashMap;
 
-use super::Ty;
+use super::{property_key::PropertyKeyType, Ty};
 use crate::analyzer::Analyzer;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NamespaceType<'a> {
   pub members: FxHashMap<&'a str, Ty<'a>>,
+  /// Members which are types, like exported interfaces and type aliases
+  pub types: FxHashMap<&'a str, Ty<'a>>,
+}
+
+impl<'a> NamespaceType<'a> {
+  pub fn get_property(&self, key: PropertyKeyType<'a>) -> Ty<'a> {
+    match key {
+      PropertyKeyType::StringLiteral(s) => self.members.get(s.as_str()).copied(),
+      _ => None,
+    }
+    .unwrap_or(Ty::Error)
+  }
 }
 
 impl<'a> Analyzer<'a> {
   pub fn serialize_namespace_type(&mut self, namespace: &NamespaceType<'a>) -> TSType<'a> {
-    todo!()
+    let mut members = self.ast_builder.vec();
+    let mut names = namespace.members.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+    for name in names {
+      let value = self.serialize_type(namespace.members[name]);
+      members.push(self.ast_builder.ts_signature_property_signature(
+        SPAN,
+        false,
+        false,
+        true,
+        self.ast_builder.property_key_identifier_name(SPAN, name),
+        Some(self.ast_builder.ts_type_annotation(SPAN, value)),
+      ));
+    }
+    self.ast_builder.ts_type_type_literal(SPAN, members)
   }
 }
@@ -0,0 +1,280 @@
+use oxc_syntax::number::ToJsString;
+
+use super::{
+  facts::Facts, property_key::PropertyKeyType, record::RecordType, unresolved::UnresolvedType, Ty,
+};
+use crate::analyzer::Analyzer;
+
+impl<'a> Analyzer<'a> {
+  /// Remove the members of `ty` which definitely have any of the `excluded` facts.
+  pub fn filter_by_facts(&mut self, ty: Ty<'a>, excluded: Facts) -> Ty<'a> {
+    match ty {
+      Ty::Union(u) => {
+        let mut types = vec![];
+        u.for_each(|t| types.push(t));
+        let len = types.len();
+        let types = types
+          .into_iter()
+          .filter(|t| !self.get_facts(*t).intersects(excluded))
+          .collect::<Vec<_>>();
+        if types.len() == len {
+          // Keep the union as is, so that it can still be printed by name
+          return ty;
+        }
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ if self.get_facts(ty).intersects(excluded) => Ty::Never,
+      _ => ty,
+    }
+  }
+
+  /// Narrow `ty` with it being `truthy`.
+  pub fn narrow_by_truthy(&mut self, ty: Ty<'a>, truthy: bool) -> Ty<'a> {
+    match ty {
+      Ty::Boolean => Ty::BooleanLiteral(truthy),
+      Ty::Union(u) => {
+        let mut types = vec![];
+        u.for_each(|t| types.push(t));
+        let narrowed = types.iter().map(|t| self.narrow_by_truthy(*t, truthy)).collect::<Vec<_>>();
+        if narrowed == types {
+          return ty;
+        }
+        self.into_union(narrowed).unwrap_or(Ty::Never)
+      }
+      _ => self.filter_by_facts(ty, Facts::truthy(!truthy)),
+    }
+  }
+
+  /// Narrow `ty` with `ty === value` being `equal`, where `value` is a literal type.
+  pub fn narrow_by_equality(&mut self, ty: Ty<'a>, value: Ty<'a>, equal: bool) -> Ty<'a> {
+    match (ty, value) {
+      (Ty::Union(u), _) => {
+        let mut types = vec![];
+        u.for_each(|t| types.push(t));
+        let narrowed =
+          types.iter().map(|t| self.narrow_by_equality(*t, value, equal)).collect::<Vec<_>>();
+        if narrowed == types {
+          return ty;
+        }
+        self.into_union(narrowed).unwrap_or(Ty::Never)
+      }
+
+      _ if ty == value => {
+        if equal {
+          ty
+        } else {
+          Ty::Never
+        }
+      }
+      (Ty::Boolean, Ty::BooleanLiteral(b)) => Ty::BooleanLiteral(b == equal),
+      _ if !equal => ty,
+
+      (Ty::Unknown, _)
+      | (Ty::String, Ty::StringLiteral(_))
+      | (Ty::Number, Ty::NumericLiteral(_))
+      | (Ty::BigInt, Ty::BigIntLiteral(_))
+      | (Ty::Symbol, Ty::UniqueSymbol(_)) => value,
+      (
+        Ty::Void
+        | Ty::BigInt
+        | Ty::Boolean
+        | Ty::Null
+        | Ty::Number
+        | Ty::Object
+        | Ty::String
+        | Ty::Symbol
+        | Ty::Undefined
+        | Ty::StringLiteral(_)
+        | Ty::NumericLiteral(_)
+        | Ty::BigIntLiteral(_)
+        | Ty::BooleanLiteral(_)
+        | Ty::UniqueSymbol(_)
+        | Ty::Record(_)
+        | Ty::Interface(_)
+        | Ty::Tuple(_)
+        | Ty::Function(_)
+        | Ty::Constructor(_),
+        _,
+      ) => Ty::Never,
+      _ => ty,
+    }
+  }
+
+  /// Narrow `ty` with `ty == null` being `nullish`.
+  pub fn narrow_by_nullish(&mut self, ty: Ty<'a>, nullish: bool) -> Ty<'a> {
+    if nullish {
+      self.filter_by_facts(ty, Facts::NOT_NULLISH)
+    } else {
+      self.filter_by_facts(ty, Facts::IS_NULLISH)
+    }
+  }
+
+  /// Narrow `ty` with `typeof ty === tag` being `truthy`.
+  pub fn narrow_by_typeof(&mut self, ty: Ty<'a>, tag: &str, truthy: bool) -> Ty<'a> {
+    let Some((eq, ne)) = get_typeof_facts(tag) else {
+      // Never equals to an unknown tag
+      return if truthy { Ty::Never } else { ty };
+    };
+    if !truthy {
+      return self.filter_by_facts(ty, eq);
+    }
+    match (ty, tag) {
+      (Ty::Any | Ty::Unknown, "string") => Ty::String,
+      (Ty::Any | Ty::Unknown, "number") => Ty::Number,
+      (Ty::Any | Ty::Unknown, "bigint") => Ty::BigInt,
+      (Ty::Any | Ty::Unknown, "boolean") => Ty::Boolean,
+      (Ty::Any | Ty::Unknown, "symbol") => Ty::Symbol,
+      (Ty::Any | Ty::Unknown, "undefined") => Ty::Undefined,
+      (Ty::Unknown, "object") => self.into_union([Ty::Object, Ty::Null]).unwrap(),
+      _ => self.filter_by_facts(ty, ne),
+    }
+  }
+  /// Narrow `ty` with `key in ty` being `truthy`.
+  pub fn narrow_by_in(&mut self, ty: Ty<'a>, key: PropertyKeyType<'a>, truthy: bool) -> Ty<'a> {
+    self.filter_union(ty, |analyzer, t| analyzer.has_property(t, key) != Some(!truthy))
+  }
+
+  /// Narrow `ty` with it being of the `target` type being `truthy`, like `ty instanceof C` or a type
+  /// guard `ty is T`. Without a subtype checker, members with all the properties of `target` are
+  /// its subtypes.
+  pub fn narrow_by_type(&mut self, ty: Ty<'a>, target: Ty<'a>, truthy: bool) -> Ty<'a> {
+    match target {
+      Ty::Union(u) => {
+        let mut targets = vec![];
+        u.for_each(|t| targets.push(t));
+        if truthy {
+          let types =
+            targets.into_iter().map(|t| self.narrow_by_type(ty, t, true)).collect::<Vec<_>>();
+          self.into_union(types).unwrap_or(Ty::Never)
+        } else {
+          targets.into_iter().fold(ty, |ty, t| self.narrow_by_type(ty, t, false))
+        }
+      }
+      Ty::StringLiteral(_)
+      | Ty::NumericLiteral(_)
+      | Ty::BigIntLiteral(_)
+      | Ty::BooleanLiteral(_)
+      | Ty::UniqueSymbol(_) => self.narrow_by_equality(ty, target, truthy),
+      _ => {
+        let narrowed =
+          self.filter_union(ty, |analyzer, t| analyzer.is_subtype_like(t, target) == truthy);
+        match (narrowed, truthy) {
+          (Ty::Never | Ty::Any | Ty::Unknown, true) => target,
+          _ => narrowed,
+        }
+      }
+    }
+  }
+
+  /// Keep the members of `ty` which satisfy `keep`.
+  fn filter_union(
+    &mut self,
+    ty: Ty<'a>,
+    mut keep: impl FnMut(&mut Self, Ty<'a>) -> bool,
+  ) -> Ty<'a> {
+    match ty {
+      Ty::Union(u) => {
+        let mut types = vec![];
+        u.for_each(|t| types.push(t));
+        let len = types.len();
+        let types = types.into_iter().filter(|t| keep(self, *t)).collect::<Vec<_>>();
+        if types.len() == len {
+          return ty;
+        }
+        self.into_union(types).unwrap_or(Ty::Never)
+      }
+      _ if keep(self, ty) => ty,
+      _ => Ty::Never,
+    }
+  }
+
+  /// Whether `ty` definitely has the property `key`. `None` if it might have it.
+  fn has_property(&mut self, ty: Ty<'a>, key: PropertyKeyType<'a>) -> Option<bool> {
+    match ty {
+      Ty::Record(record) => has_record_property(record, key),
+      Ty::Interface(interface) => {
+        let interface = interface.0.borrow();
+        match has_record_property(&interface.record, key) {
+          Some(false) if !interface.unresolved_extends.is_empty() => None,
+          has => has,
+        }
+      }
+      Ty::Instance(_) | Ty::Unresolved(UnresolvedType::UnInitType(_)) => {
+        let resolved = self.get_lowest_type(ty);
+        if resolved == ty {
+          None
+        } else {
+          self.has_property(resolved, key)
+        }
+      }
+      // The `in` operator throws on primitives
+      Ty::Void
+      | Ty::BigInt
+      | Ty::Boolean
+      | Ty::Null
+      | Ty::Number
+      | Ty::String
+      | Ty::Symbol
+      | Ty::Undefined
+      | Ty::StringLiteral(_)
+      | Ty::NumericLiteral(_)
+      | Ty::BigIntLiteral(_)
+      | Ty::BooleanLiteral(_)
+      | Ty::UniqueSymbol(_) => Some(false),
+      _ => None,
+    }
+  }
+
+  fn is_subtype_like(&mut self, ty: Ty<'a>, target: Ty<'a>) -> bool {
+    match (ty, target) {
+      _ if ty == target => return true,
+      (Ty::StringLiteral(_), Ty::String)
+      | (Ty::NumericLiteral(_), Ty::Number)
+      | (Ty::BigIntLiteral(_), Ty::BigInt)
+      | (Ty::BooleanLiteral(_), Ty::Boolean)
+      | (Ty::UniqueSymbol(_), Ty::Symbol) => return true,
+      _ => {}
+    }
+    let (Some(record), Some(target_record)) =
+      (self.get_object_record(ty), self.get_object_record(target))
+    else {
+      return false;
+    };
+    target_record.string_keyed.0.keys().all(|key| record.string_keyed.0.contains_key(key))
+      && target_record.symbol_keyed.0.keys().all(|key| record.symbol_keyed.0.contains_key(key))
+  }
+}
+
+fn has_record_property<'a>(record: &RecordType<'a>, key: PropertyKeyType<'a>) -> Option<bool> {
+  let (property, mapped) = match key {
+    PropertyKeyType::StringLiteral(s) => {
+      (record.string_keyed.0.get(s.as_str()), &record.string_mapped)
+    }
+    PropertyKeyType::NumericLiteral(n) => (
+      record.string_keyed.0.get(n.0.to_js_string().as_str()),
+      if record.number_mapped.is_some() { &record.number_mapped } else { &record.string_mapped },
+    ),
+    PropertyKeyType::UniqueSymbol(s) => (record.symbol_keyed.0.get(&s), &record.symbol_mapped),
+    _ => return None,
+  };
+  match (property, mapped) {
+    (Some(property), _) => (!property.optional).then_some(true),
+    (None, Some(_)) => None,
+    (None, None) => Some(false),
+  }
+}
+
+/// The facts of `typeof value === tag` being true and false.
+fn get_typeof_facts(tag: &str) -> Option<(Facts, Facts)> {
+  Some(match tag {
+    "string" => (Facts::T_EQ_STRING, Facts::T_NE_STRING),
+    "number" => (Facts::T_EQ_NUMBER, Facts::T_NE_NUMBER),
+    "bigint" => (Facts::T_EQ_BIGINT, Facts::T_NE_BIGINT),
+    "boolean" => (Facts::T_EQ_BOOLEAN, Facts::T_NE_BOOLEAN),
+    "symbol" => (Facts::T_EQ_SYMBOL, Facts::T_NE_SYMBOL),
+    "object" => (Facts::T_EQ_OBJECT, Facts::T_NE_OBJECT),
+    "function" => (Facts::T_EQ_FUNCTION, Facts::T_NE_FUNCTION),
+    "undefined" => (Facts::EQ_UNDEFINED, Facts::NE_UNDEFINED),
+    _ => return None,
+  })
+}
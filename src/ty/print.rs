@@ -1,5 +1,8 @@
 use oxc::{
-  ast::ast::{TSType, TSTypeOperatorOperator},
+  allocator,
+  ast::ast::{
+    TSType, TSTypeName, TSTypeOperatorOperator, TSTypeParameterInstantiation, TSTypeQueryExprName,
+  },
   span::SPAN,
 };
 use oxc_syntax::number::{BigintBase, NumberBase};
@@ -9,6 +12,21 @@ use crate::analyzer::Analyzer;
 
 impl<'a> Analyzer<'a> {
   pub fn serialize_type(&mut self, ty: Ty<'a>) -> TSType<'a> {
+    if let Some(name) = self.builtins.global_type_names.get(&ty) {
+      return self.serialize_global_type_reference(name, None);
+    }
+    if let Some(&name) = self.named_types.get(&ty) {
+      return self.serialize_global_type_reference(name, None);
+    }
+    if let Some(&name) = self.named_values.get(&ty) {
+      let name = self.ast_builder.alloc(self.ast_builder.identifier_reference(SPAN, name));
+      return self.ast_builder.ts_type_type_query(
+        SPAN,
+        TSTypeQueryExprName::IdentifierReference(name),
+        None::<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+      );
+    }
+
     match ty {
       Ty::Error | Ty::Any => self.ast_builder.ts_type_any_keyword(SPAN),
       Ty::Unknown => self.ast_builder.ts_type_unknown_keyword(SPAN),
@@ -43,9 +61,17 @@ impl<'a> Analyzer<'a> {
         TSTypeOperatorOperator::Unique,
         self.ast_builder.ts_type_symbol_keyword(SPAN),
       ),
+      Ty::TemplateLiteral(t) => self.serialize_template_literal_type(t),
 
       Ty::Record(r) => self.serialize_record_type(r),
-      Ty::Interface(i) => self.serialize_interface_type(i),
+      Ty::Interface(i) => {
+        if !self.printing_interfaces.insert(ty) {
+          return self.ast_builder.ts_type_any_keyword(SPAN);
+        }
+        let serialized = self.serialize_interface_type(i);
+        self.printing_interfaces.remove(&ty);
+        serialized
+      }
       Ty::Tuple(t) => self.serialize_tuple_type(t),
       Ty::Function(r) => self.serialize_callable_type(r),
       Ty::Constructor(r) => self.serialize_callable_type(r),
@@ -61,4 +87,19 @@ impl<'a> Analyzer<'a> {
       Ty::Unresolved(u) => self.serialize_unresolved_type(u),
     }
   }
+
+  /// Global types are referenced by name, and resolved as globals when deserialized.
+  pub fn serialize_global_type_reference(
+    &mut self,
+    name: &'a str,
+    type_parameters: Option<allocator::Box<'a, TSTypeParameterInstantiation<'a>>>,
+  ) -> TSType<'a> {
+    self.ast_builder.ts_type_type_reference(
+      SPAN,
+      TSTypeName::IdentifierReference(
+        self.ast_builder.alloc(self.ast_builder.identifier_reference(SPAN, name)),
+      ),
+      type_parameters,
+    )
+  }
 }
@@ -27,7 +27,7 @@ impl<'a> Analyzer<'a> {
       }
       Ty::Unknown | Ty::Never | Ty::Void => PropertyKeyType::Error,
 
-      Ty::String => PropertyKeyType::AnyString,
+      Ty::String | Ty::TemplateLiteral(_) => PropertyKeyType::AnyString,
       Ty::Number => PropertyKeyType::AnyNumber,
       Ty::Symbol => PropertyKeyType::AnySymbol,
       Ty::BigInt | Ty::Boolean | Ty::Null | Ty::Object | Ty::Undefined => PropertyKeyType::Error,
@@ -1,6 +1,7 @@
 use std::{collections::hash_map::Entry, hash::Hash};
 
 use oxc::{
+  allocator::CloneIn,
   ast::ast::{PropertyKey, TSSignature, TSType},
   semantic::SymbolId,
   span::SPAN,
@@ -8,8 +9,10 @@ use oxc::{
 use oxc_syntax::number::ToJsString;
 use rustc_hash::FxHashMap;
 
-use super::{accumulator::TypeAccumulator, property_key::PropertyKeyType, Ty};
-use crate::{analyzer::Analyzer, ty::union::UnionType};
+use super::{
+  accumulator::TypeAccumulator, property_key::PropertyKeyType, unresolved::UnresolvedType, Ty,
+};
+use crate::{analyzer::Analyzer, ty::intersection::IntersectionType};
 
 #[derive(Debug, Clone)]
 pub struct RecordPropertyValue<'a> {
@@ -30,13 +33,14 @@ impl<'a, K> Default for KeyedPropertyMap<'a, K> {
 
 impl<'a, K: Eq + Hash> KeyedPropertyMap<'a, K> {
   pub fn init(&mut self, analyzer: &mut Analyzer<'a>, key: K, mut value: RecordPropertyValue<'a>) {
-    fn union_is_bivariant<'a>(u: &UnionType<'a>) -> bool {
-      let mut bivariant = true;
-      u.for_each(|ty| match ty {
-        Ty::Function(f) => bivariant &= f.is_method,
-        _ => bivariant = false,
+    // Overloads of a method
+    fn is_overloaded_method(i: &IntersectionType) -> bool {
+      let mut overloaded = true;
+      i.for_each(|ty| match ty {
+        Ty::Function(f) => overloaded &= f.is_method,
+        _ => overloaded = false,
       });
-      bivariant
+      overloaded
     }
 
     match self.0.entry(key) {
@@ -44,10 +48,10 @@ impl<'a, K: Eq + Hash> KeyedPropertyMap<'a, K> {
         let prev = entry.get();
         value.value = match (prev.value, value.value) {
           (Ty::Function(f1), Ty::Function(f2)) if f1.is_method && f2.is_method => {
-            analyzer.into_union([prev.value, value.value]).unwrap()
+            analyzer.into_intersection([prev.value, value.value])
           }
-          (Ty::Union(u1), Ty::Function(f2)) if union_is_bivariant(u1) && f2.is_method => {
-            analyzer.into_union([prev.value, value.value]).unwrap()
+          (Ty::Intersection(i1), Ty::Function(f2)) if is_overloaded_method(i1) && f2.is_method => {
+            analyzer.into_intersection([prev.value, value.value])
           }
           _ => value.value,
         };
@@ -74,6 +78,13 @@ pub struct MappedPropertyBuilder<'a> {
   readonly: bool,
 }
 
+impl<'a> MappedPropertyBuilder<'a> {
+  fn add(&mut self, analyzer: &mut Analyzer<'a>, value: Ty<'a>, readonly: bool) {
+    self.value.add(value, analyzer.allocator);
+    self.readonly |= readonly;
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct RecordTypeBuilder<'a> {
   pub string_keyed: KeyedPropertyMap<'a, &'a str>,
@@ -97,14 +108,13 @@ impl<'a> RecordTypeBuilder<'a> {
     match key {
       PropertyKeyType::Error => {}
       PropertyKeyType::AnyString => {
-        self.string_mapped.value.add(value, analyzer.allocator);
+        self.string_mapped.add(analyzer, value, readonly);
       }
       PropertyKeyType::AnyNumber => {
-        self.string_mapped.value.add(value, analyzer.allocator);
-        self.number_mapped.value.add(value, analyzer.allocator);
+        self.number_mapped.add(analyzer, value, readonly);
       }
       PropertyKeyType::AnySymbol => {
-        self.symbol_mapped.value.add(value, analyzer.allocator);
+        self.symbol_mapped.add(analyzer, value, readonly);
       }
       PropertyKeyType::StringLiteral(s) => {
         self.string_keyed.init(analyzer, s.as_str(), keyed_property);
@@ -119,12 +129,106 @@ impl<'a> RecordTypeBuilder<'a> {
     }
   }
 
+  /// Copy the own properties of `value`, like `{ ...value }`.
   pub fn init_spread(&mut self, analyzer: &mut Analyzer<'a>, value: Ty<'a>) {
-    todo!()
+    match value {
+      Ty::Any | Ty::Error => self.string_mapped.add(analyzer, value, false),
+      // Spreading nullish values and primitives adds nothing
+      Ty::Null | Ty::Undefined | Ty::Void | Ty::Never => {}
+      Ty::Tuple(tuple) => {
+        for (index, element) in tuple.elements.iter().enumerate() {
+          if element.spread {
+            let value = analyzer.iterate_result_union(element.ty);
+            self.number_mapped.add(analyzer, value, false);
+            break;
+          }
+          let key = analyzer.allocator.alloc(index.to_string());
+          self.string_keyed.0.insert(
+            key,
+            RecordPropertyValue { value: element.ty, optional: element.optional, readonly: false },
+          );
+        }
+      }
+      Ty::Union(union) => {
+        let mut members = vec![];
+        union.for_each(|ty| members.push(ty));
+        let records = members
+          .into_iter()
+          .filter(|ty| !matches!(ty, Ty::Null | Ty::Undefined | Ty::Void))
+          .filter_map(|ty| analyzer.get_object_record(ty))
+          .collect::<Vec<_>>();
+        // Properties which are not present in all the members become optional
+        let mut spread = RecordTypeBuilder::default();
+        for record in &records {
+          for (key, property) in &record.string_keyed.0 {
+            let optional =
+              property.optional || records.iter().any(|r| !r.string_keyed.0.contains_key(key));
+            spread.merge_keyed(analyzer, key, property, optional);
+          }
+        }
+        for record in records {
+          self.init_record_mapped(analyzer, &record);
+        }
+        self.string_keyed.0.extend(spread.string_keyed.0);
+      }
+      _ => {
+        if let Some(record) = analyzer.get_object_record(value) {
+          self.string_keyed.0.extend(record.string_keyed.0.clone());
+          self.symbol_keyed.0.extend(record.symbol_keyed.0.clone());
+          self.init_record_mapped(analyzer, &record);
+        }
+      }
+    }
   }
 
+  fn init_record_mapped(&mut self, analyzer: &mut Analyzer<'a>, record: &RecordType<'a>) {
+    if let Some(property) = &record.string_mapped {
+      self.string_mapped.add(analyzer, property.value, property.readonly);
+    }
+    if let Some(property) = &record.number_mapped {
+      self.number_mapped.add(analyzer, property.value, property.readonly);
+    }
+    if let Some(property) = &record.symbol_mapped {
+      self.symbol_mapped.add(analyzer, property.value, property.readonly);
+    }
+  }
+
+  fn merge_keyed(
+    &mut self,
+    analyzer: &mut Analyzer<'a>,
+    key: &'a str,
+    property: &RecordPropertyValue<'a>,
+    optional: bool,
+  ) {
+    let value = match self.string_keyed.0.get(key) {
+      Some(prev) => analyzer.into_union([prev.value, property.value]).unwrap(),
+      None => property.value,
+    };
+    self
+      .string_keyed
+      .0
+      .insert(key, RecordPropertyValue { value, optional, readonly: property.readonly });
+  }
+
+  /// Remove a picked property, like `key` in `const { key, ...rest } = object`.
   pub fn remove_property(&mut self, analyzer: &mut Analyzer<'a>, key: PropertyKeyType<'a>) {
-    todo!()
+    match key {
+      PropertyKeyType::StringLiteral(s) => {
+        self.string_keyed.0.remove(s.as_str());
+      }
+      PropertyKeyType::NumericLiteral(n) => {
+        let s = analyzer.allocator.alloc(n.0.to_js_string());
+        self.string_keyed.0.remove(s.as_str());
+      }
+      PropertyKeyType::UniqueSymbol(s) => {
+        self.symbol_keyed.0.remove(&s);
+      }
+      // Computed keys can not be removed
+      PropertyKeyType::Error
+      | PropertyKeyType::AnyString
+      | PropertyKeyType::AnyNumber
+      | PropertyKeyType::AnySymbol => {}
+    }
   }
 
   pub fn build(mut self) -> RecordType<'a> {
@@ -167,9 +271,22 @@ impl<'a> RecordType<'a> {
       PropertyKeyType::AnyString => self.string_mapped.as_ref().map_or(Ty::Error, |p| p.value),
       PropertyKeyType::AnyNumber => self.number_mapped.as_ref().map_or(Ty::Error, |p| p.value),
       PropertyKeyType::AnySymbol => self.symbol_mapped.as_ref().map_or(Ty::Error, |p| p.value),
-      PropertyKeyType::StringLiteral(s) => self.string_keyed.get(s.as_str()),
-      PropertyKeyType::NumericLiteral(n) => self.string_keyed.get(n.0.to_js_string().as_str()),
-      PropertyKeyType::UniqueSymbol(s) => self.symbol_keyed.get(s),
+      // Keys which are not declared fall back to the index signatures
+      PropertyKeyType::StringLiteral(s) => match self.string_keyed.0.get(s.as_str()) {
+        Some(property) => property.value,
+        None => self.get_property(PropertyKeyType::AnyString),
+      },
+      PropertyKeyType::NumericLiteral(n) => {
+        match self.string_keyed.0.get(n.0.to_js_string().as_str()) {
+          Some(property) => property.value,
+          None if self.number_mapped.is_some() => self.get_property(PropertyKeyType::AnyNumber),
+          None => self.get_property(PropertyKeyType::AnyString),
+        }
+      }
+      PropertyKeyType::UniqueSymbol(s) => match self.symbol_keyed.0.get(&s) {
+        Some(property) => property.value,
+        None => self.get_property(PropertyKeyType::AnySymbol),
+      },
     }
   }
 
@@ -177,9 +294,35 @@ impl<'a> RecordType<'a> {
     // FIXME: overload
     self.string_keyed.0.extend(other.string_keyed.0);
     self.symbol_keyed.0.extend(other.symbol_keyed.0);
-    self.string_mapped = other.string_mapped;
-    self.number_mapped = other.number_mapped;
-    self.symbol_mapped = other.symbol_mapped;
+    if other.string_mapped.is_some() {
+      self.string_mapped = other.string_mapped;
+    }
+    if other.number_mapped.is_some() {
+      self.number_mapped = other.number_mapped;
+    }
+    if other.symbol_mapped.is_some() {
+      self.symbol_mapped = other.symbol_mapped;
+    }
+  }
+
+  pub fn for_each_value(&self, mut f: impl FnMut(Ty<'a>)) {
+    self.string_keyed.0.values().for_each(|property| f(property.value));
+    self.symbol_keyed.0.values().for_each(|property| f(property.value));
+    [&self.string_mapped, &self.number_mapped, &self.symbol_mapped]
+      .into_iter()
+      .flatten()
+      .for_each(|property| f(property.value));
+  }
+
+  pub fn values_mut(&mut self) -> impl Iterator<Item = &mut RecordPropertyValue<'a>> {
+    self
+      .string_keyed
+      .0
+      .values_mut()
+      .chain(self.symbol_keyed.0.values_mut())
+      .chain(self.string_mapped.iter_mut())
+      .chain(self.number_mapped.iter_mut())
+      .chain(self.symbol_mapped.iter_mut())
   }
 
   pub fn is_empty(&self) -> bool {
@@ -192,14 +335,41 @@ impl<'a> RecordType<'a> {
 }
 
 impl<'a> Analyzer<'a> {
+  /// The properties of an object type, like a record, an interface, or an intersection of them.
+  pub fn get_object_record(&mut self, ty: Ty<'a>) -> Option<RecordType<'a>> {
+    match ty {
+      Ty::Record(record) => Some(record.clone()),
+      Ty::Interface(interface) => Some(interface.0.borrow().record.clone()),
+      Ty::Intersection(intersection) => {
+        let mut members = vec![];
+        intersection.for_each(|ty| members.push(ty));
+        let mut record = RecordType::default();
+        for member in members {
+          record.extend(self.get_object_record(member)?);
+        }
+        Some(record)
+      }
+      Ty::Instance(_) | Ty::Unresolved(UnresolvedType::UnInitType(_)) => {
+        let resolved = self.get_lowest_type(ty);
+        if resolved == ty {
+          None
+        } else {
+          self.get_object_record(resolved)
+        }
+      }
+      _ => None,
+    }
+  }
+
   fn serialize_keyed_property(
     &mut self,
     key: PropertyKey<'a>,
+    computed: bool,
     property: &RecordPropertyValue<'a>,
   ) -> TSSignature<'a> {
     self.ast_builder.ts_signature_property_signature(
       SPAN,
-      false,
+      computed,
       property.optional,
       property.readonly,
       key,
@@ -217,7 +387,7 @@ impl<'a> Analyzer<'a> {
       SPAN,
       self.ast_builder.vec1(self.ast_builder.ts_index_signature_name(
         SPAN,
-        "1",
+        "key",
         self.ast_builder.ts_type_annotation(SPAN, key_type),
       )),
       self.ast_builder.ts_type_annotation(SPAN, self.serialize_type(property.value)),
@@ -231,11 +401,17 @@ impl<'a> Analyzer<'a> {
     for (key, property) in &record.string_keyed.0 {
       members.push(self.serialize_keyed_property(
         self.ast_builder.property_key_identifier_name(SPAN, *key),
+        false,
         property,
       ));
     }
     for (key, property) in &record.symbol_keyed.0 {
-      members.push(self.serialize_keyed_property(todo!(), property));
+      // Unique symbols are printed as the expressions referencing them, like `[Symbol.iterator]`
+      let Some(name) = self.unique_symbol_names.get(key) else {
+        continue;
+      };
+      let key = PropertyKey::from(name.clone_in(self.allocator));
+      members.push(self.serialize_keyed_property(key, true, property));
     }
     if let Some(node) = self.serialize_mapped_property(
       self.ast_builder.ts_type_number_keyword(SPAN),
@@ -0,0 +1,212 @@
+use oxc::{
+  ast::ast::{TSType, TemplateElementValue},
+  span::{Atom, SPAN},
+};
+use oxc_syntax::number::ToJsString;
+use rustc_hash::FxHashMap;
+
+use super::{r#match::MatchResult, Ty};
+use crate::Analyzer;
+
+/// A template literal type which can not be expanded to string literals, like `` `a${string}` ``.
+#[derive(Debug)]
+pub struct TemplateLiteralType<'a> {
+  /// Always one more than `types`
+  pub quasis: Vec<&'a str>,
+  /// Only `string`, `number`, `bigint`, and the types which depend on type parameters
+  pub types: Vec<Ty<'a>>,
+}
+
+/// The text before the holes, the holes each with the text after it, and the text after the holes
+type TemplateParts<'a> = (String, Vec<(Ty<'a>, String)>, String);
+
+impl<'a> Analyzer<'a> {
+  /// Create the type of `` `${quasis[0]}${types[0]}${quasis[1]}...` ``, which is distributive over
+  /// the union members in `types`.
+  pub fn create_template_literal_type(&mut self, quasis: &[&'a str], types: &[Ty<'a>]) -> Ty<'a> {
+    // The expanded templates, each with the quasis and the holes so far
+    let mut expanded = vec![(String::from(quasis[0]), vec![], vec![])];
+    for (ty, quasi) in types.iter().zip(&quasis[1..]) {
+      let mut members = vec![];
+      match *ty {
+        Ty::Union(union) => union.for_each(|ty| members.push(ty)),
+        ty => members.push(ty),
+      }
+      let mut next = vec![];
+      for (text, prev_quasis, holes) in &expanded {
+        for member in &members {
+          let Some(parts) = self.get_template_parts(*member) else {
+            return Ty::Error;
+          };
+          for (head, inner_holes, tail) in parts {
+            let mut text = text.clone();
+            let mut prev_quasis = prev_quasis.clone();
+            let mut holes = holes.clone();
+            text.push_str(&head);
+            for (hole, quasi) in inner_holes {
+              prev_quasis.push(self.allocator.alloc_str(&text) as &str);
+              holes.push(hole);
+              text = quasi;
+            }
+            text.push_str(&tail);
+            text.push_str(quasi);
+            next.push((text, prev_quasis, holes));
+          }
+        }
+      }
+      expanded = next;
+    }
+
+    let types = expanded
+      .into_iter()
+      .map(|(text, mut quasis, types)| {
+        let text = self.allocator.alloc_str(&text);
+        if types.is_empty() {
+          Ty::StringLiteral(self.allocator.alloc(Atom::from(&*text)))
+        } else if types == [Ty::String] && quasis == [""] && text.is_empty() {
+          Ty::String
+        } else {
+          quasis.push(text);
+          Ty::TemplateLiteral(self.allocator.alloc(TemplateLiteralType { quasis, types }))
+        }
+      })
+      .collect::<Vec<_>>();
+    self.into_union(types).unwrap_or(Ty::Never)
+  }
+
+  /// The parts of a non-union type in a template literal type, or `None` if it is not allowed.
+  fn get_template_parts(&mut self, ty: Ty<'a>) -> Option<Vec<TemplateParts<'a>>> {
+    let text = |s: String| Some(vec![(s, vec![], String::new())]);
+    match ty {
+      Ty::StringLiteral(s) => text(s.to_string()),
+      Ty::NumericLiteral(n) => text(n.0.to_js_string()),
+      Ty::BigIntLiteral(n) => text(n.trim_end_matches('n').to_string()),
+      Ty::BooleanLiteral(b) => text(b.to_string()),
+      Ty::Null => text("null".to_string()),
+      Ty::Undefined => text("undefined".to_string()),
+      Ty::Boolean => Some(vec![
+        ("true".to_string(), vec![], String::new()),
+        ("false".to_string(), vec![], String::new()),
+      ]),
+      Ty::Never => Some(vec![]),
+      Ty::String | Ty::Number | Ty::BigInt | Ty::Any | Ty::Unresolved(_) => {
+        Some(vec![(String::new(), vec![(ty, String::new())], String::new())])
+      }
+      // Nested template literal types are flattened
+      Ty::TemplateLiteral(template) => {
+        let holes = template
+          .types
+          .iter()
+          .zip(&template.quasis[1..])
+          .map(|(ty, quasi)| (*ty, quasi.to_string()))
+          .collect();
+        Some(vec![(template.quasis[0].to_string(), holes, String::new())])
+      }
+      Ty::Instance(instance) => {
+        let unwrapped = self.unwrap_generic_instance(instance);
+        match unwrapped {
+          Ty::Union(union) => {
+            let mut members = vec![];
+            union.for_each(|ty| members.push(ty));
+            let mut parts = vec![];
+            for member in members {
+              parts.extend(self.get_template_parts(member)?);
+            }
+            Some(parts)
+          }
+          _ => self.get_template_parts(unwrapped),
+        }
+      }
+      _ => None,
+    }
+  }
+
+  /// Match the string literal `text` against a template literal type, where each hole takes the
+  /// shortest text before the next quasi.
+  pub fn match_template_literal(
+    &mut self,
+    specificity: i32,
+    text: &str,
+    pattern: &TemplateLiteralType<'a>,
+  ) -> MatchResult<'a> {
+    let last = pattern.quasis.len() - 1;
+    let (head, tail) = (pattern.quasis[0], pattern.quasis[last]);
+    if text.len() < head.len() + tail.len() || !text.starts_with(head) || !text.ends_with(tail) {
+      return MatchResult::Unmatched;
+    }
+    let mut rest = &text[head.len()..text.len() - tail.len()];
+
+    let mut inferred = FxHashMap::default();
+    for (index, hole) in pattern.types.iter().enumerate() {
+      let quasi = pattern.quasis[index + 1];
+      let matched = if index + 1 == last {
+        rest
+      } else if quasi.is_empty() {
+        // Without a delimiter, the hole takes one character
+        let Some(c) = rest.chars().next() else {
+          return MatchResult::Unmatched;
+        };
+        &rest[..c.len_utf8()]
+      } else {
+        let Some(end) = rest.find(quasi) else {
+          return MatchResult::Unmatched;
+        };
+        &rest[..end]
+      };
+      rest = &rest[matched.len()..];
+      if index + 1 != last {
+        rest = &rest[quasi.len()..];
+      }
+
+      let matched_ty = match hole {
+        Ty::Number if !is_numeric_string(matched) => return MatchResult::Unmatched,
+        Ty::BigInt if !is_bigint_string(matched) => return MatchResult::Unmatched,
+        Ty::Number | Ty::BigInt => continue,
+        _ => {
+          Ty::StringLiteral(self.allocator.alloc(Atom::from(&*self.allocator.alloc_str(matched))))
+        }
+      };
+      match self.match_covariant_types(specificity, matched_ty, *hole) {
+        MatchResult::Error => return MatchResult::Error,
+        MatchResult::Unmatched => return MatchResult::Unmatched,
+        MatchResult::Matched => {}
+        MatchResult::Inferred(map) => inferred.extend(map),
+      }
+    }
+    MatchResult::Inferred(inferred)
+  }
+
+  pub fn serialize_template_literal_type(
+    &mut self,
+    template: &TemplateLiteralType<'a>,
+  ) -> TSType<'a> {
+    let mut quasis = self.ast_builder.vec();
+    for (index, quasi) in template.quasis.iter().enumerate() {
+      let raw = quasi.replace('\\', "\\\\").replace('`', "\\`").replace("${", "\\${");
+      let value = TemplateElementValue {
+        raw: Atom::from(&*self.allocator.alloc_str(&raw)),
+        cooked: Some(Atom::from(*quasi)),
+      };
+      quasis.push(self.ast_builder.template_element(
+        SPAN,
+        index == template.quasis.len() - 1,
+        value,
+      ));
+    }
+    let mut types = self.ast_builder.vec();
+    for ty in &template.types {
+      types.push(self.serialize_type(*ty));
+    }
+    self.ast_builder.ts_type_template_literal_type(SPAN, quasis, types)
+  }
+}
+
+/// Whether `s` is the text of a finite number, like `ToNumber` without the whitespaces.
+fn is_numeric_string(s: &str) -> bool {
+  !s.is_empty() && s.trim() == s && s.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+fn is_bigint_string(s: &str) -> bool {
+  let digits = s.strip_prefix('-').unwrap_or(s);
+  !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
@@ -1,5 +1,5 @@
 use oxc::{
-  ast::ast::{TSTupleElement, TSType},
+  ast::ast::{TSTupleElement, TSType, TSTypeOperatorOperator},
   span::{Atom, SPAN},
 };
 
@@ -45,19 +45,98 @@ impl<'a> TupleType<'a> {
       PropertyKeyType::StringLiteral(s) => {
         if let Some(index) = s.parse::<usize>().ok() {
           self.get_element_by_index(index, analyzer)
+        } else if s.as_str() == "length" {
+          self.get_length(analyzer)
         } else {
-          todo!("Array prototype");
+          self.get_array_property(key, analyzer)
         }
       }
       PropertyKeyType::NumericLiteral(n) => {
         let index = n.0 as usize;
         self.get_element_by_index(index, analyzer)
       }
-      PropertyKeyType::UniqueSymbol(s) => {
-        todo!("Array prototype");
+      PropertyKeyType::UniqueSymbol(_) => self.get_array_property(key, analyzer),
+    }
+  }
+
+  /// The types of the first `len` elements, and the tuple of the rest elements if `need_rest`.
+  pub fn destruct(
+    &self,
+    len: usize,
+    need_rest: bool,
+    analyzer: &mut Analyzer<'a>,
+  ) -> (Vec<Ty<'a>>, Option<Ty<'a>>) {
+    let mut values = Vec::with_capacity(len);
+    let mut rest = vec![];
+    for (index, element) in self.elements.iter().enumerate() {
+      if element.spread {
+        // Elements after a spread are no longer at fixed positions
+        let spread = &self.elements[index..];
+        let types =
+          spread
+            .iter()
+            .map(|element| {
+              if element.spread {
+                analyzer.iterate_result_union(element.ty)
+              } else {
+                element.ty
+              }
+            })
+            .collect::<Vec<_>>();
+        let value = analyzer.into_union(types).unwrap_or(Ty::Never);
+        values.resize(len, value);
+        rest.extend(spread);
+        break;
+      }
+      if values.len() < len {
+        values.push(if element.optional {
+          analyzer.into_union([element.ty, Ty::Undefined]).unwrap()
+        } else {
+          element.ty
+        });
+      } else {
+        rest.push(element);
       }
     }
-    // self.0.get(index).map(|e| e.ty)
+    // Destructuring past the end gives `undefined`
+    values.resize(len, Ty::Undefined);
+
+    let rest = need_rest.then(|| {
+      Ty::Tuple(
+        analyzer.allocator.alloc(TupleType {
+          elements: rest
+            .into_iter()
+            .map(|element| TupleElement {
+              name: element.name,
+              spread: element.spread,
+              optional: element.optional,
+              ty: element.ty,
+            })
+            .collect(),
+          readonly: false,
+        }),
+      )
+    });
+    (values, rest)
+  }
+
+  /// The union of the possible lengths, or `number` with spread elements.
+  fn get_length(&self, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
+    if self.elements.iter().any(|element| element.spread) {
+      return Ty::Number;
+    }
+    let required = self.elements.iter().filter(|element| !element.optional).count();
+    let lengths = (required..=self.elements.len())
+      .map(|len| Ty::NumericLiteral((len as f64).into()))
+      .collect::<Vec<_>>();
+    analyzer.into_union(lengths).unwrap()
+  }
+
+  /// Properties other than the elements are from the array prototype.
+  fn get_array_property(&self, key: PropertyKeyType<'a>, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
+    let element = self.iterate_result_union(analyzer);
+    let array = analyzer.create_array_type(element, self.readonly);
+    analyzer.get_property(array, key)
   }
 
   fn get_element_by_index(&self, index: usize, analyzer: &mut Analyzer<'a>) -> Ty<'a> {
@@ -105,6 +184,11 @@ impl<'a> Analyzer<'a> {
       }
       elements.push(node);
     }
-    self.ast_builder.ts_type_tuple_type(SPAN, elements)
+    let node = self.ast_builder.ts_type_tuple_type(SPAN, elements);
+    if tuple.readonly {
+      self.ast_builder.ts_type_type_operator(SPAN, TSTypeOperatorOperator::Readonly, node)
+    } else {
+      node
+    }
   }
 }
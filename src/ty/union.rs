@@ -37,18 +37,28 @@ impl<'a> UnionTypeBuilder<'a> {
 
       (s, Ty::Instance(u)) => {
         let resolved = analyzer.unwrap_generic_instance(u);
-        s.add(analyzer, resolved);
+        if matches!(resolved, Ty::Interface(_)) {
+          // Keep the instance, so that it can be printed by name
+          s.add_to_compound(ty);
+        } else {
+          s.add(analyzer, resolved);
+        }
       }
 
       // The rest should be added to compound
-      (s @ UnionTypeBuilder::Never, c) => {
+      (s, c) => s.add_to_compound(c),
+    }
+  }
+
+  fn add_to_compound(&mut self, ty: Ty<'a>) {
+    match self {
+      UnionTypeBuilder::Never => {
         let mut compound = Box::new(UnionType::default());
-        compound.add(c);
-        *s = UnionTypeBuilder::Compound(compound);
-      }
-      (UnionTypeBuilder::Compound(compound), c) => {
-        compound.add(c);
+        compound.add(ty);
+        *self = UnionTypeBuilder::Compound(compound);
       }
+      UnionTypeBuilder::Compound(compound) => compound.add(ty),
+      _ => unreachable!(),
     }
   }
 
@@ -58,7 +68,21 @@ impl<'a> UnionTypeBuilder<'a> {
       UnionTypeBuilder::Error => Ty::Error,
       UnionTypeBuilder::Any => Ty::Any,
       UnionTypeBuilder::Unknown => Ty::Unknown,
-      UnionTypeBuilder::Compound(compound) => Ty::Union(analyzer.allocator.alloc(compound)),
+      UnionTypeBuilder::Compound(mut compound) => {
+        // Without `strictNullChecks`, `null` and `undefined` are absorbed by the other members
+        if !analyzer.config.strict_null_checks && (compound.null || compound.undefined) {
+          let mut rest = compound.clone();
+          (rest.null, rest.undefined) = (false, false);
+          let mut members = vec![];
+          rest.for_each(|ty| members.push(ty));
+          match members.as_slice() {
+            [] => {}
+            [ty] => return *ty,
+            _ => compound = rest,
+          }
+        }
+        Ty::Union(analyzer.allocator.alloc(compound))
+      }
     }
   }
 }
@@ -77,7 +101,8 @@ pub struct UnionType<'a> {
   /// (has_true, has_false)
   pub boolean: (bool, bool),
 
-  pub complex: FxHashSet<Ty<'a>>,
+  /// In insertion order, so that they are printed deterministically
+  pub complex: Vec<Ty<'a>>,
   pub unresolved: Vec<UnresolvedType<'a>>,
 }
 
@@ -103,12 +128,17 @@ impl<'a> UnionType<'a> {
       Ty::BooleanLiteral(false) => self.boolean.1 = true,
 
       Ty::Record(_)
+      | Ty::Tuple(_)
+      | Ty::Instance(_)
       | Ty::Function(_)
       | Ty::Constructor(_)
       | Ty::Interface(_)
       | Ty::Namespace(_)
+      | Ty::TemplateLiteral(_)
       | Ty::Intersection(_) => {
-        self.complex.insert(ty);
+        if !self.complex.contains(&ty) {
+          self.complex.push(ty);
+        }
       }
 
       Ty::Unresolved(unresolved) => self.unresolved.push(unresolved),
@@ -254,6 +284,9 @@ impl<'a> Analyzer<'a> {
   pub fn serialize_union_type(&mut self, union: &UnionType<'a>) -> TSType<'a> {
     let mut types = self.ast_builder.vec();
     union.for_each(|ty| types.push(self.serialize_type(ty)));
+    if types.len() == 1 {
+      return types.pop().unwrap();
+    }
     self.ast_builder.ts_type_union_type(SPAN, types)
   }
 }
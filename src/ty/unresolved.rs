@@ -16,6 +16,10 @@ pub enum UnresolvedType<'a> {
   UnInitType(SymbolId),
   GenericParam(SymbolId),
   Keyof(&'a Ty<'a>),
+  /// `T[K]` where `T` or `K` is a type parameter
+  IndexedAccess(&'a Ty<'a>, &'a Ty<'a>),
+  /// `Awaited<T>` where `T` is a type parameter
+  Awaited(&'a Ty<'a>),
   InferType(SymbolId),
   Placeholder(usize),
 }
@@ -26,22 +30,98 @@ impl<'a> Analyzer<'a> {
     Ty::Unresolved(UnresolvedType::Placeholder(self.type_placeholder_count))
   }
 
+  /// Whether `ty` depends on an uninstantiated type parameter, so that the types computed from it
+  /// should be deferred.
+  pub fn is_type_parameter_dependent(&self, ty: Ty<'a>) -> bool {
+    match ty {
+      Ty::Unresolved(unresolved) => match unresolved {
+        UnresolvedType::UnInitType(symbol) => {
+          self.semantic.symbols().get_flags(symbol).is_type_parameter()
+        }
+        UnresolvedType::GenericParam(_) | UnresolvedType::InferType(_) => true,
+        UnresolvedType::Keyof(ty) | UnresolvedType::Awaited(ty) => {
+          self.is_type_parameter_dependent(*ty)
+        }
+        UnresolvedType::IndexedAccess(object, index) => {
+          self.is_type_parameter_dependent(*object) || self.is_type_parameter_dependent(*index)
+        }
+        UnresolvedType::UnInitVariable(_) | UnresolvedType::Placeholder(_) => false,
+      },
+      _ => false,
+    }
+  }
+
+  /// Whether `ty` may reference a type parameter anywhere inside it. Callables and anonymous
+  /// interfaces are assumed to, since their members are resolved lazily.
+  pub fn contains_type_parameter(&self, ty: Ty<'a>) -> bool {
+    match ty {
+      Ty::Unresolved(_) => self.is_type_parameter_dependent(ty),
+      Ty::Union(union) => {
+        union.complex.iter().any(|ty| self.contains_type_parameter(*ty))
+          || union.unresolved.iter().any(|u| self.is_type_parameter_dependent(Ty::Unresolved(*u)))
+      }
+      Ty::Intersection(intersection) => {
+        intersection.object_like.iter().any(|ty| self.contains_type_parameter(*ty))
+          || intersection
+            .unresolved
+            .iter()
+            .any(|u| self.is_type_parameter_dependent(Ty::Unresolved(*u)))
+      }
+      Ty::Tuple(tuple) => {
+        tuple.elements.iter().any(|element| self.contains_type_parameter(element.ty))
+      }
+      Ty::Record(record) => {
+        let mut contains = false;
+        record.for_each_value(|value| contains |= self.contains_type_parameter(value));
+        contains
+      }
+      Ty::Instance(instance) => instance.args.iter().any(|arg| self.contains_type_parameter(*arg)),
+      Ty::Function(_) | Ty::Constructor(_) | Ty::Namespace(_) => true,
+      Ty::Interface(interface) => {
+        if self.named_types.contains_key(&ty) || self.builtins.global_type_names.contains_key(&ty) {
+          return false;
+        }
+        // Held while the members are checked, so that a recursive reference is assumed to contain
+        // one
+        let Ok(inner) = interface.0.try_borrow_mut() else {
+          return true;
+        };
+        let mut contains = !inner.callables.is_empty() || !inner.unresolved_extends.is_empty();
+        inner.record.for_each_value(|value| contains |= self.contains_type_parameter(value));
+        contains
+      }
+      _ => false,
+    }
+  }
+
   pub fn serialize_unresolved_type(&mut self, unresolved: UnresolvedType<'a>) -> TSType<'a> {
     match unresolved {
       UnresolvedType::UnInitVariable(symbol) => todo!(),
-      UnresolvedType::UnInitType(symbol) => todo!(),
-      UnresolvedType::GenericParam(symbol) => self.ast_builder.ts_type_type_reference(
-        SPAN,
-        TSTypeName::IdentifierReference(
-          self.ast_builder.alloc(self.serialize_identifier_reference(symbol)),
-        ),
-        NONE,
-      ),
+      UnresolvedType::UnInitType(symbol) | UnresolvedType::GenericParam(symbol) => {
+        self.ast_builder.ts_type_type_reference(
+          SPAN,
+          TSTypeName::IdentifierReference(
+            self.ast_builder.alloc(self.serialize_identifier_reference(symbol)),
+          ),
+          NONE,
+        )
+      }
       UnresolvedType::Keyof(ty) => self.ast_builder.ts_type_type_operator(
         SPAN,
         TSTypeOperatorOperator::Keyof,
         self.serialize_type(*ty),
       ),
+      UnresolvedType::IndexedAccess(object, index) => {
+        let object = self.serialize_type(*object);
+        let index = self.serialize_type(*index);
+        self.ast_builder.ts_type_indexed_access_type(SPAN, object, index)
+      }
+      UnresolvedType::Awaited(ty) => {
+        let ty = self.serialize_type(*ty);
+        let params =
+          self.ast_builder.ts_type_parameter_instantiation(SPAN, self.ast_builder.vec1(ty));
+        self.serialize_global_type_reference("Awaited", Some(self.ast_builder.alloc(params)))
+      }
       UnresolvedType::InferType(symbol) => self.ast_builder.ts_type_infer_type(
         SPAN,
         self.ast_builder.ts_type_parameter(
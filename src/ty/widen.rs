@@ -18,7 +18,7 @@ impl<'a> Analyzer<'a> {
       | Ty::Symbol
       | Ty::Undefined => ty,
 
-      Ty::StringLiteral(_) => Ty::String,
+      Ty::StringLiteral(_) | Ty::TemplateLiteral(_) => Ty::String,
       Ty::NumericLiteral(_) => Ty::Number,
       Ty::BigIntLiteral(_) => Ty::BigInt,
       Ty::BooleanLiteral(_) => Ty::Boolean,
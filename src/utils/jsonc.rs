@@ -0,0 +1,66 @@
+use serde_json::Value;
+
+/// Parse JSON with comments and trailing commas, like `tsconfig.json`.
+pub fn parse_jsonc(source: &str) -> serde_json::Result<Value> {
+  serde_json::from_str(&strip_jsonc(source))
+}
+
+fn strip_jsonc(source: &str) -> String {
+  let mut result = String::with_capacity(source.len());
+  let mut chars = source.chars().peekable();
+  // The position in `result` of the last comma, if only whitespaces follow it
+  let mut pending_comma = None;
+  while let Some(c) = chars.next() {
+    match c {
+      '"' => {
+        result.push(c);
+        while let Some(c) = chars.next() {
+          result.push(c);
+          match c {
+            '\\' => result.extend(chars.next()),
+            '"' => break,
+            _ => {}
+          }
+        }
+      }
+      '/' if chars.peek() == Some(&'/') => {
+        for c in chars.by_ref() {
+          if c == '\n' {
+            result.push(c);
+            break;
+          }
+        }
+        continue;
+      }
+      '/' if chars.peek() == Some(&'*') => {
+        chars.next();
+        let mut last = '\0';
+        for c in chars.by_ref() {
+          if last == '*' && c == '/' {
+            break;
+          }
+          last = c;
+        }
+        continue;
+      }
+      ']' | '}' => {
+        if let Some(comma) = pending_comma {
+          result.replace_range(comma..comma + 1, " ");
+        }
+        result.push(c);
+      }
+      ',' => {
+        pending_comma = Some(result.len());
+        result.push(c);
+        continue;
+      }
+      c if c.is_whitespace() => {
+        result.push(c);
+        continue;
+      }
+      _ => result.push(c),
+    }
+    pending_comma = None;
+  }
+  result
+}
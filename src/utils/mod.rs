@@ -1,6 +1,10 @@
 mod f64_with_eq;
 mod function_name;
+mod jsonc;
+mod normalize_path;
 mod private_identifier_name;
 mod serialize;
 
 pub use f64_with_eq::*;
+pub use jsonc::*;
+pub use normalize_path::*;
@@ -0,0 +1,18 @@
+use std::path::{Component, Path, PathBuf};
+
+/// Remove `.` and `..` components without touching the file system.
+pub fn normalize_path(path: &Path) -> PathBuf {
+  let mut result = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        if !result.pop() {
+          result.push(component);
+        }
+      }
+      _ => result.push(component),
+    }
+  }
+  result
+}
@@ -0,0 +1,33 @@
+import type { Foo } from './foo'
+
+interface Options {
+  name: string
+  count?: number
+}
+
+export type Mode = 'a' | 'b'
+
+export const version = 1
+export let counter = 0
+export const options: Options = { name: 'x' }
+export const record = { a: 1, b: 'b' }
+
+export function add(a: number, b = 1) {
+  return a + b
+}
+
+export function describe(options: Options, ...rest: string[]) {
+  return rest
+}
+
+export function identity<T>(value: T): T {
+  return value
+}
+
+const local = 'local'
+const hidden = 1
+export { local as renamed }
+
+export default function (flag: boolean) {
+  return flag ? 'yes' : 'no'
+}
@@ -0,0 +1,35 @@
+export class Counter {
+  count = 0;
+  private step = 1;
+  #secret = 'secret';
+  static readonly initial = 0;
+
+  constructor(public readonly name: string, start = 0) {
+    this.count = start;
+  }
+
+  increment() {
+    this.count += this.step;
+    return this.count;
+  }
+
+  get doubled() {
+    return this.count * 2;
+  }
+
+  set doubled(value: number) {
+    this.count = value / 2;
+  }
+
+  static create(name: string) {
+    return new Counter(name);
+  }
+}
+
+export class NamedCounter extends Counter {
+  label = 'counter';
+
+  describe() {
+    return this.name + this.label;
+  }
+}
@@ -0,0 +1,3 @@
+export const first = 1, second = 'two'
+
+export default { first, second }
@@ -0,0 +1,3 @@
+export default function double(value = 1) {
+  return value * 2
+}
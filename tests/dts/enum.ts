@@ -0,0 +1,11 @@
+export enum Status {
+  Active = 1,
+  Inactive,
+}
+
+export const enum Mode {
+  Read = 'r',
+  Write = 'w',
+}
+
+export const defaultStatus = Status.Active;
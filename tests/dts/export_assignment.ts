@@ -0,0 +1,7 @@
+const config = {
+  parse(input: string) {
+    return input.length
+  },
+}
+
+export = config
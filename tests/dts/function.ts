@@ -0,0 +1,32 @@
+export function parse(value: string): number
+export function parse(value: number): number
+export function parse(value: any) {
+  return Number(value)
+}
+
+export async function load(url: string) {
+  return url.length
+}
+
+export function* count(limit: number) {
+  yield limit
+}
+
+export const format = (value: number, unit = 'px', ...rest: string[]) => value + unit
+export const isString = function (value: unknown): value is string {
+  return typeof value === 'string'
+}
+
+export class Parser {
+  constructor(public strict = false) {}
+
+  parse(value: string): number
+  parse(value: number): number
+  parse(value: any) {
+    return Number(value)
+  }
+
+  async load() {
+    return 1
+  }
+}
@@ -0,0 +1,22 @@
+class Hidden {
+  x = 1
+}
+
+interface Shape {
+  hidden: Hidden
+}
+
+function makeHidden() {
+  return new Hidden()
+}
+
+export const hidden = makeHidden()
+export type Wrapped = { shape: Shape }
+
+enum Color {
+  Red,
+  Green,
+}
+export { Color }
+
+const unused = 1
@@ -0,0 +1,36 @@
+export namespace Shapes {
+  export const origin = 0
+  const scale = 2
+
+  export function area(width: number, height: number) {
+    return width * height * scale
+  }
+
+  export namespace Units {
+    export let name = 'px'
+  }
+}
+
+namespace Local {
+  export interface Point {
+    x: number
+  }
+}
+
+namespace Unused {
+  export const value = 1
+}
+
+export type LocalPoint = Local.Point
+
+export const handlers = {
+  onClick(x: number, y: number) {
+    return x + y
+  },
+  onKey: (key: string) => key.length,
+  nested: {
+    onDone: function (ok: boolean) {
+      return ok
+    },
+  },
+}
@@ -0,0 +1,55 @@
+const numbers = [1, 2, 3];
+//    ^? numbers
+
+const mixed = [1, "a", true];
+//    ^? mixed
+
+const empty = [];
+//    ^? empty
+
+const tuple = [1, "a"] as const;
+//    ^? tuple
+
+const spread = [...numbers, "b"];
+//    ^? spread
+
+const chars = [..."abc"];
+//    ^? chars
+
+const first = numbers[0];
+//    ^? first
+
+declare const index: number;
+const element = mixed[index];
+//    ^? element
+
+const length = numbers.length;
+//    ^? length
+
+const mapped = numbers.map(function (n) { return String(n); });
+//    ^? mapped
+
+const tupleFirst = tuple[0];
+//    ^? tupleFirst
+
+const tupleLength = tuple.length;
+//    ^? tupleLength
+
+const tupleJoined = tuple.join(",");
+//    ^? tupleJoined
+
+declare const optional: [string, number?];
+const optionalLength = optional.length;
+//    ^? optionalLength
+
+declare const generic: Array<string>;
+//            ^? generic
+
+declare const readonlyArray: ReadonlyArray<number>;
+//            ^? readonlyArray
+
+const sliced = readonlyArray.slice(1);
+//    ^? sliced
+
+const annotated: (string | number)[] = [1];
+//    ^? annotated
@@ -0,0 +1,62 @@
+const add = (a: number, b: number) => a + b;
+//    ^? Add
+
+const block = (flag: boolean) => {
+//    ^? Block
+  if (flag) {
+    return "yes";
+  }
+  return 0;
+};
+
+const annotated = (): string[] => [];
+//    ^? Annotated
+
+const asyncArrow = async (value: number) => value;
+//    ^? AsyncArrow
+
+const defaulted = (count = 1) => count;
+//    ^? Defaulted
+
+declare const numbers: number[];
+const mapped = numbers.map((n) => String(n));
+//    ^? Mapped
+
+const filtered = numbers.filter((n, index) => n > index);
+//    ^? Filtered
+
+const handler: (event: { x: number }, ...rest: string[]) => void = (event, first) => {
+  event;
+//^? Event
+  first;
+//^? First
+};
+
+interface Props {
+  onChange?: (value: string) => void;
+}
+const props: Props = {
+  onChange: (value) => {
+    value;
+  //^? Value
+  },
+};
+
+class Counter {
+  count = 0;
+  increment() {
+    const step = () => this;
+    //    ^? Step
+  }
+}
+
+numbers.map((n) => {
+  n;
+//^? Argument
+});
+
+declare function h(callback: (m: string) => void): void;
+h((m) => {
+  m;
+//^? CallbackArgument
+});
@@ -0,0 +1,63 @@
+interface Thenable<T> {
+  then(onfulfilled: (value: T) => void): void;
+}
+
+async function main(
+  promise: Promise<number>,
+  nested: Promise<Promise<string>>,
+  either: Promise<number> | boolean,
+  thenable: Thenable<"done">,
+) {
+  const a = await promise;
+  //    ^? A
+  const b = await nested;
+  //    ^? B
+  const c = await either;
+  //    ^? C
+  const d = await thenable;
+  //    ^? D
+  const e = await 1;
+  //    ^? E
+}
+
+async function empty() {}
+const r1 = empty();
+//    ^? R1
+
+async function returnsValue() {
+  return 1;
+}
+const r2 = returnsValue();
+//    ^? R2
+
+async function returnsPromise(flag: boolean, promise: Promise<number>) {
+  if (flag) {
+    return promise;
+  }
+  return "none";
+}
+const r3 = returnsPromise(true, Promise.resolve(1));
+//    ^? R3
+
+async function annotated(): Promise<number[]> {
+  return [];
+}
+const r4 = annotated();
+//    ^? R4
+
+async function generic<T>(value: T) {
+  return await value;
+}
+const r5 = generic(Promise.resolve("a"));
+//    ^? R5
+const r6 = generic<Promise<"a"> | null>(null);
+//    ^? R6
+
+type Unwrapped = Awaited<Promise<PromiseLike<boolean>>>;
+//   ^? Unwrapped
+
+function deferred<T>(value: T): Awaited<T> {
+  return value as any;
+}
+const r7 = deferred(Promise.resolve(1));
+//    ^? R7
@@ -0,0 +1,72 @@
+const s = "hello";
+const len = s.length;
+//    ^? Len
+const upper = s.toUpperCase();
+//    ^? Upper
+
+const n = parseInt("42");
+//    ^? N
+const fixed = (1).toFixed(2);
+//    ^? Fixed
+const max = Math.max(1, 2, 3);
+//    ^? Max
+
+declare const arr: Array<number>;
+const first = arr[0];
+//    ^? First
+const joined = arr.join(",");
+//    ^? Joined
+const arr2 = arr;
+//    ^? Arr
+
+declare const p: Promise<string>;
+const p2 = p;
+//    ^? P
+
+type T1 = NonNullable<string | null | undefined>;
+const t1: T1 = "";
+//    ^? T1
+
+const num = Number("1");
+//    ^? Num
+const date = new Date();
+const time = date.getTime();
+//    ^? Time
+const maybe: Array<number> | string = "";
+//    ^? Maybe
+
+const set = new Set([1, 2]);
+//    ^? NumberSet
+
+declare function head<T>(items: readonly T[]): T;
+const h = head([1, 2]);
+//    ^? Head
+declare function headOrNull<T>(items: readonly T[] | null): T;
+const hn = headOrNull([1, 2]);
+//    ^? HeadOrNull
+
+const entries = Object.entries({ a: 1 });
+//    ^? Entries
+const all = Promise.all([Promise.resolve(1), "a"]);
+//    ^? All
+const fromArray = Array.from([1]);
+//    ^? FromArray
+const filled = [1].fill(0);
+//    ^? Filled
+
+const weakMap = new WeakMap<object, number>();
+const weakValue = weakMap.get({});
+//    ^? WeakValue
+const weakSet = new WeakSet<object>();
+const weakHas = weakSet.has({});
+//    ^? WeakHas
+
+const bytes = new Uint8Array(new ArrayBuffer(8));
+//    ^? Bytes
+const byte = bytes[0];
+//    ^? Byte
+
+const ownKeys = Reflect.ownKeys({});
+//    ^? OwnKeys
+const proxy = new Proxy({ a: 1 }, {});
+//    ^? Proxied
@@ -0,0 +1,167 @@
+class Animal {
+  name: string;
+  legs = 4;
+  readonly kind = 'animal';
+  nickname?: string;
+
+  constructor(name: string) {
+    this.name = name;
+  }
+
+  describe() {
+    return this.name;
+  }
+
+  get loud() {
+    return this.name.length > 3;
+  }
+
+  set loud(value: boolean) {}
+
+  static count = 0;
+
+  static create(name: string) {
+    return new Animal(name);
+  }
+}
+
+const animal = new Animal('cat');
+//    ^? A
+  animal.legs
+//       ^? Legs
+  animal.kind
+//       ^? Kind
+  animal.describe()
+//       ^? Describe
+  animal.loud
+//       ^? Loud
+  Animal.count
+//       ^? Count
+  Animal.create
+//       ^? Create
+
+class Dog extends Animal {
+  constructor(public readonly breed: string, private age?: number) {
+    super(breed);
+  }
+
+  describe() {
+    return super.describe() + this.breed;
+  }
+
+  bark(): 'woof' {
+    return 'woof';
+  }
+}
+
+const dog = new Dog('husky');
+  dog.breed
+//    ^? Breed
+  dog.name
+//    ^? Name
+  dog.bark
+//    ^? Bark
+  Dog.create
+//    ^? InheritedStatic
+
+class Point {
+  constructor(public x: number, public y: number) {}
+}
+
+  Point
+//^? PointClass
+const point: Point = new Point(1, 2);
+  point.x
+//      ^? X
+
+const Anonymous = class {
+  value = 'a';
+};
+const anonymous = new Anonymous();
+//    ^? AnonymousInstance
+
+interface Labeled {
+  label: string;
+}
+
+class Tag implements Labeled {
+  label = 'tag';
+}
+
+const tag = new Tag();
+  tag.label
+//    ^? Label
+
+class Counter {
+  constructor(public step = 1, readonly label: string = '') {}
+
+  inc() {
+    return this;
+  }
+
+  static create() {
+    return new Counter();
+  }
+}
+
+function makeCounter() {
+  return new Counter();
+}
+const arrowCounter = () => new Counter();
+
+  makeCounter()
+//^? MadeByFunction
+  arrowCounter()
+//^? MadeByArrow
+  Counter.create()
+//        ^? MadeByStatic
+  new Counter().inc()
+//              ^? ReturnedThis
+  new Counter().step
+//              ^? DefaultParameterProperty
+  new Counter().label
+//              ^? AnnotatedDefaultParameterProperty
+
+class Box<T> {
+  constructor(public value: T) {}
+
+  get() {
+    return this.value;
+  }
+
+  self() {
+    return this;
+  }
+
+  map<U>(f: (value: T) => U) {
+    return new Box(f(this.value));
+  }
+}
+
+const box = new Box(1);
+//    ^? GenericInstance
+  box.value
+//    ^? GenericProperty
+  new Box('a').get()
+//             ^? GenericMethod
+  new Box(true).self()
+//              ^? GenericThis
+  box.map(String)
+//    ^? GenericMap
+declare const annotatedBox: Box<number>;
+  annotatedBox.value
+//             ^? AnnotatedGenericProperty
+
+class StringBox extends Box<string> {}
+  new StringBox('a').value
+//                   ^? InheritedGenericProperty
+
+class Temperature {
+  set celsius(value) {
+    value
+  //^? SetterParameter
+  }
+  get celsius() {
+    return 0;
+  }
+}
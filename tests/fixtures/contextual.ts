@@ -0,0 +1,63 @@
+const compare: (a: string, b: string) => number = function (a, b) {
+  a;
+//^? A
+  return a.length - b.length;
+};
+
+interface Window {
+  title: string;
+}
+declare function listen<T>(callback: (this: Window, code: number) => T): T;
+const code = listen(function (code) {
+//    ^? Code
+  return code;
+});
+const listenThis = listen(function () {
+//    ^? ListenThis
+  return this;
+});
+
+const check = function (value) {
+//    ^? Satisfied
+  return value;
+} satisfies (value: number) => unknown;
+
+interface Overloaded {
+  (name: string): void;
+  (name: string, count: number): void;
+}
+const overloaded: Overloaded = function (name, count) {
+  count;
+//^? Count
+};
+
+const point = {
+  x: 1,
+  y: 2,
+  length() {
+    return this;
+    //     ^? PointThis
+  },
+};
+
+interface Shape {
+  area(scale: number): number;
+  name: string;
+}
+const square: Shape = {
+  name: "square",
+  area(scale) {
+    scale;
+  //^? Scale
+    this;
+  //^? ShapeThis
+    return this.name.length * scale;
+  },
+};
+
+const curried: () => (value: boolean) => void = function () {
+  return function (value) {
+    value;
+  //^? ContextualReturn
+  };
+};
@@ -0,0 +1,105 @@
+declare const pair: [number, string];
+const [
+  a,
+//^? a
+  b,
+//^? b
+] = pair;
+
+declare const triple: [number, string?, ...boolean[]];
+const [
+  c,
+//^? c
+  d,
+//^? d
+  ...others
+//   ^? others
+] = triple;
+
+declare const numbers: number[];
+const [
+  head,
+//^? head
+  ...tail
+//   ^? tail
+] = numbers;
+
+const [x = "default"] = [undefined] as [number | undefined];
+//     ^? x
+
+const [ch] = "text";
+//     ^? ch
+
+declare const either: [1, 2] | ["a"];
+const [
+  e1,
+//^? e1
+  e2,
+//^? e2
+] = either;
+
+interface Props {
+  id: number;
+  label?: string;
+  disabled: boolean;
+}
+declare const props: Props;
+const {
+  label = "none",
+//^? label
+  ...rest
+//   ^? rest
+} = props;
+
+const { 0: zero } = pair;
+//         ^? zero
+
+const merged = { ...props, extra: 1 };
+//    ^? merged
+
+declare const maybe: { a: number; b: string } | { a: boolean } | undefined;
+const spreadUnion = { ...maybe };
+//    ^? spreadUnion
+
+for (const item of numbers) {
+  item;
+//^? item
+}
+
+for (const [key, value] of [["a", 1]] as [string, number][]) {
+  key;
+//^? key
+  value;
+//^? value
+}
+
+declare function first<T>(...items: T[]): T;
+const firstItem = first(...numbers);
+//    ^? firstItem
+
+for (const [key, value] of new Map([["a", 1]])) {
+  key;
+//^? mapKey
+  value;
+//^? mapValue
+}
+
+const [firstOfSet] = new Set([1]);
+//     ^? firstOfSet
+
+declare const iterable: { [Symbol.iterator](): Iterator<boolean> };
+for (const item of iterable) {
+  item;
+//^? iterableItem
+}
+
+async function iterateAsync(values: AsyncIterable<number>, promises: Promise<string>[]) {
+  for await (const value of values) {
+    value;
+  //^? asyncValue
+  }
+  for await (const value of promises) {
+    value;
+  //^? awaitedValue
+  }
+}
@@ -0,0 +1,61 @@
+enum Direction {
+  Up,
+  Down,
+  Left = 10,
+  Right,
+}
+
+  Direction.Down
+//          ^? Down
+  Direction.Right
+//          ^? Right
+let direction: Direction = Direction.Up;
+//  ^? DirectionType
+  Direction
+//^? DirectionValue
+
+enum Color {
+  Red = 'RED',
+  Green = 'GREEN',
+}
+
+  Color.Green
+//      ^? Green
+type GreenType = Color.Green;
+//   ^? GreenTypeAlias
+
+const enum Flags {
+  None = 0,
+  A = 1 << 0,
+  B = 1 << 1,
+  AB = A | B,
+  Mixed = Flags.AB + 1,
+  Named = 'na' + 'med',
+}
+
+  Flags.AB
+//      ^? AB
+  Flags.Mixed
+//      ^? Mixed
+  Flags.Named
+//      ^? Named
+
+enum Computed {
+  Length = 'abc'.length,
+  Dynamic = Math.random(),
+}
+
+  Computed.Length
+//         ^? Length
+  Computed.Dynamic
+//         ^? Dynamic
+
+function move(direction: Direction, fallback: Color) {
+  if (direction === Direction.Left) {
+    return fallback;
+  }
+  return direction;
+}
+
+const moved = move(Direction.Up, Color.Red);
+//    ^? Moved
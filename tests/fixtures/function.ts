@@ -14,3 +14,19 @@ function f3<T>(): T {
 }
 const t3 = f3<3>()
 //    ^? T3
+
+declare function o2(x: string): 1;
+declare function o2(x: number): 2;
+const t4 = o2(5);
+//    ^? T4
+
+const t5 = [1, 2].reduce((a, v) => a + v, '');
+//    ^? T5
+
+function f6(x: string): string;
+function f6(x: number): number;
+function f6(x: any) {
+  return x;
+}
+const t6 = f6(1);
+//    ^? T6
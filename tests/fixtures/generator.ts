@@ -0,0 +1,59 @@
+function* counter() {
+  yield 1;
+  yield 2;
+}
+const g1 = counter();
+//    ^? G1
+
+function* withReturn(flag: boolean) {
+  const received = yield "a";
+  //    ^? Received
+  if (flag) {
+    return true;
+  }
+  return 0;
+}
+const g2 = withReturn(true);
+//    ^? G2
+
+function* empty() {}
+const g3 = empty();
+//    ^? G3
+
+function* delegating(numbers: number[]) {
+  const result = yield* withReturn(false);
+  //    ^? Result
+  yield* numbers;
+}
+const g4 = delegating([]);
+//    ^? G4
+
+function* annotated(): Generator<number, string, boolean> {
+  const next = yield 1;
+  //    ^? Next
+  return "done";
+}
+
+async function* asyncCounter(promise: Promise<string>) {
+  yield promise;
+  yield 1;
+}
+const g5 = asyncCounter(Promise.resolve(""));
+//    ^? G5
+
+function iterate(generator: Generator<number, void, unknown>) {
+  for (const value of generator) {
+    value;
+  //^? Value
+  }
+}
+
+async function iterateAsync(generator: AsyncGenerator<string>) {
+  for await (const value of generator) {
+    value;
+  //^? AsyncValue
+  }
+}
+
+const step = g1.next();
+//    ^? Step
@@ -0,0 +1,68 @@
+interface User {
+  id: number;
+  name: string;
+  tags?: string[];
+}
+
+type T1 = User["name"];
+//   ^? T1
+
+type T2 = User["id" | "name"];
+//   ^? T2
+
+type Pair = [string, number];
+type T3 = Pair[0];
+//   ^? T3
+
+type T4 = Pair[number];
+//   ^? T4
+
+type T5 = string[][number];
+//   ^? T5
+
+type Dict = { [key: string]: boolean; fixed: 1 };
+type T6 = Dict["other"];
+//   ^? T6
+
+type Values<T> = { [K in keyof T]: T[K] };
+type T7 = Values<{ a: 1; b?: "b" }>;
+//   ^? T7
+
+type Pick2<T, K extends keyof T> = { [P in K]: T[P] };
+type T8 = Pick2<User, "id" | "tags">;
+//   ^? T8
+
+type Required2<T> = { [P in keyof T]-?: T[P] };
+type T9 = Required2<User>;
+//   ^? T9
+
+function get<T, K extends keyof T>(obj: T, key: K): T[K] {
+  type Deferred = T[K];
+  //   ^? Deferred
+  return obj[key];
+}
+
+declare const user: User;
+const userName = get(user, "name");
+//    ^? GetName
+const userTags = get(user, "tags");
+//    ^? GetTags
+
+function constrained<T extends { id: number }, K extends keyof T>(obj: T, key: K) {
+  const id = obj.id;
+  //    ^? ConstraintProperty
+  const value = obj[key];
+  //    ^? ConstraintIndex
+  return value;
+}
+
+type DeclaredLaterName = DeclaredLater["name"];
+//   ^? DeclaredLaterName
+
+type DeclaredLaterValues = DeclaredLater[keyof DeclaredLater];
+//   ^? DeclaredLaterValues
+
+interface DeclaredLater {
+  name: string;
+  count: number;
+}
@@ -0,0 +1,51 @@
+interface Point {
+  x: number;
+  y: number;
+}
+
+type K1 = keyof Point;
+//   ^? K1
+
+type K2 = keyof (Point & { z: number });
+//   ^? K2
+
+type K3 = keyof { a: 1; 0: 2 };
+//   ^? K3
+
+type K4 = keyof { a: 1; b: 2 } | { b: 3; c: 4 };
+//   ^? K4
+
+type K5 = keyof ({ a: 1; b: 2 } | { b: 3; c: 4 });
+//   ^? K5
+
+type K6 = keyof { [key: string]: boolean };
+//   ^? K6
+
+type K7 = keyof { [key: number]: boolean; length: number };
+//   ^? K7
+
+type K8 = keyof ({ [key: string]: 1 } | { a: 1 });
+//   ^? K8
+
+type K9 = keyof any;
+//   ^? K9
+
+type Only<T, K> = T extends K ? T : never;
+type K10 = Only<keyof [string, number], "1" | "push">;
+//   ^? K10
+
+type K11 = Only<keyof [string], "1">;
+//   ^? K11
+
+function keys<T>(obj: T) {
+  type Deferred = keyof T;
+  //   ^? Deferred
+}
+
+type K12 = keyof DeclaredLater;
+//   ^? K12
+
+interface DeclaredLater {
+  first: string;
+  second: number;
+}
@@ -0,0 +1,49 @@
+type Flags<K extends string> = { [P in K]: boolean };
+type Keyed<T> = { [P in keyof T]: string };
+type Mutable<T> = { -readonly [P in keyof T]-?: number };
+type Frozen<T> = { +readonly [P in keyof T]+?: T };
+type Omitted<T, K> = { [P in keyof T as P extends K ? never : P]: 1 };
+type Boxed<T> = { [P in keyof T]: { value: P } };
+
+interface Options {
+  readonly name: string;
+  debug?: boolean;
+}
+
+type T1 = Flags<"a" | "b">;
+//   ^? T1
+
+type T2 = Keyed<Options>;
+//   ^? T2
+
+type T3 = Mutable<Options>;
+//   ^? T3
+
+type T4 = Frozen<{ a: 1 }>;
+//   ^? T4
+
+type T5 = Omitted<Options, "debug">;
+//   ^? T5
+
+type T6 = Keyed<{ a: 1 } | { b: 2 }>;
+//   ^? T6
+
+type T7 = Keyed<[number, string?]>;
+//   ^? T7
+
+type T8 = Keyed<number>;
+//   ^? T8
+
+type T9 = Boxed<{ [key: string]: 1; x: 2 }>;
+//   ^? T9
+
+type T10 = { [P in keyof Options]: boolean };
+//   ^? T10
+
+type T11 = { readonly [P in keyof DeclaredLater]: P };
+//   ^? T11
+
+interface DeclaredLater {
+  a: 1;
+  b: 2;
+}
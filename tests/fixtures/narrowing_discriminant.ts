@@ -0,0 +1,73 @@
+type Circle = { kind: "circle"; radius: number };
+type Square = { kind: "square"; size: number };
+interface Triangle {
+  kind: "triangle";
+  base: number;
+}
+interface Tagged<T> {
+  kind: "tagged";
+  value: T;
+}
+type Shape = Circle | Square | Triangle | Tagged<string>;
+
+function area(shape: Shape) {
+  if (shape.kind === "circle") {
+    shape
+  //^? A
+    shape.radius
+  //      ^? B
+  } else {
+    shape
+  //^? C
+  }
+
+  if (shape.kind !== "square" && shape.kind !== "tagged") {
+    shape
+  //^? D
+  }
+
+  if ("triangle" == shape.kind) {
+    shape.base
+  //      ^? E
+  }
+
+  if (shape.kind === "tagged") {
+    shape
+  //^? F
+    shape.value
+  //      ^? G
+  }
+}
+
+function switched(shape: Shape) {
+  switch (shape.kind) {
+    case "circle":
+      shape
+    //^? H
+      break;
+    case "square":
+    case "triangle":
+      shape
+    //^? I
+      break;
+    default:
+      shape
+    //^? J
+  }
+}
+
+type Action = { type: 1; payload: string } | { type: 2; payload: number };
+
+function reducer(action: Action, flag: "on" | "off") {
+  if (action.type === 2) {
+    action.payload
+  //       ^? K
+  }
+  if (flag === "on") {
+    flag
+  //^? L
+  } else {
+    flag
+  //^? M
+  }
+}
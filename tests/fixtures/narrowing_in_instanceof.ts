@@ -0,0 +1,57 @@
+type Fish = { swim: () => void; name: string };
+type Bird = { fly: () => void; name: string };
+type Bat = { fly?: () => void; swim?: () => void };
+
+function move(animal: Fish | Bird | Bat) {
+  if ("swim" in animal) {
+    animal
+  //^? A
+  } else {
+    animal
+  //^? B
+  }
+  if ("name" in animal) {
+    animal
+  //^? C
+  }
+}
+
+class HttpError {
+  status: number = 500;
+  message: string = "";
+}
+class NotFound extends HttpError {
+  path: string = "";
+}
+class Timeout {
+  ms: number = 0;
+}
+
+function handle(err: unknown, known: HttpError | Timeout | string) {
+  if (err instanceof HttpError) {
+    err
+  //^? D
+  }
+  if (known instanceof HttpError) {
+    known
+  //^? E
+  } else {
+    known
+  //^? F
+  }
+  if (known instanceof NotFound) {
+    known
+  //^? G
+  }
+}
+
+function handleLater(err: unknown) {
+  if (err instanceof DeclaredLater) {
+    err.code
+  //    ^? H
+  }
+}
+
+class DeclaredLater {
+  code = 1;
+}
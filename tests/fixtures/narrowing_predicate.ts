@@ -0,0 +1,74 @@
+type Cat = { meow: () => void; name: string };
+type Dog = { bark: () => void; name: string };
+
+declare function isString(x: unknown): x is string;
+declare function isCat(pet: Cat | Dog): pet is Cat;
+declare function assertIsNumber(x: unknown): asserts x is number;
+declare function assert(condition: unknown): asserts condition;
+
+export const guard = isCat;
+//           ^? Guard
+export const assertion = assertIsNumber;
+//           ^? Assertion
+
+function check(value: unknown, pet: Cat | Dog, id: string | number) {
+  if (isString(value)) {
+    value
+  //^? A
+  }
+  if (isCat(pet)) {
+    pet
+  //^? B
+  } else {
+    pet
+  //^? C
+  }
+  if (!isString(id)) {
+    id
+  //^? D
+  }
+}
+
+function validate(input: unknown, maybe: string | undefined) {
+  assertIsNumber(input);
+  input
+//^? E
+  assert(maybe);
+  maybe
+//^? F
+  assert(typeof input === "number" && maybe === "ok");
+  maybe
+//^? G
+}
+
+type Circle = { radius: number; isCircle(): this is Circle };
+type Square = { size: number; isCircle(): this is Circle };
+
+function area(shape: Circle | Square) {
+  if (shape.isCircle()) {
+    shape
+  //^? H
+  }
+}
+
+function isAdmin(user: Member | Admin): user is Admin {
+  return "rights" in user;
+}
+
+function greet(user: Member | Admin) {
+  if (isAdmin(user)) {
+    user.rights
+  //     ^? I
+  } else {
+    user
+  //^? J
+  }
+}
+
+interface Member {
+  name: string;
+}
+interface Admin {
+  name: string;
+  rights: string[];
+}
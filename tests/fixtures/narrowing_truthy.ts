@@ -0,0 +1,107 @@
+function truthy(x: string | undefined, y: { foo: number } | null, z: boolean | 0) {
+  if (x) {
+    x
+  //^? A
+  } else {
+    x
+  //^? B
+  }
+
+  const foo = y && y.foo;
+  //    ^? C
+
+  if (z) {
+    z
+  //^? D
+  }
+
+  if (!y) {
+    y
+  //^? E
+  }
+}
+
+function nullish(x: number | null | undefined, y: string | null) {
+  const a = x ?? "default";
+  //    ^? F
+
+  if (x != null) {
+    x
+  //^? G
+  }
+  if (x !== undefined) {
+    x
+  //^? H
+  }
+  if (x === null) {
+    x
+  //^? I
+  }
+  if (y == undefined) {
+    y
+  //^? J
+  }
+}
+
+function early(x: { a: string } | undefined, y: string | number | undefined) {
+  if (!x) return;
+  x
+//^? K
+
+  if (y === undefined) {
+    return;
+  } else if (typeof y === "string") {
+    return;
+  }
+  y
+//^? L
+}
+
+function conditional(x: string | null) {
+  const a = x ? x : "none";
+  //    ^? M
+  const b = x || 1;
+  //    ^? N
+  if (x && typeof x === "string") {
+    x
+  //^? O
+  }
+}
+
+function assigned(o: { a: string | null }) {
+  if (o.a !== null) {
+    o.a = null;
+    o.a
+  //^? P
+  }
+}
+
+function loops(v: string | undefined, w: number | null) {
+  while (v) {
+    v
+  //^? Q
+  }
+  for (; w !== null; ) {
+    w
+  //^? R
+  }
+}
+
+function thrown(x: string | undefined) {
+  if (!x) throw new Error();
+  x
+//^? S
+}
+
+function caught(x: string | undefined) {
+  try {
+    if (!x) throw new Error();
+  } catch {}
+  x
+//^? T
+}
+
+let moduleLevel: string | undefined;
+if (!moduleLevel) throw new Error();
+export const u = moduleLevel;
+//           ^? U
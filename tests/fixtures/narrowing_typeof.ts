@@ -0,0 +1,72 @@
+function basic(x: string | number | undefined) {
+  if (typeof x === "string") {
+    x
+  //^? A
+  } else {
+    x
+  //^? B
+  }
+
+  if (typeof x !== "number") {
+    x
+  //^? C
+  }
+
+  if ("undefined" == typeof x) {
+    x
+  //^? D
+  }
+
+  const y = typeof x === "number" ? x : 0;
+  //    ^? E
+
+  x
+//^? F
+}
+
+function objects(x: { a: number } | (() => void) | null) {
+  if (typeof x === "object") {
+    x
+  //^? G
+  } else {
+    x
+  //^? H
+  }
+}
+
+function unknowns(x: unknown) {
+  if (typeof x === "bigint") {
+    x
+  //^? I
+  }
+}
+
+function switched(x: string | number | boolean) {
+  switch (typeof x) {
+    case "string":
+      x
+    //^? J
+      break;
+    case "number":
+    case "boolean":
+      x
+    //^? K
+      break;
+    default:
+      x
+    //^? L
+  }
+}
+
+function properties(obj: { a: string | number; b: { c: boolean | undefined } }) {
+  if (typeof obj.a === "string") {
+    obj.a
+  //    ^? M
+  }
+  if (typeof obj.b.c !== "undefined") {
+    obj.b.c
+  //      ^? N
+  }
+  obj.a
+//    ^? O
+}
@@ -0,0 +1,31 @@
+const temperature = {
+//    ^? Temperature
+  celsius: 20,
+  get fahrenheit() {
+    return this.celsius * 1.8 + 32;
+  },
+  set fahrenheit(value: number) {
+    this.celsius = (value - 32) / 1.8;
+  },
+  get label() {
+    this;
+  //^? AccessorThis
+    return `${this.celsius}°C`;
+  },
+};
+
+const writeOnly = {
+//    ^? WriteOnly
+  set name(value: string) {},
+};
+
+interface Named {
+  name: string;
+}
+const named: Named = {
+  get name() {
+    this;
+  //^? ContextualThis
+    return "named";
+  },
+};
@@ -0,0 +1,105 @@
+type Circle = { kind: "circle"; radius: number };
+type Square = { kind: "square"; size: number };
+type Shape = Circle | Square;
+
+function exhaustive(shape: Shape) {
+  switch (shape.kind) {
+    case "circle":
+      return 1;
+    case "square":
+      return 2;
+  }
+}
+export const a = exhaustive;
+//           ^? Exhaustive
+
+function partial(shape: Shape) {
+  switch (shape.kind) {
+    case "circle":
+      return 1;
+  }
+}
+export const b = partial;
+//           ^? Partial
+
+function breaks(shape: Shape) {
+  switch (shape.kind) {
+    case "circle":
+      return 1;
+    case "square":
+      break;
+  }
+  return 2;
+}
+export const c = breaks;
+//           ^? Breaks
+
+function withDefault(kind: "a" | "b" | "c") {
+  switch (kind) {
+    case "a":
+      kind
+    //^? A
+    case "b":
+      kind
+    //^? AB
+      return 1;
+    default:
+      kind
+    //^? C
+      return 2;
+  }
+}
+export const d = withDefault;
+//           ^? WithDefault
+
+function unreachableDefault(kind: "a" | "b") {
+  switch (kind) {
+    case "a":
+    case "b":
+      return kind;
+    default:
+      kind
+    //^? Never
+      return 0;
+  }
+}
+
+declare const outerKind: "a" | "b";
+
+function outerBinding() {
+  switch (outerKind) {
+    case "a":
+      return 1;
+    case "b":
+      return "x";
+  }
+}
+export const o = outerBinding;
+//           ^? OuterBinding
+
+const outerArrow = () => {
+  switch (outerKind) {
+    case "a":
+      return 1;
+    case "b":
+      return "x";
+  }
+};
+export const oa = outerArrow;
+//           ^? OuterArrow
+
+enum Color {
+  Red,
+  Green,
+}
+
+function enumMembers(color: Color) {
+  switch (color) {
+    case Color.Red:
+      return 1;
+    case Color.Green:
+      return 2;
+  }
+}
+export const e = enumMembers;
+//           ^? EnumMembers
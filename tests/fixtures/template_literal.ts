@@ -0,0 +1,65 @@
+type Lang = "en" | "ja";
+type Page = "home" | "about";
+
+type T1 = `/${Lang}/${Page}`;
+//   ^? T1
+
+type T2 = `id-${number}`;
+//   ^? T2
+
+type T3 = `${boolean}!` | `${1 | 2n}`;
+//   ^? T3
+
+type T4 = `prefix${string}`;
+//   ^? T4
+
+type T5 = `${string}`;
+//   ^? T5
+
+type Split<S> = S extends `${infer Head}.${infer Tail}` ? [Head, Tail] : [S];
+type T6 = Split<"a.b.c">;
+//   ^? T6
+
+type T7 = Split<"abc">;
+//   ^? T7
+
+type Param<S> = S extends `${string}:${infer P}/${infer Rest}` ? P | Param<`/${Rest}`> : S extends `${string}:${infer P}` ? P : never;
+type T8 = Param<"/users/:userId/posts/:postId">;
+//   ^? T8
+
+type IsId<S> = S extends `id-${number}` ? "yes" : "no";
+type T9 = IsId<"id-42">;
+//   ^? T9
+
+type T10 = IsId<"id-x">;
+//   ^? T10
+
+type Chars<S> = S extends `${infer C}${infer Rest}` ? C | Chars<Rest> : never;
+type T11 = Chars<"abc">;
+//   ^? T11
+
+type T12 = Uppercase<"hello" | "world">;
+//   ^? T12
+
+type T13 = Lowercase<"HeLLo">;
+//   ^? T13
+
+type T14 = Capitalize<"hello">;
+//   ^? T14
+
+type T15 = Uncapitalize<"Hello">;
+//   ^? T15
+
+type Getter<K extends string> = `get${Capitalize<K>}`;
+type T16 = Getter<"name" | "age">;
+//   ^? T16
+
+type T17 = Uppercase<`a${string}b`>;
+//   ^? T17
+
+function route<P extends string>(path: P) {
+  type Deferred = `/${P}`;
+  //   ^? Deferred
+  type Upper = Uppercase<P>;
+  //   ^? Upper
+}
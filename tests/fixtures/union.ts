@@ -9,3 +9,10 @@ let b: string | "a";
 let c: string | (bigint | symbol);
   c
 //^? C
+
+const d = Math.random() > 0.5 ? [1] : [2];
+//    ^? D
+
+let e: Array<string> | string[] | Promise<number> | Promise<number>;
+  e
+//^? E
@@ -0,0 +1,31 @@
+interface Point {
+  x: number;
+  y?: string;
+}
+
+const partial: Partial<Point> = {};
+//    ^? PartialPoint
+const required: Required<Point> = { x: 1, y: "" };
+//    ^? RequiredPoint
+const readonly: Readonly<Point> = { x: 1 };
+//    ^? ReadonlyPoint
+const picked: Pick<Point, "x"> = { x: 1 };
+//    ^? Picked
+const omitted: Omit<Point, "x"> = {};
+//    ^? Omitted
+const record: Record<"a" | "b", number> = { a: 1, b: 2 };
+//    ^? RecordAB
+
+declare function fn(a: number, b: string): boolean;
+const params: Parameters<typeof fn> = [1, ""];
+//    ^? Params
+const ret: ReturnType<typeof fn> = true;
+//    ^? Ret
+
+class Foo {
+  value = 1;
+}
+const instance: InstanceType<typeof Foo> = new Foo();
+//    ^? Instance
+const date: InstanceType<typeof Date> = new Date();
+//    ^? DateInstance
@@ -1,21 +1,30 @@
-use std::{fs, sync::LazyLock};
+use std::{fs, path::Path, sync::LazyLock};
 
 use insta::{assert_snapshot, glob, Settings};
 use oxc::{
   allocator::Allocator,
   ast::{ast::Statement, NONE},
   codegen::Codegen,
+  parser::Parser,
+  semantic::SemanticBuilder,
   span::{SourceType, SPAN},
 };
 use regex::Regex;
-use simple_ts::{analyze, Config};
+use simple_ts::{analyze, analyze_program, Analyzer, Config, Jsx, Lib, NodeModuleResolver, Target};
+
+static FIXTURES_CONFIG: LazyLock<Config> =
+  LazyLock::new(|| Config::from_tsconfig("tests/fixtures/tsconfig.json").unwrap());
 
 static TYPE_QUERY_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\^\? (\w+)").unwrap());
 
-pub fn serialize_queried_types(code: String) -> String {
+pub fn serialize_queried_types(code: String, config: Config) -> String {
   let allocator = Allocator::default();
   let code = allocator.alloc(code);
-  let mut analyzer = analyze(&allocator, code, Config::default());
+  let mut analyzer = analyze(&allocator, code, config);
+  serialize_queries(&mut analyzer, code)
+}
+
+fn serialize_queries(analyzer: &mut Analyzer, code: &str) -> String {
   let codegen = Codegen::new();
 
   let mut snapshot_stmts = analyzer.ast_builder.vec();
@@ -59,7 +68,171 @@ fn test() {
     settings.set_omit_expression(true);
     settings.set_prepend_module_to_snapshot(false);
     settings.bind(|| {
-      assert_snapshot!(serialize_queried_types(input));
+      assert_snapshot!(serialize_queried_types(input, FIXTURES_CONFIG.clone()));
+    })
+  });
+}
+
+/// Each directory in `programs` is analyzed as a program, and the queries in all files are
+/// snapshotted together.
+#[test]
+fn test_program() {
+  glob!("programs/*/main.ts", |main| {
+    let dir = main.parent().unwrap();
+    let mut files = vec![];
+    collect_source_files(dir, &mut files);
+    files.sort();
+
+    let tsconfig = dir.join("tsconfig.json");
+    let config =
+      if tsconfig.exists() { Config::from_tsconfig(&tsconfig).unwrap() } else { Config::default() };
+    let resolver = NodeModuleResolver::from_config(&config);
+    let allocator = Allocator::default();
+    let mut analyzer = analyze_program(&allocator, files.clone(), &resolver, config);
+
+    let mut snapshot = String::new();
+    for (path, source) in &files {
+      if !TYPE_QUERY_RE.is_match(source) {
+        continue;
+      }
+      let module = analyzer.get_module_by_path(path).unwrap();
+      analyzer.select_module(module);
+      let name = Path::new(path).strip_prefix(dir).unwrap().display();
+      snapshot += &format!("// {name}\n{}", serialize_queries(&mut analyzer, source));
+    }
+
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(snapshot);
+    })
+  });
+}
+
+#[test]
+fn test_empty_program() {
+  let allocator = Allocator::default();
+  let resolver = NodeModuleResolver::default();
+  let analyzer = analyze_program(&allocator, vec![], &resolver, Config::default());
+  assert_eq!(analyzer.get_module_by_path("main.ts"), None);
+}
+
+#[test]
+fn test_dts() {
+  glob!("dts/*.ts", |path| {
+    let input = fs::read_to_string(path).unwrap();
+    let allocator = Allocator::default();
+    let code = allocator.alloc_str(&input);
+    let program = allocator.alloc(Parser::new(&allocator, code, SourceType::ts()).parse().program);
+    let semantic = allocator.alloc(SemanticBuilder::new().build(program).semantic);
+    let mut analyzer = Analyzer::new(&allocator, FIXTURES_CONFIG.clone(), semantic);
+    analyzer.exec_program(program);
+
+    let mut settings = Settings::clone_current();
+    settings.set_omit_expression(true);
+    settings.set_prepend_module_to_snapshot(false);
+    settings.bind(|| {
+      assert_snapshot!(analyzer.emit_declarations(program));
     })
   });
 }
+
+fn collect_source_files(dir: &Path, files: &mut Vec<(String, String)>) {
+  for entry in fs::read_dir(dir).unwrap() {
+    let path = entry.unwrap().path();
+    if path.is_dir() {
+      collect_source_files(&path, files);
+    } else if matches!(
+      path.extension().and_then(|ext| ext.to_str()),
+      Some("ts" | "tsx" | "mts" | "cts")
+    ) {
+      let source = fs::read_to_string(&path).unwrap();
+      files.push((path.to_string_lossy().into_owned(), source));
+    }
+  }
+}
+
+#[test]
+fn test_lib() {
+  let query = |code: &str, config: Config| serialize_queried_types(code.to_string(), config);
+  let has_own = "const hasOwn = Object.hasOwn;\n//    ^? T\n";
+  let document = "const doc = document;\n//    ^? T\n";
+
+  let es2015 = Config { target: Target::ES2015, lib: None, ..Default::default() };
+  let es2022 = Config { target: Target::ES2022, lib: None, ..Default::default() };
+  assert_eq!(query(has_own, es2015), "type T = any;\n");
+  assert_eq!(
+    query(has_own, es2022.clone()),
+    "type T = (a0: object, a1: PropertyKey) => boolean;\n"
+  );
+  assert_eq!(query(document, es2022.clone()), "type T = Document;\n");
+  let navigator = "const n = navigator.userAgent;\n//    ^? T\n";
+  let url = "const u = new URL('a', 'b').searchParams;\n//    ^? T\n";
+  let input = "const i = (input: HTMLInputElement) => input.value;\n//    ^? T\n";
+  assert_eq!(query(navigator, es2022.clone()), "type T = string;\n");
+  assert_eq!(query(url, es2022.clone()), "type T = URLSearchParams;\n");
+  assert_eq!(query(input, es2022), "type T = (a0: HTMLInputElement) => string;\n");
+
+  let worker = Config {
+    target: Target::ES2022,
+    lib: Some(vec![Lib::ES2022, Lib::WebWorker]),
+    ..Default::default()
+  };
+  assert_eq!(query(document, worker.clone()), "type T = any;\n");
+  assert_eq!(query(navigator, worker.clone()), "type T = string;\n");
+  assert_eq!(query("const s = self;\n//    ^? T\n", worker), "type T = WorkerGlobalScope;\n");
+}
+
+#[test]
+fn test_tsconfig() {
+  let dir = std::path::absolute("tests/tsconfig").unwrap();
+  let config = Config::from_tsconfig(dir.join("tsconfig.json")).unwrap();
+
+  // From `configs/base.json`
+  assert_eq!(config.target, Target::ES2020);
+  assert!(config.strict_null_checks);
+  assert!(config.no_unchecked_indexed_access);
+  assert_eq!(config.base_url, Some(dir.join("configs")));
+  assert_eq!(config.paths_base, Some(dir.join("configs")));
+  // Overridden by `tsconfig.json`, where sub-libs load their parent lib
+  assert_eq!(config.lib, Some(vec![Lib::ES2020, Lib::Dom, Lib::ES2015]));
+  assert!(!config.exact_optional_property_types);
+  assert_eq!(config.jsx, Some(Jsx::ReactJsx));
+  assert_eq!(config.jsx_import_source.as_deref(), Some("preact"));
+  assert_eq!(config.paths, vec![("@/*".to_string(), vec!["../src/*".to_string()])]);
+
+  let files = config.source_files().unwrap();
+  let files = files.iter().map(|file| file.strip_prefix(&dir).unwrap().to_str().unwrap());
+  assert_eq!(files.collect::<Vec<_>>(), vec!["extra.ts", "src/a.ts", "src/nested/b.tsx"]);
+}
+
+#[test]
+fn test_resolver_without_base_url() {
+  let dir = std::path::absolute("tests/programs/paths_without_base_url").unwrap();
+  let config = Config::from_tsconfig(dir.join("tsconfig.json")).unwrap();
+  assert_eq!(config.base_url, None);
+
+  let resolver = NodeModuleResolver::from_config(&config);
+  let main = dir.join("main.ts");
+  assert_eq!(resolver.resolve_path(&main, "@lib/shared"), Some(dir.join("lib/shared.ts")));
+  assert_eq!(
+    resolver.resolve_path(&main, "react"),
+    Some(dir.join("node_modules/react/index.d.ts"))
+  );
+  assert_eq!(resolver.resolve_path(&main, "lib/shared"), None);
+}
+
+#[test]
+fn test_tsconfig_defaults() {
+  let dir = std::path::absolute("tests/tsconfig/defaults").unwrap();
+  let config = Config::from_tsconfig(dir.join("tsconfig.json")).unwrap();
+
+  assert!(!config.strict_null_checks);
+  assert!(!config.exact_optional_property_types);
+
+  // Everything is included, except for `node_modules`
+  let files = config.source_files().unwrap();
+  let files = files.iter().map(|file| file.strip_prefix(&dir).unwrap().to_str().unwrap());
+  assert_eq!(files.collect::<Vec<_>>(), vec!["main.ts"]);
+}
@@ -0,0 +1,17 @@
+import { Leaf, Node, makeLeaf } from './tree'
+
+export interface Options {
+  depth: number
+}
+
+export const defaults: Options = { depth: 2 }
+
+export function makeNode(children: Leaf[]) {
+  return { kind: 'node' as const, children }
+}
+
+const leaf = makeLeaf('x')
+//    ^? Leaf
+
+const node: Node = makeNode([leaf])
+//    ^? NodeType
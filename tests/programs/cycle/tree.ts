@@ -0,0 +1,18 @@
+import { Options, defaults, makeNode } from './main'
+
+export type Leaf = { kind: 'leaf'; value: string }
+
+export type Node = { kind: 'node'; children: Leaf[] } | Leaf
+
+export function makeLeaf(value: string) {
+  return { kind: 'leaf' as const, value }
+}
+
+const options: Options = defaults
+//    ^? TreeOptions
+
+const depth = defaults.depth
+//    ^? Depth
+
+const root = makeNode([])
+//    ^? Root
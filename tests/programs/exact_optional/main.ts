@@ -0,0 +1,3 @@
+type Options = { name?: string | undefined; size?: number };
+type Filled = Required<Options>;
+//   ^? Filled
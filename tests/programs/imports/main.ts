@@ -0,0 +1,21 @@
+import greet, { count, Point, origin } from './shapes'
+import * as shapes from './shapes'
+import { total } from './reexport'
+
+const a = greet('world')
+//    ^? A
+
+const b = count
+//    ^? B
+
+const c: Point = { x: 1, y: 2 }
+//    ^? C
+
+const d = shapes.origin
+//    ^? D
+
+const e: shapes.Point = origin
+//    ^? E
+
+const f = total
+//    ^? F
@@ -0,0 +1 @@
+export { count as total } from './shapes'
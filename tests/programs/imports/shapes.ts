@@ -0,0 +1,13 @@
+export interface Point {
+  x: number
+  y: number
+}
+
+export const origin: Point = { x: 0, y: 0 }
+
+const count = 42
+export { count }
+
+export default function greet(name: string) {
+  return 'Hello, ' + name
+}
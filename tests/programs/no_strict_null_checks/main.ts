@@ -0,0 +1,13 @@
+declare const maybe: string | null | undefined;
+const value = maybe;
+//    ^? Value
+
+declare const nullish: null | undefined;
+const empty = nullish;
+//    ^? Empty
+
+function find(items: number[]) {
+  return items.length > 0 ? items[0] : undefined;
+}
+const found = find([1]);
+//    ^? Found
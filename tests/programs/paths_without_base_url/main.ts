@@ -0,0 +1,8 @@
+import { shared } from '@lib/shared'
+import { react } from 'react'
+
+// `paths` is relative to the config, but `react` is not resolved against it without `baseUrl`
+const a = shared
+//    ^? A
+const b = react
+//    ^? B
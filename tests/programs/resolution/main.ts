@@ -0,0 +1,37 @@
+import { helper } from './src/utils'
+import { version } from './src/version.js'
+import { shared } from '@lib/shared'
+import { fromBase } from 'src/base'
+import { typed } from 'typed-pkg'
+import { feature } from 'typed-pkg/feature'
+import { legacy } from 'legacy-pkg'
+import { untyped } from 'untyped'
+import { tool } from '@scope/tool'
+import { esm } from './src/esm.mjs'
+import { cjs } from './src/cjs.cjs'
+import { probed } from './src/probed'
+
+const a = helper
+//    ^? A
+const b = version
+//    ^? B
+const c = shared
+//    ^? C
+const d = fromBase
+//    ^? D
+const e = typed
+//    ^? E
+const f = feature
+//    ^? F
+const g = legacy
+//    ^? G
+const h = untyped
+//    ^? H
+const i = tool
+//    ^? I
+const j = esm
+//    ^? J
+const k = cjs
+//    ^? K
+const l = probed
+//    ^? L
@@ -0,0 +1 @@
+export const esm = 'esm' as const
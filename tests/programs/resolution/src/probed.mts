@@ -0,0 +1 @@
+export const probed = 1
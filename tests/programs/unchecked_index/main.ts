@@ -0,0 +1,18 @@
+declare const numbers: number[];
+const first = numbers[0];
+//    ^? First
+
+declare const tuple: [string, number];
+const element = tuple[1];
+//    ^? Element
+
+declare const index: number;
+const any = tuple[index];
+//    ^? Any
+
+declare const dict: { [key: string]: boolean; fixed: 1 };
+const other = dict["other"];
+//    ^? Other
+
+const fixed = dict.fixed;
+//    ^? Fixed
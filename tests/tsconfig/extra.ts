@@ -0,0 +1 @@
+export const extra = 4